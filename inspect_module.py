@@ -1,15 +1,68 @@
+import ast
+import builtins
+import contextlib
 import inspect
 import importlib
 import pathlib
 import sys
 import json
+import re
 import types
 import collections.abc
 import typing
 
 
+def is_callback_protocol(ty) -> bool:
+    return (
+        inspect.isclass(ty)
+        and getattr(ty, "_is_protocol", False)
+        and "__call__" in ty.__dict__
+    )
+
+
+def callback_protocol_as_tag(ty: type) -> dict:
+    sig = inspect.signature(ty.__call__)
+    # Skip `self`
+    params = list(sig.parameters.values())[1:]
+    positional = [inspect.Parameter.POSITIONAL_ONLY, inspect.Parameter.POSITIONAL_OR_KEYWORD]
+    param_spec = None
+    for p in params:
+        if p.kind == inspect.Parameter.VAR_POSITIONAL and type(
+            p.annotation
+        ) == typing.ParamSpecArgs:
+            param_spec = p.annotation.__origin__.__name__
+    return {
+        "kind": "callable",
+        "args": [type_as_tag(p.annotation) for p in params if p.kind in positional],
+        "keywords": [
+            {"name": p.name, "type": type_as_tag(p.annotation), "doc": None}
+            for p in params
+            if p.kind == inspect.Parameter.KEYWORD_ONLY
+        ],
+        "param_spec": param_spec,
+        "return": type_as_tag(sig.return_annotation),
+    }
+
+
+def annotation_as_tag(metadata) -> dict | None:
+    # Markers defined in `py2o2.py` are matched by name,
+    # and metadata for other libraries are ignored
+    if type(metadata).__module__ != "py2o2":
+        return None
+    name = type(metadata).__name__
+    if name == "Rust":
+        return {"kind": "rust", "type": metadata.type}
+    if name == "Rename":
+        return {"kind": "rename", "name": metadata.name}
+    if name == "Skip":
+        return {"kind": "skip"}
+    return None
+
+
 def type_as_tag(ty: type) -> dict:
-    if ty is None or ty == inspect._empty:
+    if ty == inspect._empty:
+        return {"kind": "unknown"}
+    if ty is None or ty is type(None):
         return {"kind": "none"}
     if ty == int:
         return {"kind": "primitive", "name": "int"}
@@ -19,8 +72,33 @@ def type_as_tag(ty: type) -> dict:
         return {"kind": "primitive", "name": "float"}
     if ty == Exception:
         return {"kind": "exception"}
+    if ty is typing.Any or ty is object:
+        return {"kind": "any"}
+    if ty is typing.NoReturn or ty is getattr(typing, "Never", typing.NoReturn):
+        return {"kind": "never"}
+    if typing.get_origin(ty) is type:
+        (cls,) = typing.get_args(ty)
+        if cls is typing.Any:
+            cls = object
+        if cls.__qualname__ != cls.__name__:
+            # e.g. `Outer.Inner` or `f.<locals>.C`, which is not an attribute of the module
+            raise NotImplementedError(
+                f"Nested class {cls.__module__}.{cls.__qualname__} is not supported in type[T]"
+            )
+        return {
+            "kind": "class_object",
+            "module": cls.__module__,
+            "name": cls.__name__,
+        }
     if ty == Ellipsis:
         return {"kind": "ellipsis"}
+    if typing.get_origin(ty) == typing.Annotated:
+        inner = type_as_tag(ty.__origin__)
+        metadata = [annotation_as_tag(m) for m in ty.__metadata__]
+        metadata = [m for m in metadata if m is not None]
+        if not metadata:
+            return inner
+        return {"kind": "annotated", "inner": inner, "metadata": metadata}
     if type(ty) == types.GenericAlias:
         if ty.__origin__ in [list, collections.abc.Sequence]:
             return {"kind": "list", "inner": [type_as_tag(t) for t in ty.__args__]}
@@ -39,35 +117,259 @@ def type_as_tag(ty: type) -> dict:
         }
     if type(ty) in [types.UnionType, typing._UnionGenericAlias]:
         return {"kind": "union", "args": [type_as_tag(t) for t in ty.__args__]}
-    if type(ty) == collections.abc._CallableGenericAlias:
+    if type(ty) in [collections.abc._CallableGenericAlias, typing._CallableGenericAlias]:
+        *args, ret = ty.__args__
+        param_spec = None
+        if len(args) == 1 and type(args[0]) == typing.ParamSpec:
+            # Callable[P, R]
+            param_spec = args[0].__name__
+            args = []
+        elif len(args) == 1 and type(args[0]) == typing._ConcatenateGenericAlias:
+            # Callable[Concatenate[int, P], R]
+            *args, spec = args[0].__args__
+            param_spec = spec.__name__
         return {
             "kind": "callable",
-            "args": [type_as_tag(t) for t in ty.__args__[:-1]],
-            "return": type_as_tag(ty.__args__[-1]),
+            "args": [type_as_tag(t) for t in args],
+            "keywords": [],
+            "param_spec": param_spec,
+            "return": type_as_tag(ret),
         }
+    if is_callback_protocol(ty):
+        return callback_protocol_as_tag(ty)
     raise NotImplementedError(f"Unsupported type = {ty}, {type(ty)}")
 
 
-def inspect_module(target: str) -> str:
-    module = importlib.import_module(target)
+def docstring_type_as_tag(ty: str, namespace: dict) -> dict | None:
+    """Type written in docstring, e.g. `int`, `list of float` or `str, optional`"""
+    ty = re.sub(r",\s*optional$", "", ty.strip())
+    m = re.fullmatch(r"(list|sequence) of (.+)", ty)
+    if m is not None:
+        ty = f"list[{m.group(2)}]"
+    try:
+        return type_as_tag(resolve_type(ast.parse(ty, mode="eval").body, namespace))
+    except Exception:
+        return None
+
+
+def resolve_type(node: ast.expr, namespace: dict):
+    """Type object of the expression, looking up names in the namespace without evaluating code
+
+    Only names, attributes, subscripts, lists, `X | Y` and `None` are resolved,
+    anything else raises `ValueError`.
+    """
+    if isinstance(node, ast.Name):
+        if node.id in namespace:
+            return namespace[node.id]
+        return getattr(builtins, node.id)
+    if isinstance(node, ast.Attribute):
+        return getattr(resolve_type(node.value, namespace), node.attr)
+    if isinstance(node, ast.Subscript):
+        value = resolve_type(node.value, namespace)
+        if isinstance(node.slice, ast.Tuple):
+            return value[tuple(resolve_type(e, namespace) for e in node.slice.elts)]
+        return value[resolve_type(node.slice, namespace)]
+    if isinstance(node, ast.List):
+        # Parameters of `Callable[[int], str]`
+        return [resolve_type(e, namespace) for e in node.elts]
+    if isinstance(node, ast.BinOp) and isinstance(node.op, ast.BitOr):
+        return resolve_type(node.left, namespace) | resolve_type(node.right, namespace)
+    if isinstance(node, ast.Constant) and node.value is None:
+        return None
+    raise ValueError(f"Unsupported type expression: {ast.dump(node)}")
+
+
+def type_definition_doc(ty: typing.NewType) -> str | None:
+    # `NewType` instances inherit the docstring of `typing.NewType` itself
+    if ty.__doc__ is None or ty.__doc__ is type(ty).__doc__:
+        return None
+    return inspect.cleandoc(ty.__doc__)
+
+
+# Variants of `@typing.overload` keyed by `(__module__, __qualname__)`,
+# recorded by `recording_overloads` before Python 3.11
+_overloads = collections.defaultdict(list)
+
+
+@contextlib.contextmanager
+def recording_overloads(target: str):
+    """Record overload variants defined while importing the module
+
+    `typing.get_overloads` is available since Python 3.11. Before that, `typing.overload`
+    is replaced during the import so that the variants are kept as Python 3.11 does.
+    """
+    if hasattr(typing, "get_overloads"):
+        yield
+        return
+    if target in sys.modules:
+        print(
+            f"warning: `{target}` is already imported, "
+            "overloads cannot be found before Python 3.11",
+            file=sys.stderr,
+        )
+    original = typing.overload
+
+    def overload(func):
+        f = getattr(func, "__func__", func)
+        _overloads[(f.__module__, f.__qualname__)].append(func)
+        return original(func)
+
+    typing.overload = overload
+    try:
+        yield
+    finally:
+        typing.overload = original
+
+
+def get_overloads(f) -> list:
+    if hasattr(typing, "get_overloads"):
+        return typing.get_overloads(f)
+    f = getattr(f, "__func__", f)
+    key = (getattr(f, "__module__", None), getattr(f, "__qualname__", None))
+    return list(_overloads.get(key, []))
+
+
+def inspect_parameter(
+    name: str, p: inspect.Parameter, section: dict, namespace: dict, infer: bool
+) -> dict:
+    ty = type_as_tag(p.annotation)
+    inferred = False
+    if ty["kind"] == "unknown" and infer and section.get("type"):
+        tag = docstring_type_as_tag(section["type"], namespace)
+        if tag is not None:
+            ty = tag
+            inferred = True
+    return {
+        "name": name,
+        "type": ty,
+        "doc": section.get("doc") or None,
+        "inferred": inferred,
+    }
+
+
+def inspect_function(
+    name: str,
+    f,
+    infer_from_docstring: bool = False,
+    param_sections=None,
+    overloads: bool = True,
+) -> dict:
+    sig = inspect.signature(f, eval_str=True)
+    doc = inspect.getdoc(f)
+    sections = {}
+    if param_sections is not None and doc is not None:
+        sections = json.loads(param_sections(doc))
+    return {
+        "name": name,
+        "parameters": [
+            inspect_parameter(
+                name,
+                p,
+                sections.get(name, {}),
+                getattr(f, "__globals__", {}),
+                infer_from_docstring,
+            )
+            for name, p in sig.parameters.items()
+        ],
+        "return": type_as_tag(sig.return_annotation),
+        "doc": doc,
+        "signature": f"def {name}{sig}",
+        "overloads": [
+            inspect_function(
+                name, o, infer_from_docstring, param_sections, overloads=False
+            )
+            for o in get_overloads(f)
+        ]
+        if overloads
+        else [],
+    }
+
+
+def load_stub(module, parse_stub) -> dict:
+    """Interface declared by the `.pyi` stub of the module, or empty if not found
+
+    The stub is found and read by `parse_stub` without executing it, since stubs may import
+    modules which only exist for type checkers, e.g. `_typeshed`.
+    If the stub cannot be read, the module is inspected at runtime alone.
+    """
+    if parse_stub is None:
+        return {}
+    try:
+        stub = parse_stub(module.__name__, getattr(module, "__file__", None))
+    except Exception as e:
+        print(
+            f"warning: Cannot read stub of {module.__name__}, ignored: {e}",
+            file=sys.stderr,
+        )
+        return {}
+    return {} if stub is None else json.loads(stub)
+
+
+def inspect_routine(
+    name: str, f, stub: dict, infer_from_docstring: bool, param_sections
+) -> dict | None:
+    if name in stub.get("functions", {}):
+        interface = stub["functions"][name]
+        interface["doc"] = interface["doc"] or inspect.getdoc(f)
+        return interface
+    try:
+        # Use annotations of the Python function or `__text_signature__` of the builtin function
+        interface = inspect_function(name, f, infer_from_docstring, param_sections)
+    except ValueError:
+        # Neither stub nor `__text_signature__` exists
+        return None
+    interface["source"] = "runtime" if inspect.isfunction(f) else "text_signature"
+    return interface
+
+
+def inspect_type_definition(attr, source: str) -> dict:
+    return {
+        "module": attr.__module__,
+        "name": attr.__name__,
+        "supertype": type_as_tag(attr.__supertype__),
+        "doc": type_definition_doc(attr),
+        "source": source,
+    }
+
+
+def inspect_module(
+    target: str,
+    infer_from_docstring: bool = False,
+    parse_stub=None,
+    param_sections=None,
+) -> str:
+    """Interface of the module as JSON
+
+    `parse_stub(module_name, module_file)` returns the interface declared by the stub
+    of the module as JSON, or `None` if not found, which is given by py2o2.
+    Stubs are not used without it.
+    `param_sections(doc)` returns the types and descriptions of parameters in a docstring as JSON,
+    which is also given by py2o2. Parameters are not documented without it.
+    """
+    with recording_overloads(target):
+        module = importlib.import_module(target)
+    stub = load_stub(module, parse_stub)
     interface = {"functions": {}, "type_definitions": {}}
+    without_signature = []
     for name, attr in inspect.getmembers(module):
-        if inspect.isfunction(attr):
-            sig = inspect.signature(getattr(module, name))
-            interface["functions"][name] = {
-                "name": name,
-                "parameters": [
-                    {"name": name, "type": type_as_tag(p.annotation)}
-                    for name, p in sig.parameters.items()
-                ],
-                "return": type_as_tag(sig.return_annotation),
-            }
+        if inspect.isroutine(attr):
+            f = inspect_routine(
+                name, attr, stub, infer_from_docstring, param_sections
+            )
+            if f is not None:
+                interface["functions"][name] = f
+            else:
+                without_signature.append(name)
         if type(attr) == typing.NewType:
-            interface["type_definitions"][name] = {
-                "module": attr.__module__,
-                "name": attr.__name__,
-                "supertype": type_as_tag(attr.__supertype__),
-            }
+            interface["type_definitions"][name] = inspect_type_definition(
+                attr, "runtime"
+            )
+    if without_signature:
+        # Omitted if empty, as `Interface` in Rust serializes it
+        interface["without_signature"] = without_signature
+    # NewType only declared in the stub does not exist at runtime,
+    # but is still usable as a Rust type
+    interface["type_definitions"].update(stub.get("type_definitions", {}))
     return json.dumps(interface, indent=4)
 
 
@@ -0,0 +1,27 @@
+"""Markers for `typing.Annotated` to steer code generation of py2o2
+
+This module is registered as `py2o2` by the inspector and the generated bindings
+unless another `py2o2` module is importable, see `py2o2_runtime::import_markers`.
+Copy it onto `sys.path` to import the annotated module without py2o2.
+"""
+
+from dataclasses import dataclass
+
+
+@dataclass(frozen=True)
+class Rust:
+    """Use the Rust type instead, e.g. `Annotated[int, Rust("u32")]`"""
+
+    type: str
+
+
+@dataclass(frozen=True)
+class Rename:
+    """Rename the parameter in Rust, e.g. `Annotated[str, Rename("kind")]`"""
+
+    name: str
+
+
+@dataclass(frozen=True)
+class Skip:
+    """Do not generate the function if used in the annotation of its return or any parameter"""
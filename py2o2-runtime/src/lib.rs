@@ -1,5 +1,13 @@
 pub use pyo3;
-use pyo3::{conversion::*, exceptions::*, prelude::*, type_object::*, types::*};
+use pyo3::{
+    conversion::*,
+    exceptions::*,
+    prelude::*,
+    sync::{GILOnceCell, GILProtected},
+    type_object::*,
+    types::*,
+};
+use std::{cell::RefCell, collections::BTreeMap};
 
 /// Types defined in Python user land (not in Python's runtime)
 ///
@@ -69,6 +77,63 @@ macro_rules! import_pytype {
     };
 }
 
+/// Class object `type[T]` of a Python class `T` or its subclasses
+pub struct ClassObject<T> {
+    inner: Py<PyType>,
+    _class: std::marker::PhantomData<T>,
+}
+
+impl<T: PyTypeInfoUser> ClassObject<T> {
+    /// Class object of `T` itself
+    pub fn new(py: Python<'_>) -> PyResult<Self> {
+        Ok(ClassObject {
+            inner: T::type_object(py)?.into(),
+            _class: std::marker::PhantomData,
+        })
+    }
+
+    pub fn as_ref<'py>(&'py self, py: Python<'py>) -> &'py PyType {
+        self.inner.as_ref(py)
+    }
+}
+
+impl<T> Clone for ClassObject<T> {
+    fn clone(&self) -> Self {
+        ClassObject {
+            inner: self.inner.clone(),
+            _class: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: PyTypeInfoUser> std::fmt::Debug for ClassObject<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClassObject<{}>({:?})", T::path(), self.inner)
+    }
+}
+
+impl<'py, T: PyTypeInfoUser> FromPyObject<'py> for ClassObject<T> {
+    fn extract(ob: &'py PyAny) -> PyResult<Self> {
+        let ty: &PyType = ob.downcast()?;
+        if !ty.is_subclass(T::type_object(ob.py())?)? {
+            return Err(PyTypeError::new_err(format!(
+                "Not a subclass of {}",
+                T::path()
+            )));
+        }
+        Ok(ClassObject {
+            inner: ty.into(),
+            _class: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<T> IntoPy<PyObject> for ClassObject<T> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.inner.into_py(py)
+    }
+}
+
 pub trait AsPyType {
     fn is_type_of(obj: &PyAny) -> bool;
 }
@@ -127,7 +192,10 @@ where
         py,
         None,
         None,
-        move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<Py<PyAny>> {
+        move |args: &PyTuple, kwargs: Option<&PyDict>| -> PyResult<Py<PyAny>> {
+            if kwargs.is_some_and(|kwargs| !kwargs.is_empty()) {
+                return Err(PyTypeError::new_err("Keyword arguments are not supported"));
+            }
             let input: Input = args.extract()?;
             let out = f(input);
             Python::with_gil(|py2| Ok(out.into_py(py2)))
@@ -135,6 +203,139 @@ where
     )
 }
 
+/// Similar to [`as_pycfunc`], but `f` takes positional and keyword arguments as they are
+pub fn as_pycfunc_with_kwargs<F, Output>(py: Python<'_>, f: F) -> PyResult<&PyCFunction>
+where
+    F: Fn(&PyTuple, Option<&PyDict>) -> PyResult<Output> + Send + 'static,
+    Output: IntoPy<Py<PyAny>>,
+{
+    PyCFunction::new_closure(
+        py,
+        None,
+        None,
+        move |args: &PyTuple, kwargs: Option<&PyDict>| -> PyResult<Py<PyAny>> {
+            let out = f(args, kwargs)?;
+            Ok(out.into_py(args.py()))
+        },
+    )
+}
+
+/// Check that `n_args` positional arguments and keyword arguments only in `keywords` are given,
+/// otherwise raise `TypeError` as Python functions do instead of dropping the others
+pub fn check_arguments(
+    args: &PyTuple,
+    kwargs: Option<&PyDict>,
+    n_args: usize,
+    keywords: &[&str],
+) -> PyResult<()> {
+    if args.len() != n_args {
+        return Err(PyTypeError::new_err(format!(
+            "Callback takes {} positional arguments but {} were given",
+            n_args,
+            args.len()
+        )));
+    }
+    for key in kwargs.into_iter().flat_map(|kwargs| kwargs.keys()) {
+        let key: &str = key.extract()?;
+        if !keywords.contains(&key) {
+            return Err(PyTypeError::new_err(format!(
+                "Callback got an unexpected keyword argument '{}'",
+                key
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Extract a keyword argument, which is `None` if not given
+pub fn extract_keyword<'py, T>(kwargs: Option<&'py PyDict>, name: &str) -> PyResult<Option<T>>
+where
+    T: FromPyObject<'py>,
+{
+    match kwargs {
+        Some(kwargs) => kwargs.get_item(name)?.map(|v| v.extract()).transpose(),
+        None => Ok(None),
+    }
+}
+
+/// Python module imported at the first use, and its attributes looked up once, for generated bindings
+///
+/// `importlib.reload` updates the module object in place, so the module itself is kept in [GILOnceCell],
+/// while the cached attributes, e.g. function objects, are dropped by [ModuleCache::reload].
+pub struct ModuleCache {
+    name: &'static str,
+    /// Whether `py2o2` is imported by [import_markers] before the module
+    markers: bool,
+    module: GILOnceCell<Py<PyModule>>,
+    attrs: GILProtected<RefCell<BTreeMap<&'static str, PyObject>>>,
+}
+
+/// Source of the `py2o2` Python module defining markers for `typing.Annotated`, e.g. `py2o2.Rust("u8")`
+pub const MARKERS: &str = include_str!("../py2o2.py");
+
+/// Import `py2o2` used by Python modules annotated with the markers
+///
+/// It is registered into `sys.modules` from [MARKERS] unless another `py2o2` module is importable.
+pub fn import_markers(py: Python<'_>) -> PyResult<&PyModule> {
+    match py.import("py2o2") {
+        Err(err) if err.is_instance_of::<PyModuleNotFoundError>(py) => {
+            PyModule::from_code(py, MARKERS, "py2o2.py", "py2o2")
+        }
+        result => result,
+    }
+}
+
+impl ModuleCache {
+    pub const fn new(name: &'static str) -> Self {
+        ModuleCache {
+            name,
+            markers: false,
+            module: GILOnceCell::new(),
+            attrs: GILProtected::new(RefCell::new(BTreeMap::new())),
+        }
+    }
+
+    /// Cache of a module annotated with the markers, where `py2o2` is imported by [import_markers] first
+    pub const fn with_markers(name: &'static str) -> Self {
+        ModuleCache {
+            name,
+            markers: true,
+            module: GILOnceCell::new(),
+            attrs: GILProtected::new(RefCell::new(BTreeMap::new())),
+        }
+    }
+
+    pub fn module<'py>(&self, py: Python<'py>) -> PyResult<&'py PyModule> {
+        let module = self.module.get_or_try_init(py, || {
+            if self.markers {
+                import_markers(py)?;
+            }
+            py.import(self.name).map(Into::into)
+        })?;
+        Ok(module.clone_ref(py).into_ref(py))
+    }
+
+    /// Attribute of the module, e.g. a function, which is looked up again only after [ModuleCache::reload]
+    pub fn getattr<'py>(&self, py: Python<'py>, attr: &'static str) -> PyResult<&'py PyAny> {
+        if let Some(value) = self.attrs.get(py).borrow().get(attr) {
+            return Ok(value.clone_ref(py).into_ref(py));
+        }
+        let value = self.module(py)?.getattr(attr)?;
+        self.attrs.get(py).borrow_mut().insert(attr, value.into());
+        Ok(value)
+    }
+
+    /// Reload the module by `importlib.reload`, e.g. after its source is edited
+    pub fn reload(&self, py: Python<'_>) -> PyResult<()> {
+        let module = self.module(py)?;
+        py.import("importlib")?
+            .getattr("reload")?
+            .call1((module,))?;
+        self.attrs.get(py).borrow_mut().clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,4 +363,45 @@ mod tests {
         })?;
         Ok(())
     }
+
+    #[test]
+    fn reload() -> Result<()> {
+        static CACHE: ModuleCache = ModuleCache::new("py2o2_runtime_reload");
+        let dir = std::env::temp_dir().join(format!("py2o2-runtime-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("py2o2_runtime_reload.py");
+        Python::with_gil(|py| -> Result<()> {
+            // Without `.pyc`, the source is read again even if modified within its mtime resolution
+            py.import("sys")?.setattr("dont_write_bytecode", true)?;
+            py.import("sys")?
+                .getattr("path")?
+                .call_method1("insert", (0, &dir))?;
+
+            std::fs::write(&path, "def f():\n    return 1\n")?;
+            let f = CACHE.getattr(py, "f")?;
+            assert_eq!(f.call0()?.extract::<i64>()?, 1);
+            assert!(CACHE.getattr(py, "f")?.is(f));
+
+            std::fs::write(&path, "def f():\n    return 2\n")?;
+            assert_eq!(CACHE.getattr(py, "f")?.call0()?.extract::<i64>()?, 1);
+            CACHE.reload(py)?;
+            assert_eq!(CACHE.getattr(py, "f")?.call0()?.extract::<i64>()?, 2);
+            Ok(())
+        })?;
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn markers() -> Result<()> {
+        Python::with_gil(|py| -> Result<()> {
+            let py2o2 = import_markers(py)?;
+            let rust = py2o2.getattr("Rust")?.call1(("u8",))?;
+            assert_eq!(rust.getattr("type")?.extract::<&str>()?, "u8");
+            // Registered into `sys.modules`
+            assert!(import_markers(py)?.is(py2o2));
+            assert!(py.import("py2o2")?.is(py2o2));
+            Ok(())
+        })
+    }
 }
@@ -0,0 +1,53 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache =
+    ::py2o2_runtime::ModuleCache::with_markers("annotated");
+/// # Python signature
+///
+/// ```python
+/// def narrow(x: typing.Annotated[int, Rust(type='u8')]) -> typing.Annotated[int, Rust(type='u8')]
+/// ```
+pub fn narrow<'py>(py: ::pyo3::Python<'py>, x: u8) -> ::pyo3::PyResult<u8> {
+    let result = MODULE.getattr(py, "narrow")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def other_metadata(x: typing.Annotated[float, 'unit: meter']) -> float
+/// ```
+pub fn other_metadata<'py>(py: ::pyo3::Python<'py>, x: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "other_metadata")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def rename(type: typing.Annotated[str, Rename(name='kind')]) -> str
+/// ```
+pub fn rename<'py>(
+    py: ::pyo3::Python<'py>,
+    kind: &str,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let result = MODULE.getattr(py, "rename")?.call((kind,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::narrow`], but acquires the GIL and returns an owned value
+    pub fn narrow(x: u8) -> ::pyo3::PyResult<u8> {
+        ::pyo3::Python::with_gil(|py| super::narrow(py, x))
+    }
+    /// Same as [`super::other_metadata`], but acquires the GIL and returns an owned value
+    pub fn other_metadata(x: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::other_metadata(py, x))
+    }
+    /// Same as [`super::rename`], but acquires the GIL and returns an owned value
+    pub fn rename(kind: &str) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::rename(py, kind)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+}
@@ -1,43 +1,211 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("callable");
+/// # Python signature
+///
+/// ```python
+/// def async_query(on_success: collections.abc.Callable[[int], None], on_error: collections.abc.Callable[[int, Exception], None]) -> None
+/// ```
 pub fn async_query<'py>(
     py: ::pyo3::Python<'py>,
-    on_success: impl Fn((i64,)) -> () + Send + 'static,
-    on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) -> () + Send + 'static,
+    on_success: impl Fn((i64,)) + Send + 'static,
+    on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) + Send + 'static,
 ) -> ::pyo3::PyResult<()> {
     let on_success = ::py2o2_runtime::as_pycfunc(py, on_success)?;
     let on_error = ::py2o2_runtime::as_pycfunc(py, on_error)?;
-    let _ = py
-        .import("callable")?
-        .getattr("async_query")?
+    let _ = MODULE
+        .getattr(py, "async_query")?
         .call((on_success, on_error), None)?;
     Ok(())
 }
+/// # Python signature
+///
+/// ```python
+/// def caller(f: collections.abc.Callable[[int, float], float]) -> None
+/// ```
 pub fn caller<'py>(
     py: ::pyo3::Python<'py>,
     f: impl Fn((i64, f64)) -> f64 + Send + 'static,
 ) -> ::pyo3::PyResult<()> {
     let f = ::py2o2_runtime::as_pycfunc(py, f)?;
-    let _ = py.import("callable")?.getattr("caller")?.call((f,), None)?;
+    let _ = MODULE.getattr(py, "caller")?.call((f,), None)?;
     Ok(())
 }
+/// # Python signature
+///
+/// ```python
+/// def concatenate(f: collections.abc.Callable[typing.Concatenate[int, ~P], float]) -> float
+/// ```
+pub fn concatenate<'py>(
+    py: ::pyo3::Python<'py>,
+    f: impl Fn((i64,), &::pyo3::types::PyTuple, Option<&::pyo3::types::PyDict>) -> f64 + Send + 'static,
+) -> ::pyo3::PyResult<f64> {
+    let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+        py,
+        move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+            Ok(f(
+                args.get_slice(0, 1).extract()?,
+                args.get_slice(1, args.len()),
+                kwargs,
+            ))
+        },
+    )?;
+    let result = MODULE.getattr(py, "concatenate")?.call((f,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def ellipsis_callable(f: collections.abc.Callable[..., None]) -> None
+/// ```
 pub fn ellipsis_callable<'py>(
     py: ::pyo3::Python<'py>,
-    f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) -> () + Send + 'static,
+    f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) + Send + 'static,
 ) -> ::pyo3::PyResult<()> {
     let f = ::py2o2_runtime::as_pycfunc(py, f)?;
-    let _ = py
-        .import("callable")?
-        .getattr("ellipsis_callable")?
-        .call((f,), None)?;
+    let _ = MODULE.getattr(py, "ellipsis_callable")?.call((f,), None)?;
     Ok(())
 }
+/// # Python signature
+///
+/// ```python
+/// def feeder(get_next_item: collections.abc.Callable[[], str]) -> None
+/// ```
 pub fn feeder<'py>(
     py: ::pyo3::Python<'py>,
     get_next_item: impl Fn() -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
 ) -> ::pyo3::PyResult<()> {
     let get_next_item = ::py2o2_runtime::as_pycfunc(py, move |_input: [usize; 0]| get_next_item())?;
-    let _ = py
-        .import("callable")?
-        .getattr("feeder")?
-        .call((get_next_item,), None)?;
+    let _ = MODULE.getattr(py, "feeder")?.call((get_next_item,), None)?;
     Ok(())
 }
+/// # Python signature
+///
+/// ```python
+/// def keyword_callback(f: callable.Formatter) -> str
+/// ```
+pub fn keyword_callback<'py>(
+    py: ::pyo3::Python<'py>,
+    f: impl Fn((i64,), (Option<&str>,)) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+        py,
+        move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+            ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+            Ok(f(
+                args.get_slice(0, 1).extract()?,
+                (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+            ))
+        },
+    )?;
+    let result = MODULE.getattr(py, "keyword_callback")?.call((f,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def unexpected_keyword(f: callable.Formatter) -> str
+/// ```
+pub fn unexpected_keyword<'py>(
+    py: ::pyo3::Python<'py>,
+    f: impl Fn((i64,), (Option<&str>,)) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+        py,
+        move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+            ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+            Ok(f(
+                args.get_slice(0, 1).extract()?,
+                (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+            ))
+        },
+    )?;
+    let result = MODULE.getattr(py, "unexpected_keyword")?.call((f,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def unexpected_positional(f: callable.Formatter) -> str
+/// ```
+pub fn unexpected_positional<'py>(
+    py: ::pyo3::Python<'py>,
+    f: impl Fn((i64,), (Option<&str>,)) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+        py,
+        move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+            ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+            Ok(f(
+                args.get_slice(0, 1).extract()?,
+                (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+            ))
+        },
+    )?;
+    let result = MODULE
+        .getattr(py, "unexpected_positional")?
+        .call((f,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::async_query`], but acquires the GIL and returns an owned value
+    pub fn async_query(
+        on_success: impl Fn((i64,)) + Send + 'static,
+        on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) + Send + 'static,
+    ) -> ::pyo3::PyResult<()> {
+        ::pyo3::Python::with_gil(|py| super::async_query(py, on_success, on_error))
+    }
+    /// Same as [`super::caller`], but acquires the GIL and returns an owned value
+    pub fn caller(f: impl Fn((i64, f64)) -> f64 + Send + 'static) -> ::pyo3::PyResult<()> {
+        ::pyo3::Python::with_gil(|py| super::caller(py, f))
+    }
+    /// Same as [`super::concatenate`], but acquires the GIL and returns an owned value
+    pub fn concatenate(
+        f: impl Fn((i64,), &::pyo3::types::PyTuple, Option<&::pyo3::types::PyDict>) -> f64
+            + Send
+            + 'static,
+    ) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::concatenate(py, f))
+    }
+    /// Same as [`super::ellipsis_callable`], but acquires the GIL and returns an owned value
+    pub fn ellipsis_callable(
+        f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) + Send + 'static,
+    ) -> ::pyo3::PyResult<()> {
+        ::pyo3::Python::with_gil(|py| super::ellipsis_callable(py, f))
+    }
+    /// Same as [`super::feeder`], but acquires the GIL and returns an owned value
+    pub fn feeder(
+        get_next_item: impl Fn() -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<()> {
+        ::pyo3::Python::with_gil(|py| super::feeder(py, get_next_item))
+    }
+    /// Same as [`super::keyword_callback`], but acquires the GIL and returns an owned value
+    pub fn keyword_callback(
+        f: impl Fn((i64,), (Option<&str>,)) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::keyword_callback(py, f)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::unexpected_keyword`], but acquires the GIL and returns an owned value
+    pub fn unexpected_keyword(
+        f: impl Fn((i64,), (Option<&str>,)) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::unexpected_keyword(py, f)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::unexpected_positional`], but acquires the GIL and returns an owned value
+    pub fn unexpected_positional(
+        f: impl Fn((i64,), (Option<&str>,)) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::unexpected_positional(py, f)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+}
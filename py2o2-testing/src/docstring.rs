@@ -0,0 +1,110 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("docstring");
+/// Length in pixels
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pixel(pub i64);
+impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for Pixel {
+    fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+        self.0.into_py(py)
+    }
+}
+/// Greet in Google style.
+///
+/// ```text
+/// Args:
+///     x (int): Number of greetings.
+///     name: Whom to greet. Long descriptions
+///         may continue on the next line.
+///
+/// Returns:
+///     Greeting message.
+/// ```
+///
+/// # Python signature
+///
+/// ```python
+/// def google(x: int, name: str) -> str
+/// ```
+pub fn google<'py>(
+    py: ::pyo3::Python<'py>,
+    x: i64,
+    name: &str,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let result = MODULE.getattr(py, "google")?.call((x, name), None)?;
+    result.extract()
+}
+/// Area of a rectangle in NumPy style.
+///
+/// ```text
+/// Parameters
+/// ----------
+/// width : Pixel
+///     Width of the rectangle.
+/// height : Pixel
+///     Height of the rectangle.
+///
+/// Returns
+/// -------
+/// int
+///     Area of the rectangle.
+/// ```
+///
+/// # Python signature
+///
+/// ```python
+/// def numpy(width: docstring.Pixel, height: docstring.Pixel) -> int
+/// ```
+pub fn numpy<'py>(py: ::pyo3::Python<'py>, width: Pixel, height: Pixel) -> ::pyo3::PyResult<i64> {
+    let result = MODULE.getattr(py, "numpy")?.call((width, height), None)?;
+    result.extract()
+}
+/// Sum of two numbers in Sphinx style.
+///
+/// ```text
+/// :param a: The first number.
+/// :param float b: The second number.
+/// :return: The sum.
+/// ```
+///
+/// # Python signature
+///
+/// ```python
+/// def sphinx(a: float, b: float) -> float
+/// ```
+pub fn sphinx<'py>(py: ::pyo3::Python<'py>, a: f64, b: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "sphinx")?.call((a, b), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def undocumented(a: int) -> None
+/// ```
+pub fn undocumented<'py>(py: ::pyo3::Python<'py>, a: i64) -> ::pyo3::PyResult<()> {
+    let _ = MODULE.getattr(py, "undocumented")?.call((a,), None)?;
+    Ok(())
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::google`], but acquires the GIL and returns an owned value
+    pub fn google(x: i64, name: &str) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::google(py, x, name)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::numpy`], but acquires the GIL and returns an owned value
+    pub fn numpy(width: Pixel, height: Pixel) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| super::numpy(py, width, height))
+    }
+    /// Same as [`super::sphinx`], but acquires the GIL and returns an owned value
+    pub fn sphinx(a: f64, b: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::sphinx(py, a, b))
+    }
+    /// Same as [`super::undocumented`], but acquires the GIL and returns an owned value
+    pub fn undocumented(a: i64) -> ::pyo3::PyResult<()> {
+        ::pyo3::Python::with_gil(|py| super::undocumented(py, a))
+    }
+}
@@ -1,36 +1,135 @@
-pub fn a1<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<()> {
-    let _ = py.import("example")?.getattr("a1")?.call((), None)?;
-    Ok(())
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("example");
+/// # Python signature
+///
+/// ```python
+/// def a1()
+/// ```
+pub fn a1<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<::pyo3::PyObject> {
+    let result = MODULE.getattr(py, "a1")?.call((), None)?;
+    result.extract()
 }
-pub fn a2<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<()> {
-    let _ = py.import("example")?.getattr("a2")?.call((x,), None)?;
-    Ok(())
+/// # Python signature
+///
+/// ```python
+/// def a2(x: int)
+/// ```
+pub fn a2<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<::pyo3::PyObject> {
+    let result = MODULE.getattr(py, "a2")?.call((x,), None)?;
+    result.extract()
 }
-pub fn a3<'py>(py: ::pyo3::Python<'py>, y: &str, z: f64) -> ::pyo3::PyResult<()> {
-    let _ = py.import("example")?.getattr("a3")?.call((y, z), None)?;
-    Ok(())
+/// # Python signature
+///
+/// ```python
+/// def a3(y: str, z: float)
+/// ```
+pub fn a3<'py>(py: ::pyo3::Python<'py>, y: &str, z: f64) -> ::pyo3::PyResult<::pyo3::PyObject> {
+    let result = MODULE.getattr(py, "a3")?.call((y, z), None)?;
+    result.extract()
 }
+/// # Python signature
+///
+/// ```python
+/// def a4() -> int
+/// ```
 pub fn a4<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<i64> {
-    let result = py.import("example")?.getattr("a4")?.call((), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "a4")?.call((), None)?;
+    result.extract()
 }
+/// # Python signature
+///
+/// ```python
+/// def a5(x: int) -> str
+/// ```
 pub fn a5<'py>(
     py: ::pyo3::Python<'py>,
     x: i64,
 ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
-    let result = py.import("example")?.getattr("a5")?.call((x,), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "a5")?.call((x,), None)?;
+    result.extract()
 }
+/// # Python signature
+///
+/// ```python
+/// def a6() -> tuple[int, str]
+/// ```
 pub fn a6<'py>(
     py: ::pyo3::Python<'py>,
 ) -> ::pyo3::PyResult<(i64, ::pyo3::Py<::pyo3::types::PyString>)> {
-    let result = py.import("example")?.getattr("a6")?.call((), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "a6")?.call((), None)?;
+    result.extract()
 }
+/// # Python signature
+///
+/// ```python
+/// def a7(x: int) -> tuple[int, str, float]
+/// ```
 pub fn a7<'py>(
     py: ::pyo3::Python<'py>,
     x: i64,
 ) -> ::pyo3::PyResult<(i64, ::pyo3::Py<::pyo3::types::PyString>, f64)> {
-    let result = py.import("example")?.getattr("a7")?.call((x,), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "a7")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def a8(x, y)
+/// ```
+pub fn a8<'py>(
+    py: ::pyo3::Python<'py>,
+    x: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    y: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+) -> ::pyo3::PyResult<::pyo3::PyObject> {
+    let result = MODULE.getattr(py, "a8")?.call((x, y), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::a1`], but acquires the GIL and returns an owned value
+    pub fn a1() -> ::pyo3::PyResult<::pyo3::PyObject> {
+        ::pyo3::Python::with_gil(super::a1)
+    }
+    /// Same as [`super::a2`], but acquires the GIL and returns an owned value
+    pub fn a2(x: i64) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        ::pyo3::Python::with_gil(|py| super::a2(py, x))
+    }
+    /// Same as [`super::a3`], but acquires the GIL and returns an owned value
+    pub fn a3(y: &str, z: f64) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        ::pyo3::Python::with_gil(|py| super::a3(py, y, z))
+    }
+    /// Same as [`super::a4`], but acquires the GIL and returns an owned value
+    pub fn a4() -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(super::a4)
+    }
+    /// Same as [`super::a5`], but acquires the GIL and returns an owned value
+    pub fn a5(x: i64) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::a5(py, x)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::a6`], but acquires the GIL and returns an owned value
+    pub fn a6() -> ::pyo3::PyResult<(i64, String)> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::a6(py)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::a7`], but acquires the GIL and returns an owned value
+    pub fn a7(x: i64) -> ::pyo3::PyResult<(i64, String, f64)> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::a7(py, x)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::a8`], but acquires the GIL and returns an owned value
+    pub fn a8(
+        x: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+        y: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        ::pyo3::Python::with_gil(|py| super::a8(py, x, y))
+    }
 }
@@ -0,0 +1,66 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("fastmath");
+/// Return a float with the magnitude (absolute value) of x but the sign of y.
+///
+/// ```text
+/// On platforms that support signed zeros, copysign(1.0, -0.0)
+/// returns -1.0.
+/// ```
+///
+/// # Python signature
+///
+/// ```python
+/// def copysign(x: float, y: float, /) -> float
+/// ```
+pub fn copysign<'py>(py: ::pyo3::Python<'py>, x: f64, y: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "copysign")?.call((x, y), None)?;
+    result.extract()
+}
+/// Find n!.
+///
+/// ```text
+/// Raise a ValueError if x is negative or non-integral.
+/// ```
+///
+/// # Python signature
+///
+/// ```python
+/// def factorial(n, /)
+/// ```
+pub fn factorial<'py>(
+    py: ::pyo3::Python<'py>,
+    n: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+) -> ::pyo3::PyResult<::pyo3::PyObject> {
+    let result = MODULE.getattr(py, "factorial")?.call((n,), None)?;
+    result.extract()
+}
+/// Return the square root of x.
+///
+/// # Python signature
+///
+/// ```python
+/// def sqrt(x: float, /) -> float
+/// ```
+pub fn sqrt<'py>(py: ::pyo3::Python<'py>, x: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "sqrt")?.call((x,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::copysign`], but acquires the GIL and returns an owned value
+    pub fn copysign(x: f64, y: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::copysign(py, x, y))
+    }
+    /// Same as [`super::factorial`], but acquires the GIL and returns an owned value
+    pub fn factorial(
+        n: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        ::pyo3::Python::with_gil(|py| super::factorial(py, n))
+    }
+    /// Same as [`super::sqrt`], but acquires the GIL and returns an owned value
+    pub fn sqrt(x: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::sqrt(py, x))
+    }
+}
@@ -0,0 +1,55 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("greek");
+/// # Python signature
+///
+/// ```python
+/// def find(h: list[str], x: str) -> int
+/// ```
+pub fn find<'py>(
+    py: ::pyo3::Python<'py>,
+    h: &::pyo3::types::PyList,
+    x: &str,
+) -> ::pyo3::PyResult<i64> {
+    let result = MODULE.getattr(py, "find")?.call((h, x), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def gaussian(x: float, μ: float, σ: float) -> float
+/// ```
+pub fn gaussian<'py>(py: ::pyo3::Python<'py>, x: f64, μ: f64, σ: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "gaussian")?.call((x, μ, σ), None)?;
+    result.extract()
+}
+/// Frequency of light from its wavelength
+///
+/// # Python signature
+///
+/// ```python
+/// def λ_to_ν(λ: float) -> float
+/// ```
+pub fn λ_to_ν<'py>(py: ::pyo3::Python<'py>, λ: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "λ_to_ν")?.call((λ,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::find`], but acquires the GIL and returns an owned value
+    pub fn find(h: Vec<String>, x: &str) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| {
+            let h = ::pyo3::types::PyList::new(py, h);
+            super::find(py, h, x)
+        })
+    }
+    /// Same as [`super::gaussian`], but acquires the GIL and returns an owned value
+    pub fn gaussian(x: f64, μ: f64, σ: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::gaussian(py, x, μ, σ))
+    }
+    /// Same as [`super::λ_to_ν`], but acquires the GIL and returns an owned value
+    pub fn λ_to_ν(λ: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::λ_to_ν(py, λ))
+    }
+}
@@ -0,0 +1,35 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("keywords");
+/// # Python signature
+///
+/// ```python
+/// def f(type: int, match: str) -> int
+/// ```
+pub fn f<'py>(py: ::pyo3::Python<'py>, r#type: i64, r#match: &str) -> ::pyo3::PyResult<i64> {
+    let result = MODULE.getattr(py, "f")?.call((r#type, r#match), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def move(self: int, _: int) -> int
+/// ```
+pub fn r#move<'py>(py: ::pyo3::Python<'py>, self_: i64, underscore: i64) -> ::pyo3::PyResult<i64> {
+    let result = MODULE
+        .getattr(py, "move")?
+        .call((self_, underscore), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::f`], but acquires the GIL and returns an owned value
+    pub fn f(r#type: i64, r#match: &str) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| super::f(py, r#type, r#match))
+    }
+    /// Same as [`super::r#move`], but acquires the GIL and returns an owned value
+    pub fn r#move(self_: i64, underscore: i64) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| super::r#move(py, self_, underscore))
+    }
+}
@@ -0,0 +1,61 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("legacy");
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UserId(pub i64);
+impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
+    fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+        self.0.into_py(py)
+    }
+}
+/// # Python signature
+///
+/// ```python
+/// def answer() -> int
+/// ```
+pub fn answer<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<i64> {
+    let result = MODULE.getattr(py, "answer")?.call((), None)?;
+    result.extract()
+}
+/// Multiply `x` by `factor`
+///
+/// # Python signature
+///
+/// ```python
+/// def scale(x: float, factor: float) -> float
+/// ```
+pub fn scale<'py>(py: ::pyo3::Python<'py>, x: f64, factor: f64) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "scale")?.call((x, factor), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def user_name(user_id: UserId) -> str
+/// ```
+pub fn user_name<'py>(
+    py: ::pyo3::Python<'py>,
+    user_id: UserId,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let result = MODULE.getattr(py, "user_name")?.call((user_id,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::answer`], but acquires the GIL and returns an owned value
+    pub fn answer() -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(super::answer)
+    }
+    /// Same as [`super::scale`], but acquires the GIL and returns an owned value
+    pub fn scale(x: f64, factor: f64) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| super::scale(py, x, factor))
+    }
+    /// Same as [`super::user_name`], but acquires the GIL and returns an owned value
+    pub fn user_name(user_id: UserId) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::user_name(py, user_id)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+}
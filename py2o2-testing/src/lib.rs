@@ -4,8 +4,16 @@ use anyhow::Result;
 use py2o2_runtime::Enum2;
 use pyo3::{prelude::*, types::*, Python};
 
+pub mod annotated;
 pub mod callable;
+pub mod docstring;
 pub mod example;
+pub mod fastmath;
+pub mod greek;
+pub mod keywords;
+pub mod legacy;
+pub mod overload;
+pub mod special_forms;
 pub mod type_aliases;
 pub mod union;
 
@@ -26,6 +34,10 @@ fn example() -> Result<()> {
         dbg!(example::a5(py, 33)?);
         dbg!(example::a6(py)?);
         dbg!(example::a7(py, 112)?);
+
+        // Not annotated
+        let out = example::a8(py, 1, 2)?;
+        assert_eq!(out.extract::<i64>(py)?, 3);
         Ok(())
     })
 }
@@ -42,8 +54,13 @@ fn type_aliases() -> Result<()> {
         let out = type_aliases::get_user_name(py, id)?;
         assert_eq!(out.as_ref(py).to_str()?, "ID = 124");
 
-        Ok(())
-    })
+        Ok::<_, PyErr>(())
+    })?;
+
+    // Annotated keys are still hashable in the owned value
+    let out = type_aliases::gil::digits(2)?;
+    assert_eq!(out, [(0, "0".to_string()), (1, "1".to_string())].into());
+    Ok(())
 }
 
 #[test]
@@ -75,6 +92,185 @@ fn callable() -> Result<()> {
 
         callable::caller(py, |(a, b): (i64, f64)| a as f64 * b)?;
 
+        let out = callable::concatenate(py, |(a,): (i64,), args, kwargs| {
+            let b: f64 = args.get_item(0).unwrap().extract().unwrap();
+            let scale: f64 = kwargs
+                .unwrap()
+                .get_item("scale")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            (a as f64 + b) * scale
+        })?;
+        assert_eq!(out, 20.0);
+
+        let out =
+            callable::keyword_callback(py, |(value,): (i64,), (prefix,): (Option<&str>,)| {
+                let s = format!("{}{}", prefix.unwrap_or_default(), value);
+                Python::with_gil(|py2| PyString::new(py2, &s).into())
+            })?;
+        assert_eq!(out.as_ref(py).to_str()?, "answer = 42");
+
+        // Arguments the callback does not take are rejected instead of dropped
+        let formatter = |(value,): (i64,), _: (Option<&str>,)| {
+            Python::with_gil(|py2| PyString::new(py2, &value.to_string()).into())
+        };
+        let err = callable::unexpected_keyword(py, formatter).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        let err = callable::unexpected_positional(py, formatter).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+
+        Ok(())
+    })
+}
+
+#[test]
+fn docstring() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        let out = docstring::google(py, 2, "py2o2")?;
+        assert_eq!(out.as_ref(py).to_str()?, "Hello, py2o2! Hello, py2o2! ");
+
+        let out = docstring::numpy(py, docstring::Pixel(3), docstring::Pixel(4))?;
+        assert_eq!(out, 12);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn overload() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        let out = overload::double_1(py, 21)?;
+        assert_eq!(out, 42);
+
+        let out = overload::double_2(py, "py")?;
+        assert_eq!(out.as_ref(py).to_str()?, "pypy");
+
+        Ok(())
+    })
+}
+
+#[test]
+fn annotated() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        let out: u8 = annotated::narrow(py, 100)?;
+        assert_eq!(out, 200);
+        // 2 * 200 does not fit into u8
+        let err = annotated::narrow(py, 200).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyOverflowError>(py));
+
+        let out = annotated::rename(py, "test")?;
+        assert_eq!(out.as_ref(py).to_str()?, "kind = test");
+        Ok(())
+    })
+}
+
+#[test]
+fn special_forms() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        let out = special_forms::identity(py, 42.into_py(py))?;
+        assert_eq!(out.extract::<i64>(py)?, 42);
+
+        let out = special_forms::describe(py, "object".into_py(py))?;
+        assert_eq!(out.as_ref(py).to_str()?, "object");
+
+        let animal = py2o2_runtime::ClassObject::<special_forms::Animal>::new(py)?;
+        let sub = special_forms::subclass(py, animal)?;
+        assert_eq!(sub.as_ref(py).name()?, "SubAnimal");
+
+        let err = special_forms::fail(py, "always fails").unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyRuntimeError>(py));
         Ok(())
     })
 }
+
+#[test]
+fn fastmath() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        // Typed by the stub
+        assert_eq!(fastmath::sqrt(py, 4.0)?, 2.0);
+        assert_eq!(fastmath::copysign(py, 1.0, -2.0)?, -1.0);
+
+        // Only `__text_signature__` is available
+        let out = fastmath::factorial(py, 5)?;
+        assert_eq!(out.extract::<i64>(py)?, 120);
+        Ok(())
+    })
+}
+
+#[test]
+fn greek() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        assert_eq!(greek::λ_to_ν(py, 299792458.0)?, 1.0);
+        assert_eq!(greek::gaussian(py, 0.0, 0.0, 1.0)?, 0.3989422804014327);
+
+        // `ﬁnd(ℎ, x)` is normalized into `find(h, x)`
+        let out = greek::find(py, PyList::new(py, ["a", "b"]), "b")?;
+        assert_eq!(out, 1);
+        Ok(())
+    })
+}
+
+#[test]
+fn keywords() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        assert_eq!(keywords::f(py, 1, "abc")?, 4);
+        // `self` and `_` cannot be raw identifiers
+        assert_eq!(keywords::r#move(py, 3, 1)?, 2);
+        Ok(())
+    })
+}
+
+#[test]
+fn legacy() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    Python::with_gil(|py| {
+        // Typed by the stub in `python/stubs`
+        assert_eq!(legacy::scale(py, 1.5, 2.0)?, 3.0);
+        let out = legacy::user_name(py, legacy::UserId(3))?;
+        assert_eq!(out.as_ref(py).to_str()?, "user3");
+        assert_eq!(legacy::answer(py)?, 42);
+        Ok(())
+    })
+}
+
+#[test]
+fn reload() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+
+    Python::with_gil(|py| {
+        assert_eq!(example::a8(py, 1, 2)?.extract::<i64>(py)?, 3);
+        // Functions are looked up again in the reloaded module
+        example::MODULE.reload(py)?;
+        assert_eq!(example::a8(py, 3, 4)?.extract::<i64>(py)?, 7);
+        Ok(())
+    })
+}
+
+#[test]
+fn gil() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+
+    // Called without `Python::with_gil`, and returns owned values
+    example::gil::a2(57)?;
+    let out: (i64, String) = example::gil::a6()?;
+    assert_eq!(out, (6, "This is a6".to_string()));
+    let out: Vec<f64> = type_aliases::gil::scale(2.0, vec![1.0, 2.0, 3.0])?;
+    assert_eq!(out, [2.0, 4.0, 6.0]);
+    let out: String = type_aliases::gil::get_user_name(type_aliases::UserId(124))?;
+    assert_eq!(out, "ID = 124");
+    // Nested dicts with keys which are not `Hash` still reach Python as dicts
+    let out = type_aliases::gil::total(vec![vec![(0.5, 1), (1.5, 2)], vec![(2.5, 3)]])?;
+    assert_eq!(out, 6);
+    let out = type_aliases::gil::weighted_sum((vec![1.0, 2.0], 0.5))?;
+    assert_eq!(out, 1.5);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("overload");
+pub trait Union5d6b010906f780ce: ::pyo3::conversion::IntoPy<::pyo3::PyObject> {}
+impl Union5d6b010906f780ce for i64 {}
+impl Union5d6b010906f780ce for &str {}
+/// Double the value
+///
+/// # Python signature
+///
+/// ```python
+/// def double(x: int | str) -> int | str
+/// ```
+pub fn double<'py>(
+    py: ::pyo3::Python<'py>,
+    x: impl Union5d6b010906f780ce,
+) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
+    let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def double(x: int) -> int
+/// ```
+pub fn double_1<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<i64> {
+    let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def double(x: str) -> str
+/// ```
+pub fn double_2<'py>(
+    py: ::pyo3::Python<'py>,
+    x: &str,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::double`], but acquires the GIL and returns an owned value
+    pub fn double(
+        x: impl Union5d6b010906f780ce,
+    ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
+        ::pyo3::Python::with_gil(|py| super::double(py, x))
+    }
+    /// Same as [`super::double_1`], but acquires the GIL and returns an owned value
+    pub fn double_1(x: i64) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| super::double_1(py, x))
+    }
+    /// Same as [`super::double_2`], but acquires the GIL and returns an owned value
+    pub fn double_2(x: &str) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::double_2(py, x)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+}
@@ -0,0 +1,105 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache =
+    ::py2o2_runtime::ModuleCache::new("special_forms");
+/// Marker of Python class `special_forms.Animal`
+#[derive(Debug)]
+pub struct Animal;
+impl ::py2o2_runtime::PyTypeInfoUser for Animal {
+    const NAME: &'static str = "Animal";
+    const MODULE: &'static [&'static str] = &["special_forms"];
+    fn type_object(py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<&::pyo3::types::PyType> {
+        py.import("special_forms")?.getattr("Animal")?.extract()
+    }
+}
+/// # Python signature
+///
+/// ```python
+/// def absurd(x: NoReturn) -> int
+/// ```
+pub fn absurd<'py>(
+    py: ::pyo3::Python<'py>,
+    x: ::std::convert::Infallible,
+) -> ::pyo3::PyResult<i64> {
+    let _ = (py, x);
+    match x {}
+}
+/// # Python signature
+///
+/// ```python
+/// def describe(x: object) -> str
+/// ```
+pub fn describe<'py>(
+    py: ::pyo3::Python<'py>,
+    x: ::pyo3::PyObject,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+    let result = MODULE.getattr(py, "describe")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def fail(message: str) -> NoReturn
+/// ```
+pub fn fail<'py>(
+    py: ::pyo3::Python<'py>,
+    message: &str,
+) -> ::pyo3::PyResult<::std::convert::Infallible> {
+    let _ = MODULE.getattr(py, "fail")?.call((message,), None)?;
+    Err(::pyo3::exceptions::PyRuntimeError::new_err(
+        "fail returned though annotated as NoReturn",
+    ))
+}
+/// # Python signature
+///
+/// ```python
+/// def identity(x: Any) -> Any
+/// ```
+pub fn identity<'py>(
+    py: ::pyo3::Python<'py>,
+    x: ::pyo3::PyObject,
+) -> ::pyo3::PyResult<::pyo3::PyObject> {
+    let result = MODULE.getattr(py, "identity")?.call((x,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def subclass(cls: type[special_forms.Animal]) -> type[special_forms.Animal]
+/// ```
+pub fn subclass<'py>(
+    py: ::pyo3::Python<'py>,
+    cls: ::py2o2_runtime::ClassObject<Animal>,
+) -> ::pyo3::PyResult<::py2o2_runtime::ClassObject<Animal>> {
+    let result = MODULE.getattr(py, "subclass")?.call((cls,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::absurd`], but acquires the GIL and returns an owned value
+    pub fn absurd(x: ::std::convert::Infallible) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| super::absurd(py, x))
+    }
+    /// Same as [`super::describe`], but acquires the GIL and returns an owned value
+    pub fn describe(x: ::pyo3::PyObject) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::describe(py, x)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::fail`], but acquires the GIL and returns an owned value
+    pub fn fail(message: &str) -> ::pyo3::PyResult<::std::convert::Infallible> {
+        ::pyo3::Python::with_gil(|py| super::fail(py, message))
+    }
+    /// Same as [`super::identity`], but acquires the GIL and returns an owned value
+    pub fn identity(x: ::pyo3::PyObject) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        ::pyo3::Python::with_gil(|py| super::identity(py, x))
+    }
+    /// Same as [`super::subclass`], but acquires the GIL and returns an owned value
+    pub fn subclass(
+        cls: ::py2o2_runtime::ClassObject<Animal>,
+    ) -> ::pyo3::PyResult<::py2o2_runtime::ClassObject<Animal>> {
+        ::pyo3::Python::with_gil(|py| super::subclass(py, cls))
+    }
+}
@@ -1,3 +1,6 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache =
+    ::py2o2_runtime::ModuleCache::with_markers("type_aliases");
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UserId(pub i64);
 impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
@@ -5,35 +8,149 @@ impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
         self.0.into_py(py)
     }
 }
+/// # Python signature
+///
+/// ```python
+/// def broadcast_message(message: str, servers: collections.abc.Sequence[tuple[tuple[str, int], dict[str, str]]]) -> None
+/// ```
 pub fn broadcast_message<'py>(
     py: ::pyo3::Python<'py>,
     message: &str,
     servers: &::pyo3::types::PyList,
 ) -> ::pyo3::PyResult<()> {
-    let _ = py
-        .import("type_aliases")?
-        .getattr("broadcast_message")?
+    let _ = MODULE
+        .getattr(py, "broadcast_message")?
         .call((message, servers), None)?;
     Ok(())
 }
+/// # Python signature
+///
+/// ```python
+/// def digits(n: int) -> dict[typing.Annotated[int, Rust(type='u8')], str]
+/// ```
+pub fn digits<'py>(
+    py: ::pyo3::Python<'py>,
+    n: i64,
+) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyDict>> {
+    let result = MODULE.getattr(py, "digits")?.call((n,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def get_user_name(user_id: type_aliases.UserId) -> str
+/// ```
 pub fn get_user_name<'py>(
     py: ::pyo3::Python<'py>,
     user_id: UserId,
 ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
-    let result = py
-        .import("type_aliases")?
-        .getattr("get_user_name")?
+    let result = MODULE
+        .getattr(py, "get_user_name")?
         .call((user_id,), None)?;
-    Ok(result.extract()?)
+    result.extract()
 }
+/// # Python signature
+///
+/// ```python
+/// def scale(scalar: float, vector: list[float]) -> list[float]
+/// ```
 pub fn scale<'py>(
     py: ::pyo3::Python<'py>,
     scalar: f64,
     vector: &::pyo3::types::PyList,
 ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyList>> {
-    let result = py
-        .import("type_aliases")?
-        .getattr("scale")?
-        .call((scalar, vector), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "scale")?.call((scalar, vector), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def total(histograms: list[dict[float, int]]) -> int
+/// ```
+pub fn total<'py>(
+    py: ::pyo3::Python<'py>,
+    histograms: &::pyo3::types::PyList,
+) -> ::pyo3::PyResult<i64> {
+    let result = MODULE.getattr(py, "total")?.call((histograms,), None)?;
+    result.extract()
+}
+/// # Python signature
+///
+/// ```python
+/// def weighted_sum(pair: tuple[list[float], float]) -> float
+/// ```
+pub fn weighted_sum<'py>(
+    py: ::pyo3::Python<'py>,
+    pair: (&::pyo3::types::PyList, f64),
+) -> ::pyo3::PyResult<f64> {
+    let result = MODULE.getattr(py, "weighted_sum")?.call((pair,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::broadcast_message`], but acquires the GIL and returns an owned value
+    pub fn broadcast_message(
+        message: &str,
+        servers: Vec<((String, i64), ::std::collections::HashMap<String, String>)>,
+    ) -> ::pyo3::PyResult<()> {
+        ::pyo3::Python::with_gil(|py| {
+            let servers = ::pyo3::types::PyList::new(py, servers);
+            super::broadcast_message(py, message, servers)
+        })
+    }
+    /// Same as [`super::digits`], but acquires the GIL and returns an owned value
+    pub fn digits(n: i64) -> ::pyo3::PyResult<::std::collections::HashMap<u8, String>> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::digits(py, n)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::get_user_name`], but acquires the GIL and returns an owned value
+    pub fn get_user_name(user_id: UserId) -> ::pyo3::PyResult<String> {
+        ::pyo3::Python::with_gil(|py| {
+            let result = super::get_user_name(py, user_id)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::scale`], but acquires the GIL and returns an owned value
+    pub fn scale(scalar: f64, vector: Vec<f64>) -> ::pyo3::PyResult<Vec<f64>> {
+        ::pyo3::Python::with_gil(|py| {
+            let vector = ::pyo3::types::PyList::new(py, vector);
+            let result = super::scale(py, scalar, vector)?;
+            ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+        })
+    }
+    /// Same as [`super::total`], but acquires the GIL and returns an owned value
+    pub fn total(histograms: Vec<Vec<(f64, i64)>>) -> ::pyo3::PyResult<i64> {
+        ::pyo3::Python::with_gil(|py| {
+            let histograms = ::pyo3::types::PyList::new(
+                py,
+                histograms
+                    .into_iter()
+                    .map(|item| {
+                        Ok({
+                            let dict = ::pyo3::types::PyDict::new(py);
+                            for (key, value) in item {
+                                dict.set_item(key, value)?;
+                            }
+                            dict
+                        })
+                    })
+                    .collect::<::pyo3::PyResult<Vec<_>>>()?,
+            );
+            super::total(py, histograms)
+        })
+    }
+    /// Same as [`super::weighted_sum`], but acquires the GIL and returns an owned value
+    pub fn weighted_sum(pair: (Vec<f64>, f64)) -> ::pyo3::PyResult<f64> {
+        ::pyo3::Python::with_gil(|py| {
+            let pair = {
+                let (tag0, tag1) = pair;
+                (::pyo3::types::PyList::new(py, tag0), tag1)
+            };
+            super::weighted_sum(py, pair)
+        })
+    }
 }
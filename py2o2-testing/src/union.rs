@@ -1,17 +1,46 @@
+/// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new("union");
 pub trait Union5d6b010906f780ce: ::pyo3::conversion::IntoPy<::pyo3::PyObject> {}
 impl Union5d6b010906f780ce for i64 {}
 impl Union5d6b010906f780ce for &str {}
+/// # Python signature
+///
+/// ```python
+/// def f_new(a: int | str) -> int | str
+/// ```
 pub fn f_new<'py>(
     py: ::pyo3::Python<'py>,
     a: impl Union5d6b010906f780ce,
 ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
-    let result = py.import("union")?.getattr("f_new")?.call((a,), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "f_new")?.call((a,), None)?;
+    result.extract()
 }
+/// # Python signature
+///
+/// ```python
+/// def f_old(a: Union[int, str]) -> Union[int, str]
+/// ```
 pub fn f_old<'py>(
     py: ::pyo3::Python<'py>,
     a: impl Union5d6b010906f780ce,
 ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
-    let result = py.import("union")?.getattr("f_old")?.call((a,), None)?;
-    Ok(result.extract()?)
+    let result = MODULE.getattr(py, "f_old")?.call((a,), None)?;
+    result.extract()
+}
+/// Functions acquiring the GIL by themselves, which take and return owned Rust values
+pub mod gil {
+    #[allow(unused_imports)]
+    use super::*;
+    /// Same as [`super::f_new`], but acquires the GIL and returns an owned value
+    pub fn f_new(
+        a: impl Union5d6b010906f780ce,
+    ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
+        ::pyo3::Python::with_gil(|py| super::f_new(py, a))
+    }
+    /// Same as [`super::f_old`], but acquires the GIL and returns an owned value
+    pub fn f_old(
+        a: impl Union5d6b010906f780ce,
+    ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
+        ::pyo3::Python::with_gil(|py| super::f_old(py, a))
+    }
 }
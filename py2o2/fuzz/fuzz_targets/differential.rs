@@ -0,0 +1,10 @@
+//! Compare the parser with `ast.parse` on random sources, see `py2o2::ast::differential`
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use py2o2::ast::differential::compare;
+
+fuzz_target!(|source: &str| {
+    let outcome = compare("<fuzz>", source);
+    assert!(!outcome.is_failure(), "{source:?}: {outcome:?}");
+});
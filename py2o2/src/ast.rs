@@ -1,72 +1,488 @@
-use py2o2_runtime::{import_pytype, PyTypeInfoUser};
-use pyo3::{exceptions::*, prelude::*, types::*};
+//! Wrappers of Python's `ast` module
+//!
+//! Nodes are generated from the ASDL grammar by `cargo xtask`, see [nodes].
+//! Trees converted from them are compared with [crate::parser] in [differential].
 
-import_pytype!(ast.Module);
+pub mod differential;
+pub mod nodes;
+pub use nodes::*;
+
+use crate::parser::{self, Span};
+use pyo3::{
+    exceptions::*,
+    prelude::*,
+    types::{IntoPyDict, PyBool, PyBytes, PyComplex, PyLong},
+};
+use std::borrow::Cow;
 
 impl<'py> Module<'py> {
-    pub fn body(&self) -> PyResult<Vec<Statements<'py>>> {
-        let statments: &PyList = self.0.getattr("body")?.extract()?;
-        statments
-            .iter()
-            .map(|st| st.extract())
-            .collect::<PyResult<_>>()
+    /// Convert the body into owned [parser::Stmt], e.g. for [crate::frontend]
+    pub fn to_stmts(&self) -> PyResult<Vec<parser::Stmt<'static>>> {
+        to_stmts(self.body()?)
     }
 }
 
-import_pytype!(ast.Expression);
-
-import_pytype!(ast.arguments as Arguments);
+impl<'py> Arguments<'py> {
+    /// Convert into [parser::Arguments], e.g. for [crate::frontend]
+    pub fn to_arguments(&self) -> PyResult<parser::Arguments<'static>> {
+        let posonlyargs = self.posonlyargs()?;
+        let n_posonly = posonlyargs.len();
+        let positional: Vec<Arg> = posonlyargs.into_iter().chain(self.args()?).collect();
+        // `defaults` are of the last positional arguments
+        let defaults = self.defaults()?;
+        let defaults = std::iter::repeat_with(|| None)
+            .take(positional.len() - defaults.len())
+            .chain(defaults.into_iter().map(Some));
+        let mut positional = positional
+            .iter()
+            .zip(defaults)
+            .map(|(arg, default)| arg.to_arg(default))
+            .collect::<PyResult<Vec<_>>>()?;
+        let args = positional.split_off(n_posonly);
+        Ok(parser::Arguments {
+            args,
+            positional_only: positional,
+            keyword_only: self
+                .kwonlyargs()?
+                .iter()
+                .zip(self.kw_defaults()?)
+                .map(|(arg, default)| arg.to_arg(default))
+                .collect::<PyResult<_>>()?,
+            var_args: self.vararg()?.map(|arg| arg.to_arg(None)).transpose()?,
+            kw_args: self.kwarg()?.map(|arg| arg.to_arg(None)).transpose()?,
+        })
+    }
+}
 
-import_pytype!(ast.FunctionDef);
+impl<'py> Arg<'py> {
+    fn to_arg(&self, default: Option<Expressions<'py>>) -> PyResult<parser::Arg<'static>> {
+        Ok(parser::Arg {
+            name: owned(self.arg()?),
+            ty: self.annotation()?.map(|ty| ty.to_expr()).transpose()?,
+            default: default.map(|default| default.to_expr()).transpose()?,
+            type_comment: self.type_comment()?.map(owned),
+            span: Span::default(),
+        })
+    }
+}
 
 impl<'py> FunctionDef<'py> {
-    pub fn name(&self) -> PyResult<&'py str> {
-        self.0.getattr("name")?.extract()
+    /// Convert into [parser::FunctionDef], e.g. for [crate::frontend]
+    pub fn to_function_def(&self) -> PyResult<parser::FunctionDef<'static>> {
+        Ok(parser::FunctionDef {
+            name: owned(self.name()?),
+            args: self.args()?.to_arguments()?,
+            returns: self.returns()?.map(|e| e.to_expr()).transpose()?,
+            decorators: to_exprs(self.decorator_list()?)?,
+            body: to_stmts(self.body()?)?,
+            type_comment: self.type_comment()?.map(owned),
+            span: Span::default(),
+        })
     }
+}
+
+impl<'py> AsyncFunctionDef<'py> {
+    /// Convert into [parser::FunctionDef] of [parser::Stmt::AsyncFunctionDef]
+    pub fn to_function_def(&self) -> PyResult<parser::FunctionDef<'static>> {
+        Ok(parser::FunctionDef {
+            name: owned(self.name()?),
+            args: self.args()?.to_arguments()?,
+            returns: self.returns()?.map(|e| e.to_expr()).transpose()?,
+            decorators: to_exprs(self.decorator_list()?)?,
+            body: to_stmts(self.body()?)?,
+            type_comment: self.type_comment()?.map(owned),
+            span: Span::default(),
+        })
+    }
+}
 
-    pub fn args(&self) -> PyResult<Arguments> {
-        self.0.getattr("args")?.extract()
+impl<'py> Statements<'py> {
+    /// Convert into [parser::Stmt], e.g. for [crate::frontend]
+    ///
+    /// Statements other than `def`, `class` and `if` become [parser::Stmt::Other]
+    /// if [parser::Stmt] does not support them or expressions in them.
+    pub fn to_stmt(&self) -> PyResult<parser::Stmt<'static>> {
+        match self {
+            Statements::FunctionDef(f) => {
+                return Ok(parser::Stmt::FunctionDef(Box::new(f.to_function_def()?)))
+            }
+            Statements::AsyncFunctionDef(f) => {
+                return Ok(parser::Stmt::AsyncFunctionDef(Box::new(
+                    f.to_function_def()?,
+                )))
+            }
+            Statements::If(if_) => {
+                return Ok(parser::Stmt::If {
+                    test: if_.test()?.to_expr()?,
+                    body: to_stmts(if_.body()?)?,
+                    orelse: to_stmts(if_.orelse()?)?,
+                })
+            }
+            Statements::ClassDef(class) => {
+                return Ok(parser::Stmt::ClassDef(parser::ClassDef {
+                    name: owned(class.name()?),
+                    bases: to_exprs(class.bases()?)?,
+                    keywords: to_keywords(class.keywords()?)?,
+                    decorators: to_exprs(class.decorator_list()?)?,
+                    body: to_stmts(class.body()?)?,
+                    span: Span::default(),
+                }))
+            }
+            _ => {}
+        }
+        if let Ok(Some(stmt)) = self.to_simple_stmt() {
+            return Ok(stmt);
+        }
+        let node = self.as_any();
+        let unparse = node.py().import("ast")?.getattr("unparse")?;
+        Ok(parser::Stmt::Other(owned(
+            unparse.call1((node,))?.extract()?,
+        )))
     }
 
-    pub fn returns(&self) -> PyResult<&'py PyAny> {
-        self.0.getattr("returns")
+    fn to_simple_stmt(&self) -> PyResult<Option<parser::Stmt<'static>>> {
+        Ok(Some(match self {
+            Statements::Import(import) => parser::Stmt::Import {
+                names: to_aliases(import.names()?)?,
+            },
+            Statements::ImportFrom(import_from) => parser::Stmt::ImportFrom {
+                module: import_from.module()?.map(owned),
+                names: to_aliases(import_from.names()?)?,
+                level: import_from.level()?.unwrap_or_default() as usize,
+            },
+            Statements::Assign(assign) => parser::Stmt::Assign {
+                targets: to_exprs(assign.targets()?)?,
+                value: assign.value()?.to_expr()?,
+            },
+            Statements::AnnAssign(ann_assign) => parser::Stmt::AnnAssign {
+                target: ann_assign.target()?.to_expr()?,
+                annotation: ann_assign.annotation()?.to_expr()?,
+                value: ann_assign.value()?.map(|e| e.to_expr()).transpose()?,
+            },
+            Statements::Expr(expr) => parser::Stmt::Expr {
+                value: expr.value()?.to_expr()?,
+            },
+            _ => return Ok(None),
+        }))
     }
 }
 
-import_pytype!(ast.ImportFrom);
-import_pytype!(ast.Assign);
+fn to_stmts<'py>(stmts: Vec<Statements<'py>>) -> PyResult<Vec<parser::Stmt<'static>>> {
+    stmts.iter().map(Statements::to_stmt).collect()
+}
+
+fn to_exprs<'py>(exprs: Vec<Expressions<'py>>) -> PyResult<Vec<parser::Expr<'static>>> {
+    exprs.iter().map(Expressions::to_expr).collect()
+}
 
-#[derive(Debug)]
-pub enum Statements<'py> {
-    ImportFrom(ImportFrom<'py>),
-    FunctionDef(FunctionDef<'py>),
-    Assign(Assign<'py>),
+fn to_keywords<'py>(keywords: Vec<Keyword<'py>>) -> PyResult<Vec<parser::Keyword<'static>>> {
+    keywords
+        .iter()
+        .map(|k| {
+            Ok(parser::Keyword {
+                arg: k.arg()?.map(owned),
+                value: k.value()?.to_expr()?,
+            })
+        })
+        .collect()
 }
 
-impl<'py> FromPyObject<'py> for Statements<'py> {
-    fn extract(ob: &'py PyAny) -> PyResult<Self> {
-        if let Ok(import_from) = ob.extract() {
-            Ok(Statements::ImportFrom(import_from))
-        } else if let Ok(function_def) = ob.extract() {
-            Ok(Statements::FunctionDef(function_def))
-        } else if let Ok(assign) = ob.extract() {
-            Ok(Statements::Assign(assign))
-        } else {
-            Err(PyTypeError::new_err(format!(
-                "Expected a statement, {}",
-                ob.get_type(),
-            )))
+fn to_aliases<'py>(aliases: Vec<Alias<'py>>) -> PyResult<Vec<parser::Alias<'static>>> {
+    aliases
+        .iter()
+        .map(|alias| {
+            Ok(parser::Alias {
+                name: owned(alias.name()?),
+                asname: alias.asname()?.map(owned),
+            })
+        })
+        .collect()
+}
+
+impl<'py> Expressions<'py> {
+    /// Convert into [parser::Expr], e.g. for [crate::frontend]
+    pub fn to_expr(&self) -> PyResult<parser::Expr<'static>> {
+        use parser::Expr;
+        let boxed = |e: Expressions<'py>| -> PyResult<_> { Ok(Box::new(e.to_expr()?)) };
+        Ok(match self {
+            Expressions::Name(name) => Expr::Name {
+                id: owned(name.id()?),
+                span: Span::default(),
+            },
+            Expressions::Attribute(attribute) => Expr::Attribute {
+                value: boxed(attribute.value()?)?,
+                attr: owned(attribute.attr()?),
+                span: Span::default(),
+            },
+            Expressions::Subscript(subscript) => Expr::Subscript {
+                value: boxed(subscript.value()?)?,
+                slice: boxed(subscript.slice()?)?,
+                span: Span::default(),
+            },
+            Expressions::Tuple(tuple) => Expr::Tuple {
+                elts: to_exprs(tuple.elts()?)?,
+                span: Span::default(),
+            },
+            Expressions::List(list) => Expr::List {
+                elts: to_exprs(list.elts()?)?,
+                span: Span::default(),
+            },
+            Expressions::BinOp(bin_op) => Expr::BinOp {
+                left: boxed(bin_op.left()?)?,
+                op: to_operator(bin_op.op()?),
+                right: boxed(bin_op.right()?)?,
+                span: Span::default(),
+            },
+            Expressions::Call(call) => Expr::Call {
+                func: boxed(call.func()?)?,
+                args: to_exprs(call.args()?)?,
+                keywords: to_keywords(call.keywords()?)?,
+                span: Span::default(),
+            },
+            Expressions::Compare(compare) => Expr::Compare {
+                left: boxed(compare.left()?)?,
+                ops: compare.ops()?.into_iter().map(to_cmpop).collect(),
+                comparators: to_exprs(compare.comparators()?)?,
+                span: Span::default(),
+            },
+            Expressions::BoolOp(bool_op) => Expr::BoolOp {
+                op: match bool_op.op()? {
+                    BoolOperator::And => parser::BoolOperator::And,
+                    BoolOperator::Or => parser::BoolOperator::Or,
+                },
+                values: to_exprs(bool_op.values()?)?,
+                span: Span::default(),
+            },
+            Expressions::NamedExpr(named_expr) => Expr::NamedExpr {
+                target: boxed(named_expr.target()?)?,
+                value: boxed(named_expr.value()?)?,
+                span: Span::default(),
+            },
+            Expressions::UnaryOp(unary_op) => Expr::UnaryOp {
+                op: match unary_op.op()? {
+                    UnaryOperator::Invert => parser::UnaryOperator::Invert,
+                    UnaryOperator::Not => parser::UnaryOperator::Not,
+                    UnaryOperator::UAdd => parser::UnaryOperator::UAdd,
+                    UnaryOperator::USub => parser::UnaryOperator::USub,
+                },
+                operand: boxed(unary_op.operand()?)?,
+                span: Span::default(),
+            },
+            Expressions::Lambda(lambda) => Expr::Lambda {
+                args: Box::new(lambda.args()?.to_arguments()?),
+                body: boxed(lambda.body()?)?,
+                span: Span::default(),
+            },
+            Expressions::IfExp(if_exp) => Expr::IfExp {
+                test: boxed(if_exp.test()?)?,
+                body: boxed(if_exp.body()?)?,
+                orelse: boxed(if_exp.orelse()?)?,
+                span: Span::default(),
+            },
+            Expressions::Dict(dict) => Expr::Dict {
+                keys: dict
+                    .keys()?
+                    .into_iter()
+                    .map(|key| key.map(|key| key.to_expr()).transpose())
+                    .collect::<PyResult<_>>()?,
+                values: to_exprs(dict.values()?)?,
+                span: Span::default(),
+            },
+            Expressions::Set(set) => Expr::Set {
+                elts: to_exprs(set.elts()?)?,
+                span: Span::default(),
+            },
+            Expressions::ListComp(list_comp) => Expr::ListComp {
+                elt: boxed(list_comp.elt()?)?,
+                generators: to_comprehensions(list_comp.generators()?)?,
+                span: Span::default(),
+            },
+            Expressions::SetComp(set_comp) => Expr::SetComp {
+                elt: boxed(set_comp.elt()?)?,
+                generators: to_comprehensions(set_comp.generators()?)?,
+                span: Span::default(),
+            },
+            Expressions::DictComp(dict_comp) => Expr::DictComp {
+                key: boxed(dict_comp.key()?)?,
+                value: boxed(dict_comp.value()?)?,
+                generators: to_comprehensions(dict_comp.generators()?)?,
+                span: Span::default(),
+            },
+            Expressions::GeneratorExp(generator_exp) => Expr::GeneratorExp {
+                elt: boxed(generator_exp.elt()?)?,
+                generators: to_comprehensions(generator_exp.generators()?)?,
+                span: Span::default(),
+            },
+            Expressions::Await(await_) => Expr::Await {
+                value: boxed(await_.value()?)?,
+                span: Span::default(),
+            },
+            Expressions::Yield(yield_) => Expr::Yield {
+                value: yield_.value()?.map(boxed).transpose()?,
+                span: Span::default(),
+            },
+            Expressions::YieldFrom(yield_from) => Expr::YieldFrom {
+                value: boxed(yield_from.value()?)?,
+                span: Span::default(),
+            },
+            Expressions::Starred(starred) => Expr::Starred {
+                value: boxed(starred.value()?)?,
+                span: Span::default(),
+            },
+            Expressions::Slice(slice) => Expr::Slice {
+                lower: slice.lower()?.map(boxed).transpose()?,
+                upper: slice.upper()?.map(boxed).transpose()?,
+                step: slice.step()?.map(boxed).transpose()?,
+                span: Span::default(),
+            },
+            Expressions::Constant(constant) => to_constant(constant.value()?)?,
+            Expressions::JoinedStr(joined_str) => Expr::JoinedStr {
+                values: to_exprs(joined_str.values()?)?,
+                span: Span::default(),
+            },
+            Expressions::FormattedValue(formatted_value) => Expr::FormattedValue {
+                value: boxed(formatted_value.value()?)?,
+                conversion: match formatted_value.conversion()? {
+                    -1 => None,
+                    conversion => u8::try_from(conversion).ok().map(char::from),
+                },
+                format_spec: formatted_value.format_spec()?.map(boxed).transpose()?,
+                span: Span::default(),
+            },
+            other => {
+                return Err(PyNotImplementedError::new_err(format!(
+                    "Unsupported expression: {}",
+                    other.as_any().get_type().name()?
+                )))
+            }
+        })
+    }
+}
+
+fn to_constant(value: &PyAny) -> PyResult<parser::Expr<'static>> {
+    use parser::{Constant, Expr};
+    Ok(if value.is_none() {
+        Expr::None {
+            span: Span::default(),
+        }
+    } else if value.is(value.py().Ellipsis().as_ref(value.py())) {
+        Expr::Ellipsis {
+            span: Span::default(),
+        }
+    } else if value.is_instance_of::<PyBool>() {
+        // The parser reads `True` and `False` as names
+        Expr::Name {
+            id: if value.is_true()? { "True" } else { "False" }.into(),
+            span: Span::default(),
+        }
+    } else if let Ok(s) = value.extract::<&str>() {
+        Expr::Constant {
+            value: Constant::String(owned(s)),
+            span: Span::default(),
         }
+    } else if let Ok(b) = value.downcast::<PyBytes>() {
+        Expr::Constant {
+            value: Constant::Bytes(b.as_bytes().to_vec()),
+            span: Span::default(),
+        }
+    } else if value.is_instance_of::<PyLong>() {
+        let value = match value.extract() {
+            Ok(value) => Constant::Int(value),
+            Err(_) => Constant::BigInt(value.str()?.to_string()),
+        };
+        Expr::Constant {
+            value,
+            span: Span::default(),
+        }
+    } else if let Ok(c) = value.downcast::<PyComplex>() {
+        Expr::Constant {
+            value: Constant::Complex {
+                real: c.real(),
+                imag: c.imag(),
+            },
+            span: Span::default(),
+        }
+    } else if let Ok(f) = value.extract::<f64>() {
+        Expr::Constant {
+            value: Constant::Float(f),
+            span: Span::default(),
+        }
+    } else {
+        return Err(PyNotImplementedError::new_err(format!(
+            "Unsupported constant: {}",
+            value.repr()?
+        )));
+    })
+}
+
+fn to_comprehensions(
+    generators: Vec<Comprehension>,
+) -> PyResult<Vec<parser::Comprehension<'static>>> {
+    generators
+        .into_iter()
+        .map(|generator| {
+            Ok(parser::Comprehension {
+                target: generator.target()?.to_expr()?,
+                iter: generator.iter()?.to_expr()?,
+                ifs: to_exprs(generator.ifs()?)?,
+                is_async: generator.is_async()? != 0,
+            })
+        })
+        .collect()
+}
+
+fn to_operator(op: Operator) -> parser::Operator {
+    match op {
+        Operator::Add => parser::Operator::Add,
+        Operator::Sub => parser::Operator::Sub,
+        Operator::Mult => parser::Operator::Mult,
+        Operator::MatMult => parser::Operator::MatMult,
+        Operator::Div => parser::Operator::Div,
+        Operator::Mod => parser::Operator::Mod,
+        Operator::Pow => parser::Operator::Pow,
+        Operator::LShift => parser::Operator::LShift,
+        Operator::RShift => parser::Operator::RShift,
+        Operator::BitOr => parser::Operator::BitOr,
+        Operator::BitXor => parser::Operator::BitXor,
+        Operator::BitAnd => parser::Operator::BitAnd,
+        Operator::FloorDiv => parser::Operator::FloorDiv,
     }
 }
 
+fn to_cmpop(op: CmpOp) -> parser::CmpOp {
+    match op {
+        CmpOp::Eq => parser::CmpOp::Eq,
+        CmpOp::NotEq => parser::CmpOp::NotEq,
+        CmpOp::Lt => parser::CmpOp::Lt,
+        CmpOp::LtE => parser::CmpOp::LtE,
+        CmpOp::Gt => parser::CmpOp::Gt,
+        CmpOp::GtE => parser::CmpOp::GtE,
+        CmpOp::Is => parser::CmpOp::Is,
+        CmpOp::IsNot => parser::CmpOp::IsNot,
+        CmpOp::In => parser::CmpOp::In,
+        CmpOp::NotIn => parser::CmpOp::NotIn,
+    }
+}
+
+fn owned(s: &str) -> Cow<'static, str> {
+    Cow::Owned(s.to_string())
+}
+
+/// Parse by `ast.parse`, where type comments are kept as the parser does
 pub fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Module<'py>> {
     let ast = py.import("ast")?;
     let parse = ast.getattr("parse")?;
-    let parsed = parse.call1((input,))?;
+    let kwargs = [("type_comments", true)].into_py_dict(py);
+    let parsed = parse.call((input,), Some(kwargs))?;
     parsed.extract()
 }
 
+/// Parse by `ast.parse` into owned [parser::Stmt], which can be used without the GIL
+/// and compared with the result of [parser::module]
+pub fn parse_stmts(input: &str) -> PyResult<Vec<parser::Stmt<'static>>> {
+    Python::with_gil(|py| parse(py, input)?.to_stmts())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,6 +502,135 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_nodes() {
+        let source = r#"
+if TYPE_CHECKING:
+    import os
+
+async def f(*, key=None, **kwargs):
+    return {**kwargs, "key": -key}
+
+match x:
+    case [1, *rest]:
+        pass
+"#;
+        Python::with_gil(|py| -> PyResult<()> {
+            let body = parse(py, source)?.body()?;
+            assert!(body.iter().all(|st| !matches!(st, Statements::Other(_))));
+
+            let Statements::If(if_) = &body[0] else {
+                panic!()
+            };
+            assert!(matches!(if_.test()?, Expressions::Name(_)));
+            assert!(matches!(if_.body()?[0], Statements::Import(_)));
+            assert!(if_.orelse()?.is_empty());
+            assert_eq!(if_.lineno()?, 2);
+
+            let Statements::AsyncFunctionDef(f) = &body[1] else {
+                panic!()
+            };
+            assert_eq!(f.name()?, "f");
+            let args = f.args()?;
+            assert!(matches!(
+                args.kw_defaults()?[..],
+                [Some(Expressions::Constant(_))]
+            ));
+            assert_eq!(args.kwarg()?.unwrap().arg()?, "kwargs");
+            let Statements::Return(ret) = &f.body()?[0] else {
+                panic!()
+            };
+            let Some(Expressions::Dict(dict)) = ret.value()? else {
+                panic!()
+            };
+            let keys = dict.keys()?;
+            assert!(keys[0].is_none() && keys[1].is_some());
+            let Expressions::UnaryOp(neg) = &dict.values()?[1] else {
+                panic!()
+            };
+            assert_eq!(neg.op()?, UnaryOperator::USub);
+
+            let Statements::Match(m) = &body[2] else {
+                panic!()
+            };
+            let Pattern::MatchSequence(seq) = m.cases()?[0].pattern()? else {
+                panic!()
+            };
+            assert!(matches!(seq.patterns()?[1], Pattern::MatchStar(_)));
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_to_stmts() {
+        let source = r#"
+from typing import Callable, NewType
+import collections.abc as abc
+
+Id = NewType("Id", int)
+
+def f(a, /, b: float = 1.5, *args: str, c: "Id" = None, **kwargs) -> Callable[[int], str | None]:
+    """Docstring"""
+    x: int = a < b <= c
+
+class A(abc.Sequence):
+    def g(self) -> list[float]: ...
+
+if TYPE_CHECKING:
+    import os
+elif sys.version_info >= (3, 9):
+    x = 1.0
+else:
+    @decorate(key=abc)
+    async def h(): ...
+"#;
+        let stmts = parse_stmts(source).unwrap();
+        let (_, expected) = parser::module(parser::Input::new(source)).unwrap();
+        assert_eq!(stmts, expected);
+
+        let json = serde_json::to_string(&stmts).unwrap();
+        let deserialized: Vec<parser::Stmt> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, stmts);
+    }
+
+    #[test]
+    fn test_to_expr() {
+        let sources = [
+            "dict[str, list[float]] | None",
+            "Callable[..., Awaitable[T]]",
+            "-a ** -b * c + d // e % f @ g - h",
+            "a << b >> c & d ^ e | f",
+            "not a and b or c and not d",
+            "a < b == c is not d not in e in f",
+            "x if a else y if b else z",
+            "(lambda: x, lambda a, /, b=1.0, *c, d, **e: a)",
+            "(lambda *, key: key)",
+            "{**d, \"key\": [1.0, *xs], \"ignored\" if False else 2.0: {a, b}}",
+            "[x * y for x in xs if x for y in ys]",
+            "{k: v async for k, v in items}",
+            "{x for (x, _) in pairs}",
+            "sum(x for x in xs)",
+            "f(*args, a=1.0, *more, **kwargs)",
+            "(y := f(x), await g, -~+1.0)",
+            "xs[1.0:], xs[:-1.0], xs[::2.0], xs[a:b, ..., *c]",
+            "m.a.b(c)[d].e",
+            "((yield), (yield x), (yield from xs))",
+            "()",
+            "(a)",
+            "(a,)",
+            "(42, 0x_ff, 0o17, 0B1, 1_000.5e-3, 2j, .5, 1., 1e10)",
+            r#"('single', "a\tb\x41" 'cé', r'\d', b'\x80' rb'\n', u'x')"#,
+            r#"f'{x!r:>{width}} {y = }' 'tail' f"{{}}{z:}""#,
+            "'''multi\nline'''",
+        ];
+        for source in sources {
+            let stmts = parse_stmts(source).unwrap();
+            let (_, expected) = parser::module(parser::Input::new(source)).unwrap();
+            assert_eq!(stmts, expected, "{}", source);
+        }
+    }
+
     #[test]
     fn test_parse_callable() {
         Python::with_gil(|py| -> PyResult<()> {
@@ -0,0 +1,185 @@
+//! Differential testing of [crate::parser] against CPython's `ast.parse`
+//!
+//! Both parsers are run on the same source, and the tree of `ast.parse` converted by [crate::ast]
+//! is compared with the one of [parser::module] after [normalize].
+//! `cargo xtask differential` runs this over the `python/` fixtures and the installed standard library,
+//! and the `differential` target in `py2o2/fuzz` over random inputs.
+
+use crate::parser::{
+    self,
+    fold::{self, Fold},
+    Diagnostic, Input, Stmt,
+};
+use nom::Finish;
+use pyo3::Python;
+use std::borrow::Cow;
+
+/// Result of parsing a source by both parsers
+#[derive(Debug)]
+pub enum Outcome<'input> {
+    /// Both parsers give the same statements
+    Same,
+    /// `ast.parse` rejects the source
+    ///
+    /// The parser is not checked since it accepts unsupported statements as [Stmt::Other].
+    Invalid(String),
+    /// The tree of `ast.parse` cannot be converted, e.g. a node added in a newer Python
+    Unsupported(String),
+    /// The parser rejects the source which `ast.parse` accepts
+    Rejected(Diagnostic),
+    /// The first statements which differ, where `None` is the end of the shorter one
+    Mismatch {
+        python: Option<Box<Stmt<'static>>>,
+        parser: Option<Box<Stmt<'input>>>,
+    },
+}
+
+impl Outcome<'_> {
+    /// Whether the parser disagrees with `ast.parse`
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Rejected(_) | Outcome::Mismatch { .. })
+    }
+}
+
+/// Parse `source` by both parsers and compare them, where `path` is shown in diagnostics
+pub fn compare<'input>(path: &str, source: &'input str) -> Outcome<'input> {
+    let python = Python::with_gil(|py| {
+        let module = super::parse(py, source).map_err(|e| Outcome::Invalid(e.to_string()))?;
+        module
+            .to_stmts()
+            .map_err(|e| Outcome::Unsupported(e.to_string()))
+    });
+    let python = match python {
+        Ok(stmts) => normalize(stmts),
+        Err(outcome) => return outcome,
+    };
+    let parsed = match parser::module(Input::new(source)).finish() {
+        Ok((_, stmts)) => normalize(stmts),
+        Err(e) => return Outcome::Rejected(Diagnostic::new(path, &e)),
+    };
+    if python == parsed {
+        return Outcome::Same;
+    }
+    let (python, parser) = first_difference(&python, &parsed);
+    Outcome::Mismatch {
+        python: python.cloned().map(Box::new),
+        parser: parser.cloned().map(Box::new),
+    }
+}
+
+/// The innermost statements which differ first, descending into bodies of the same definitions
+fn first_difference<'a, 'b, 'input>(
+    python: &'a [Stmt<'static>],
+    parsed: &'b [Stmt<'input>],
+) -> (Option<&'a Stmt<'static>>, Option<&'b Stmt<'input>>) {
+    let Some(i) = (0..python.len().max(parsed.len())).find(|&i| python.get(i) != parsed.get(i))
+    else {
+        return (None, None);
+    };
+    let (a, b) = (python.get(i), parsed.get(i));
+    match (a, b) {
+        (
+            Some(Stmt::FunctionDef(f) | Stmt::AsyncFunctionDef(f)),
+            Some(Stmt::FunctionDef(g) | Stmt::AsyncFunctionDef(g)),
+        ) if f.name == g.name && f.args == g.args && f.returns == g.returns => {
+            first_difference(&f.body, &g.body)
+        }
+        (Some(Stmt::ClassDef(c)), Some(Stmt::ClassDef(d)))
+            if c.name == d.name
+                && c.bases == d.bases
+                && c.keywords == d.keywords
+                && c.decorators == d.decorators =>
+        {
+            first_difference(&c.body, &d.body)
+        }
+        (
+            Some(Stmt::If {
+                test, body, orelse, ..
+            }),
+            Some(Stmt::If {
+                test: test_,
+                body: body_,
+                orelse: orelse_,
+            }),
+        ) if test == test_ => match first_difference(body, body_) {
+            (None, None) => first_difference(orelse, orelse_),
+            different => different,
+        },
+        _ => (a, b),
+    }
+}
+
+/// Remove what the parsers keep differently, i.e. the contents of [Stmt::Other]
+///
+/// `ast.unparse` renders an unsupported compound statement with its body,
+/// while the parser keeps each clause header, e.g. `try:` and `except:`, as a statement.
+/// Consecutive ones are merged into a single `Other("<other>")` on both sides.
+pub fn normalize(stmts: Vec<Stmt<'_>>) -> Vec<Stmt<'_>> {
+    Normalize.fold_stmts(stmts)
+}
+
+struct Normalize;
+
+impl<'input> Fold<'input> for Normalize {
+    fn fold_stmts(&mut self, stmts: Vec<Stmt<'input>>) -> Vec<Stmt<'input>> {
+        let mut normalized: Vec<Stmt> = Vec::with_capacity(stmts.len());
+        for stmt in fold::fold_stmts(self, stmts) {
+            match (normalized.last(), stmt) {
+                (Some(Stmt::Other(_)), Stmt::Other(_)) => {}
+                (_, Stmt::Other(_)) => normalized.push(Stmt::Other(Cow::Borrowed("<other>"))),
+                (_, stmt) => normalized.push(stmt),
+            }
+        }
+        normalized
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn outcomes() {
+        assert!(matches!(
+            compare(
+                "same",
+                "try:\n    x = 1\nexcept E:\n    pass\nfinally:\n    y = 2\n"
+            ),
+            Outcome::Same
+        ));
+        assert!(matches!(
+            compare(
+                "regressions",
+                "x = a == b; y = 1\nasync with l:\n    pass\n"
+            ),
+            Outcome::Same
+        ));
+        assert!(matches!(
+            compare("invalid", "def f(:\n"),
+            Outcome::Invalid(_)
+        ));
+        assert!(matches!(
+            compare(
+                "big integers",
+                "def f(x=100000000000000000000, y=0xFFFF_FFFF_FFFF_FFFF): ...\n"
+            ),
+            Outcome::Same
+        ));
+    }
+
+    #[test]
+    fn python_sources() {
+        for entry in std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/../python")).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "py") {
+                let source = std::fs::read_to_string(&path).unwrap();
+                let outcome = compare(&path.display().to_string(), &source);
+                assert!(
+                    matches!(outcome, Outcome::Same),
+                    "{}: {outcome:?}",
+                    path.display()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,2507 @@
+//! Wrappers of the nodes in the ASDL grammar of Python 3.11, generated by `cargo xtask`
+use py2o2_runtime::{import_pytype, PyTypeInfoUser};
+fn check_instance(ob: &::pyo3::PyAny, name: &str) -> ::pyo3::PyResult<()> {
+    let ty = ob.py().import("ast")?.getattr(name)?;
+    if ob.is_instance(ty)? {
+        Ok(())
+    } else {
+        Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+            "Not an ast.{}: {}",
+            name,
+            ob.get_type()
+        )))
+    }
+}
+/// `mod`
+#[derive(Debug)]
+pub enum Mod<'py> {
+    Module(Module<'py>),
+    Interactive(Interactive<'py>),
+    Expression(Expression<'py>),
+    FunctionType(FunctionType<'py>),
+    /// `ast.mod` of a newer Python than the one generating this module
+    Other(&'py ::pyo3::PyAny),
+}
+impl<'py> ::pyo3::FromPyObject<'py> for Mod<'py> {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "mod")?;
+        Ok(match ob.get_type().name()? {
+            "Module" => Mod::Module(ob.extract()?),
+            "Interactive" => Mod::Interactive(ob.extract()?),
+            "Expression" => Mod::Expression(ob.extract()?),
+            "FunctionType" => Mod::FunctionType(ob.extract()?),
+            _ => Mod::Other(ob),
+        })
+    }
+}
+impl<'py> Mod<'py> {
+    /// The underlying Python object
+    pub fn as_any(&self) -> &'py ::pyo3::PyAny {
+        match self {
+            Mod::Module(node) => node.0,
+            Mod::Interactive(node) => node.0,
+            Mod::Expression(node) => node.0,
+            Mod::FunctionType(node) => node.0,
+            Mod::Other(node) => node,
+        }
+    }
+}
+import_pytype!(ast.Module);
+/// `Module(stmt* body, type_ignore* type_ignores)`
+impl<'py> Module<'py> {
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `type_ignore* type_ignores`
+    pub fn type_ignores(&self) -> ::pyo3::PyResult<Vec<TypeIgnore<'py>>> {
+        self.0.getattr("type_ignores")?.extract()
+    }
+}
+import_pytype!(ast.Interactive);
+/// `Interactive(stmt* body)`
+impl<'py> Interactive<'py> {
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+}
+import_pytype!(ast.Expression);
+/// `Expression(expr body)`
+impl<'py> Expression<'py> {
+    /// `expr body`
+    pub fn body(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("body")?.extract()
+    }
+}
+import_pytype!(ast.FunctionType);
+/// `FunctionType(expr* argtypes, expr returns)`
+impl<'py> FunctionType<'py> {
+    /// `expr* argtypes`
+    pub fn argtypes(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("argtypes")?.extract()
+    }
+    /// `expr returns`
+    pub fn returns(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("returns")?.extract()
+    }
+}
+/// `stmt`
+#[derive(Debug)]
+pub enum Statements<'py> {
+    FunctionDef(FunctionDef<'py>),
+    AsyncFunctionDef(AsyncFunctionDef<'py>),
+    ClassDef(ClassDef<'py>),
+    Return(Return<'py>),
+    Delete(Delete<'py>),
+    Assign(Assign<'py>),
+    AugAssign(AugAssign<'py>),
+    AnnAssign(AnnAssign<'py>),
+    For(For<'py>),
+    AsyncFor(AsyncFor<'py>),
+    While(While<'py>),
+    If(If<'py>),
+    With(With<'py>),
+    AsyncWith(AsyncWith<'py>),
+    Match(Match<'py>),
+    Raise(Raise<'py>),
+    Try(Try<'py>),
+    TryStar(TryStar<'py>),
+    Assert(Assert<'py>),
+    Import(Import<'py>),
+    ImportFrom(ImportFrom<'py>),
+    Global(Global<'py>),
+    Nonlocal(Nonlocal<'py>),
+    Expr(Expr<'py>),
+    Pass(Pass<'py>),
+    Break(Break<'py>),
+    Continue(Continue<'py>),
+    /// `ast.stmt` of a newer Python than the one generating this module
+    Other(&'py ::pyo3::PyAny),
+}
+impl<'py> ::pyo3::FromPyObject<'py> for Statements<'py> {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "stmt")?;
+        Ok(match ob.get_type().name()? {
+            "FunctionDef" => Statements::FunctionDef(ob.extract()?),
+            "AsyncFunctionDef" => Statements::AsyncFunctionDef(ob.extract()?),
+            "ClassDef" => Statements::ClassDef(ob.extract()?),
+            "Return" => Statements::Return(ob.extract()?),
+            "Delete" => Statements::Delete(ob.extract()?),
+            "Assign" => Statements::Assign(ob.extract()?),
+            "AugAssign" => Statements::AugAssign(ob.extract()?),
+            "AnnAssign" => Statements::AnnAssign(ob.extract()?),
+            "For" => Statements::For(ob.extract()?),
+            "AsyncFor" => Statements::AsyncFor(ob.extract()?),
+            "While" => Statements::While(ob.extract()?),
+            "If" => Statements::If(ob.extract()?),
+            "With" => Statements::With(ob.extract()?),
+            "AsyncWith" => Statements::AsyncWith(ob.extract()?),
+            "Match" => Statements::Match(ob.extract()?),
+            "Raise" => Statements::Raise(ob.extract()?),
+            "Try" => Statements::Try(ob.extract()?),
+            "TryStar" => Statements::TryStar(ob.extract()?),
+            "Assert" => Statements::Assert(ob.extract()?),
+            "Import" => Statements::Import(ob.extract()?),
+            "ImportFrom" => Statements::ImportFrom(ob.extract()?),
+            "Global" => Statements::Global(ob.extract()?),
+            "Nonlocal" => Statements::Nonlocal(ob.extract()?),
+            "Expr" => Statements::Expr(ob.extract()?),
+            "Pass" => Statements::Pass(ob.extract()?),
+            "Break" => Statements::Break(ob.extract()?),
+            "Continue" => Statements::Continue(ob.extract()?),
+            _ => Statements::Other(ob),
+        })
+    }
+}
+impl<'py> Statements<'py> {
+    /// The underlying Python object
+    pub fn as_any(&self) -> &'py ::pyo3::PyAny {
+        match self {
+            Statements::FunctionDef(node) => node.0,
+            Statements::AsyncFunctionDef(node) => node.0,
+            Statements::ClassDef(node) => node.0,
+            Statements::Return(node) => node.0,
+            Statements::Delete(node) => node.0,
+            Statements::Assign(node) => node.0,
+            Statements::AugAssign(node) => node.0,
+            Statements::AnnAssign(node) => node.0,
+            Statements::For(node) => node.0,
+            Statements::AsyncFor(node) => node.0,
+            Statements::While(node) => node.0,
+            Statements::If(node) => node.0,
+            Statements::With(node) => node.0,
+            Statements::AsyncWith(node) => node.0,
+            Statements::Match(node) => node.0,
+            Statements::Raise(node) => node.0,
+            Statements::Try(node) => node.0,
+            Statements::TryStar(node) => node.0,
+            Statements::Assert(node) => node.0,
+            Statements::Import(node) => node.0,
+            Statements::ImportFrom(node) => node.0,
+            Statements::Global(node) => node.0,
+            Statements::Nonlocal(node) => node.0,
+            Statements::Expr(node) => node.0,
+            Statements::Pass(node) => node.0,
+            Statements::Break(node) => node.0,
+            Statements::Continue(node) => node.0,
+            Statements::Other(node) => node,
+        }
+    }
+}
+import_pytype!(ast.FunctionDef);
+/// `FunctionDef(identifier name, arguments args, stmt* body, expr* decorator_list, expr? returns, string? type_comment)`
+impl<'py> FunctionDef<'py> {
+    /// `identifier name`
+    pub fn name(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `arguments args`
+    pub fn args(&self) -> ::pyo3::PyResult<Arguments<'py>> {
+        self.0.getattr("args")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `expr* decorator_list`
+    pub fn decorator_list(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("decorator_list")?.extract()
+    }
+    /// `expr? returns`
+    pub fn returns(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("returns")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.AsyncFunctionDef);
+/// `AsyncFunctionDef(identifier name, arguments args, stmt* body, expr* decorator_list, expr? returns, string? type_comment)`
+impl<'py> AsyncFunctionDef<'py> {
+    /// `identifier name`
+    pub fn name(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `arguments args`
+    pub fn args(&self) -> ::pyo3::PyResult<Arguments<'py>> {
+        self.0.getattr("args")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `expr* decorator_list`
+    pub fn decorator_list(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("decorator_list")?.extract()
+    }
+    /// `expr? returns`
+    pub fn returns(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("returns")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.ClassDef);
+/// `ClassDef(identifier name, expr* bases, keyword* keywords, stmt* body, expr* decorator_list)`
+impl<'py> ClassDef<'py> {
+    /// `identifier name`
+    pub fn name(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `expr* bases`
+    pub fn bases(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("bases")?.extract()
+    }
+    /// `keyword* keywords`
+    pub fn keywords(&self) -> ::pyo3::PyResult<Vec<Keyword<'py>>> {
+        self.0.getattr("keywords")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `expr* decorator_list`
+    pub fn decorator_list(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("decorator_list")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Return);
+/// `Return(expr? value)`
+impl<'py> Return<'py> {
+    /// `expr? value`
+    pub fn value(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Delete);
+/// `Delete(expr* targets)`
+impl<'py> Delete<'py> {
+    /// `expr* targets`
+    pub fn targets(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("targets")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Assign);
+/// `Assign(expr* targets, expr value, string? type_comment)`
+impl<'py> Assign<'py> {
+    /// `expr* targets`
+    pub fn targets(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("targets")?.extract()
+    }
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.AugAssign);
+/// `AugAssign(expr target, operator op, expr value)`
+impl<'py> AugAssign<'py> {
+    /// `expr target`
+    pub fn target(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("target")?.extract()
+    }
+    /// `operator op`
+    pub fn op(&self) -> ::pyo3::PyResult<Operator> {
+        self.0.getattr("op")?.extract()
+    }
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.AnnAssign);
+/// `AnnAssign(expr target, expr annotation, expr? value, int simple)`
+impl<'py> AnnAssign<'py> {
+    /// `expr target`
+    pub fn target(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("target")?.extract()
+    }
+    /// `expr annotation`
+    pub fn annotation(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("annotation")?.extract()
+    }
+    /// `expr? value`
+    pub fn value(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int simple`
+    pub fn simple(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("simple")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.For);
+/// `For(expr target, expr iter, stmt* body, stmt* orelse, string? type_comment)`
+impl<'py> For<'py> {
+    /// `expr target`
+    pub fn target(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("target")?.extract()
+    }
+    /// `expr iter`
+    pub fn iter(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("iter")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `stmt* orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.AsyncFor);
+/// `AsyncFor(expr target, expr iter, stmt* body, stmt* orelse, string? type_comment)`
+impl<'py> AsyncFor<'py> {
+    /// `expr target`
+    pub fn target(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("target")?.extract()
+    }
+    /// `expr iter`
+    pub fn iter(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("iter")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `stmt* orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.While);
+/// `While(expr test, stmt* body, stmt* orelse)`
+impl<'py> While<'py> {
+    /// `expr test`
+    pub fn test(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("test")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `stmt* orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.If);
+/// `If(expr test, stmt* body, stmt* orelse)`
+impl<'py> If<'py> {
+    /// `expr test`
+    pub fn test(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("test")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `stmt* orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.With);
+/// `With(withitem* items, stmt* body, string? type_comment)`
+impl<'py> With<'py> {
+    /// `withitem* items`
+    pub fn items(&self) -> ::pyo3::PyResult<Vec<WithItem<'py>>> {
+        self.0.getattr("items")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.AsyncWith);
+/// `AsyncWith(withitem* items, stmt* body, string? type_comment)`
+impl<'py> AsyncWith<'py> {
+    /// `withitem* items`
+    pub fn items(&self) -> ::pyo3::PyResult<Vec<WithItem<'py>>> {
+        self.0.getattr("items")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Match);
+/// `Match(expr subject, match_case* cases)`
+impl<'py> Match<'py> {
+    /// `expr subject`
+    pub fn subject(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("subject")?.extract()
+    }
+    /// `match_case* cases`
+    pub fn cases(&self) -> ::pyo3::PyResult<Vec<MatchCase<'py>>> {
+        self.0.getattr("cases")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Raise);
+/// `Raise(expr? exc, expr? cause)`
+impl<'py> Raise<'py> {
+    /// `expr? exc`
+    pub fn exc(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("exc")?.extract()
+    }
+    /// `expr? cause`
+    pub fn cause(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("cause")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Try);
+/// `Try(stmt* body, excepthandler* handlers, stmt* orelse, stmt* finalbody)`
+impl<'py> Try<'py> {
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `excepthandler* handlers`
+    pub fn handlers(&self) -> ::pyo3::PyResult<Vec<ExceptHandler<'py>>> {
+        self.0.getattr("handlers")?.extract()
+    }
+    /// `stmt* orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `stmt* finalbody`
+    pub fn finalbody(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("finalbody")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.TryStar);
+/// `TryStar(stmt* body, excepthandler* handlers, stmt* orelse, stmt* finalbody)`
+impl<'py> TryStar<'py> {
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `excepthandler* handlers`
+    pub fn handlers(&self) -> ::pyo3::PyResult<Vec<ExceptHandler<'py>>> {
+        self.0.getattr("handlers")?.extract()
+    }
+    /// `stmt* orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `stmt* finalbody`
+    pub fn finalbody(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("finalbody")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Assert);
+/// `Assert(expr test, expr? msg)`
+impl<'py> Assert<'py> {
+    /// `expr test`
+    pub fn test(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("test")?.extract()
+    }
+    /// `expr? msg`
+    pub fn msg(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("msg")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Import);
+/// `Import(alias* names)`
+impl<'py> Import<'py> {
+    /// `alias* names`
+    pub fn names(&self) -> ::pyo3::PyResult<Vec<Alias<'py>>> {
+        self.0.getattr("names")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.ImportFrom);
+/// `ImportFrom(identifier? module, alias* names, int? level)`
+impl<'py> ImportFrom<'py> {
+    /// `identifier? module`
+    pub fn module(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("module")?.extract()
+    }
+    /// `alias* names`
+    pub fn names(&self) -> ::pyo3::PyResult<Vec<Alias<'py>>> {
+        self.0.getattr("names")?.extract()
+    }
+    /// `int? level`
+    pub fn level(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("level")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Global);
+/// `Global(identifier* names)`
+impl<'py> Global<'py> {
+    /// `identifier* names`
+    pub fn names(&self) -> ::pyo3::PyResult<Vec<&'py str>> {
+        self.0.getattr("names")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Nonlocal);
+/// `Nonlocal(identifier* names)`
+impl<'py> Nonlocal<'py> {
+    /// `identifier* names`
+    pub fn names(&self) -> ::pyo3::PyResult<Vec<&'py str>> {
+        self.0.getattr("names")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Expr);
+/// `Expr(expr value)`
+impl<'py> Expr<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Pass);
+/// `Pass`
+impl<'py> Pass<'py> {
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Break);
+/// `Break`
+impl<'py> Break<'py> {
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Continue);
+/// `Continue`
+impl<'py> Continue<'py> {
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+/// `expr`
+#[derive(Debug)]
+pub enum Expressions<'py> {
+    BoolOp(BoolOp<'py>),
+    NamedExpr(NamedExpr<'py>),
+    BinOp(BinOp<'py>),
+    UnaryOp(UnaryOp<'py>),
+    Lambda(Lambda<'py>),
+    IfExp(IfExp<'py>),
+    Dict(Dict<'py>),
+    Set(Set<'py>),
+    ListComp(ListComp<'py>),
+    SetComp(SetComp<'py>),
+    DictComp(DictComp<'py>),
+    GeneratorExp(GeneratorExp<'py>),
+    Await(Await<'py>),
+    Yield(Yield<'py>),
+    YieldFrom(YieldFrom<'py>),
+    Compare(Compare<'py>),
+    Call(Call<'py>),
+    FormattedValue(FormattedValue<'py>),
+    JoinedStr(JoinedStr<'py>),
+    Constant(Constant<'py>),
+    Attribute(Attribute<'py>),
+    Subscript(Subscript<'py>),
+    Starred(Starred<'py>),
+    Name(Name<'py>),
+    List(List<'py>),
+    Tuple(Tuple<'py>),
+    Slice(Slice<'py>),
+    /// `ast.expr` of a newer Python than the one generating this module
+    Other(&'py ::pyo3::PyAny),
+}
+impl<'py> ::pyo3::FromPyObject<'py> for Expressions<'py> {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "expr")?;
+        Ok(match ob.get_type().name()? {
+            "BoolOp" => Expressions::BoolOp(ob.extract()?),
+            "NamedExpr" => Expressions::NamedExpr(ob.extract()?),
+            "BinOp" => Expressions::BinOp(ob.extract()?),
+            "UnaryOp" => Expressions::UnaryOp(ob.extract()?),
+            "Lambda" => Expressions::Lambda(ob.extract()?),
+            "IfExp" => Expressions::IfExp(ob.extract()?),
+            "Dict" => Expressions::Dict(ob.extract()?),
+            "Set" => Expressions::Set(ob.extract()?),
+            "ListComp" => Expressions::ListComp(ob.extract()?),
+            "SetComp" => Expressions::SetComp(ob.extract()?),
+            "DictComp" => Expressions::DictComp(ob.extract()?),
+            "GeneratorExp" => Expressions::GeneratorExp(ob.extract()?),
+            "Await" => Expressions::Await(ob.extract()?),
+            "Yield" => Expressions::Yield(ob.extract()?),
+            "YieldFrom" => Expressions::YieldFrom(ob.extract()?),
+            "Compare" => Expressions::Compare(ob.extract()?),
+            "Call" => Expressions::Call(ob.extract()?),
+            "FormattedValue" => Expressions::FormattedValue(ob.extract()?),
+            "JoinedStr" => Expressions::JoinedStr(ob.extract()?),
+            "Constant" => Expressions::Constant(ob.extract()?),
+            "Attribute" => Expressions::Attribute(ob.extract()?),
+            "Subscript" => Expressions::Subscript(ob.extract()?),
+            "Starred" => Expressions::Starred(ob.extract()?),
+            "Name" => Expressions::Name(ob.extract()?),
+            "List" => Expressions::List(ob.extract()?),
+            "Tuple" => Expressions::Tuple(ob.extract()?),
+            "Slice" => Expressions::Slice(ob.extract()?),
+            _ => Expressions::Other(ob),
+        })
+    }
+}
+impl<'py> Expressions<'py> {
+    /// The underlying Python object
+    pub fn as_any(&self) -> &'py ::pyo3::PyAny {
+        match self {
+            Expressions::BoolOp(node) => node.0,
+            Expressions::NamedExpr(node) => node.0,
+            Expressions::BinOp(node) => node.0,
+            Expressions::UnaryOp(node) => node.0,
+            Expressions::Lambda(node) => node.0,
+            Expressions::IfExp(node) => node.0,
+            Expressions::Dict(node) => node.0,
+            Expressions::Set(node) => node.0,
+            Expressions::ListComp(node) => node.0,
+            Expressions::SetComp(node) => node.0,
+            Expressions::DictComp(node) => node.0,
+            Expressions::GeneratorExp(node) => node.0,
+            Expressions::Await(node) => node.0,
+            Expressions::Yield(node) => node.0,
+            Expressions::YieldFrom(node) => node.0,
+            Expressions::Compare(node) => node.0,
+            Expressions::Call(node) => node.0,
+            Expressions::FormattedValue(node) => node.0,
+            Expressions::JoinedStr(node) => node.0,
+            Expressions::Constant(node) => node.0,
+            Expressions::Attribute(node) => node.0,
+            Expressions::Subscript(node) => node.0,
+            Expressions::Starred(node) => node.0,
+            Expressions::Name(node) => node.0,
+            Expressions::List(node) => node.0,
+            Expressions::Tuple(node) => node.0,
+            Expressions::Slice(node) => node.0,
+            Expressions::Other(node) => node,
+        }
+    }
+}
+import_pytype!(ast.BoolOp);
+/// `BoolOp(boolop op, expr* values)`
+impl<'py> BoolOp<'py> {
+    /// `boolop op`
+    pub fn op(&self) -> ::pyo3::PyResult<BoolOperator> {
+        self.0.getattr("op")?.extract()
+    }
+    /// `expr* values`
+    pub fn values(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("values")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.NamedExpr);
+/// `NamedExpr(expr target, expr value)`
+impl<'py> NamedExpr<'py> {
+    /// `expr target`
+    pub fn target(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("target")?.extract()
+    }
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.BinOp);
+/// `BinOp(expr left, operator op, expr right)`
+impl<'py> BinOp<'py> {
+    /// `expr left`
+    pub fn left(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("left")?.extract()
+    }
+    /// `operator op`
+    pub fn op(&self) -> ::pyo3::PyResult<Operator> {
+        self.0.getattr("op")?.extract()
+    }
+    /// `expr right`
+    pub fn right(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("right")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.UnaryOp);
+/// `UnaryOp(unaryop op, expr operand)`
+impl<'py> UnaryOp<'py> {
+    /// `unaryop op`
+    pub fn op(&self) -> ::pyo3::PyResult<UnaryOperator> {
+        self.0.getattr("op")?.extract()
+    }
+    /// `expr operand`
+    pub fn operand(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("operand")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Lambda);
+/// `Lambda(arguments args, expr body)`
+impl<'py> Lambda<'py> {
+    /// `arguments args`
+    pub fn args(&self) -> ::pyo3::PyResult<Arguments<'py>> {
+        self.0.getattr("args")?.extract()
+    }
+    /// `expr body`
+    pub fn body(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.IfExp);
+/// `IfExp(expr test, expr body, expr orelse)`
+impl<'py> IfExp<'py> {
+    /// `expr test`
+    pub fn test(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("test")?.extract()
+    }
+    /// `expr body`
+    pub fn body(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `expr orelse`
+    pub fn orelse(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("orelse")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Dict);
+/// `Dict(expr* keys, expr* values)`
+impl<'py> Dict<'py> {
+    /// `expr* keys`
+    pub fn keys(&self) -> ::pyo3::PyResult<Vec<Option<Expressions<'py>>>> {
+        self.0.getattr("keys")?.extract()
+    }
+    /// `expr* values`
+    pub fn values(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("values")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Set);
+/// `Set(expr* elts)`
+impl<'py> Set<'py> {
+    /// `expr* elts`
+    pub fn elts(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("elts")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.ListComp);
+/// `ListComp(expr elt, comprehension* generators)`
+impl<'py> ListComp<'py> {
+    /// `expr elt`
+    pub fn elt(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("elt")?.extract()
+    }
+    /// `comprehension* generators`
+    pub fn generators(&self) -> ::pyo3::PyResult<Vec<Comprehension<'py>>> {
+        self.0.getattr("generators")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.SetComp);
+/// `SetComp(expr elt, comprehension* generators)`
+impl<'py> SetComp<'py> {
+    /// `expr elt`
+    pub fn elt(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("elt")?.extract()
+    }
+    /// `comprehension* generators`
+    pub fn generators(&self) -> ::pyo3::PyResult<Vec<Comprehension<'py>>> {
+        self.0.getattr("generators")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.DictComp);
+/// `DictComp(expr key, expr value, comprehension* generators)`
+impl<'py> DictComp<'py> {
+    /// `expr key`
+    pub fn key(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("key")?.extract()
+    }
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `comprehension* generators`
+    pub fn generators(&self) -> ::pyo3::PyResult<Vec<Comprehension<'py>>> {
+        self.0.getattr("generators")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.GeneratorExp);
+/// `GeneratorExp(expr elt, comprehension* generators)`
+impl<'py> GeneratorExp<'py> {
+    /// `expr elt`
+    pub fn elt(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("elt")?.extract()
+    }
+    /// `comprehension* generators`
+    pub fn generators(&self) -> ::pyo3::PyResult<Vec<Comprehension<'py>>> {
+        self.0.getattr("generators")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Await);
+/// `Await(expr value)`
+impl<'py> Await<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Yield);
+/// `Yield(expr? value)`
+impl<'py> Yield<'py> {
+    /// `expr? value`
+    pub fn value(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.YieldFrom);
+/// `YieldFrom(expr value)`
+impl<'py> YieldFrom<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Compare);
+/// `Compare(expr left, cmpop* ops, expr* comparators)`
+impl<'py> Compare<'py> {
+    /// `expr left`
+    pub fn left(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("left")?.extract()
+    }
+    /// `cmpop* ops`
+    pub fn ops(&self) -> ::pyo3::PyResult<Vec<CmpOp>> {
+        self.0.getattr("ops")?.extract()
+    }
+    /// `expr* comparators`
+    pub fn comparators(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("comparators")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Call);
+/// `Call(expr func, expr* args, keyword* keywords)`
+impl<'py> Call<'py> {
+    /// `expr func`
+    pub fn func(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("func")?.extract()
+    }
+    /// `expr* args`
+    pub fn args(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("args")?.extract()
+    }
+    /// `keyword* keywords`
+    pub fn keywords(&self) -> ::pyo3::PyResult<Vec<Keyword<'py>>> {
+        self.0.getattr("keywords")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.FormattedValue);
+/// `FormattedValue(expr value, int conversion, expr? format_spec)`
+impl<'py> FormattedValue<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int conversion`
+    pub fn conversion(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("conversion")?.extract()
+    }
+    /// `expr? format_spec`
+    pub fn format_spec(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("format_spec")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.JoinedStr);
+/// `JoinedStr(expr* values)`
+impl<'py> JoinedStr<'py> {
+    /// `expr* values`
+    pub fn values(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("values")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Constant);
+/// `Constant(constant value, string? kind)`
+impl<'py> Constant<'py> {
+    /// `constant value`
+    pub fn value(&self) -> ::pyo3::PyResult<&'py ::pyo3::PyAny> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `string? kind`
+    pub fn kind(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("kind")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Attribute);
+/// `Attribute(expr value, identifier attr, expr_context ctx)`
+impl<'py> Attribute<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `identifier attr`
+    pub fn attr(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("attr")?.extract()
+    }
+    /// `expr_context ctx`
+    pub fn ctx(&self) -> ::pyo3::PyResult<ExprContext> {
+        self.0.getattr("ctx")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Subscript);
+/// `Subscript(expr value, expr slice, expr_context ctx)`
+impl<'py> Subscript<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `expr slice`
+    pub fn slice(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("slice")?.extract()
+    }
+    /// `expr_context ctx`
+    pub fn ctx(&self) -> ::pyo3::PyResult<ExprContext> {
+        self.0.getattr("ctx")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Starred);
+/// `Starred(expr value, expr_context ctx)`
+impl<'py> Starred<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `expr_context ctx`
+    pub fn ctx(&self) -> ::pyo3::PyResult<ExprContext> {
+        self.0.getattr("ctx")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Name);
+/// `Name(identifier id, expr_context ctx)`
+impl<'py> Name<'py> {
+    /// `identifier id`
+    pub fn id(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("id")?.extract()
+    }
+    /// `expr_context ctx`
+    pub fn ctx(&self) -> ::pyo3::PyResult<ExprContext> {
+        self.0.getattr("ctx")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.List);
+/// `List(expr* elts, expr_context ctx)`
+impl<'py> List<'py> {
+    /// `expr* elts`
+    pub fn elts(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("elts")?.extract()
+    }
+    /// `expr_context ctx`
+    pub fn ctx(&self) -> ::pyo3::PyResult<ExprContext> {
+        self.0.getattr("ctx")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Tuple);
+/// `Tuple(expr* elts, expr_context ctx)`
+impl<'py> Tuple<'py> {
+    /// `expr* elts`
+    pub fn elts(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("elts")?.extract()
+    }
+    /// `expr_context ctx`
+    pub fn ctx(&self) -> ::pyo3::PyResult<ExprContext> {
+        self.0.getattr("ctx")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.Slice);
+/// `Slice(expr? lower, expr? upper, expr? step)`
+impl<'py> Slice<'py> {
+    /// `expr? lower`
+    pub fn lower(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("lower")?.extract()
+    }
+    /// `expr? upper`
+    pub fn upper(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("upper")?.extract()
+    }
+    /// `expr? step`
+    pub fn step(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("step")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+/// `expr_context = Load | Store | Del`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExprContext {
+    Load,
+    Store,
+    Del,
+}
+impl<'py> ::pyo3::FromPyObject<'py> for ExprContext {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "expr_context")?;
+        match ob.get_type().name()? {
+            "Load" => Ok(ExprContext::Load),
+            "Store" => Ok(ExprContext::Store),
+            "Del" => Ok(ExprContext::Del),
+            other => Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                "Unknown ast.{}: {}",
+                "expr_context", other
+            ))),
+        }
+    }
+}
+/// `boolop = And | Or`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BoolOperator {
+    And,
+    Or,
+}
+impl<'py> ::pyo3::FromPyObject<'py> for BoolOperator {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "boolop")?;
+        match ob.get_type().name()? {
+            "And" => Ok(BoolOperator::And),
+            "Or" => Ok(BoolOperator::Or),
+            other => Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                "Unknown ast.{}: {}",
+                "boolop", other
+            ))),
+        }
+    }
+}
+/// `operator = Add | Sub | Mult | MatMult | Div | Mod | Pow | LShift | RShift | BitOr | BitXor | BitAnd | FloorDiv`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mult,
+    MatMult,
+    Div,
+    Mod,
+    Pow,
+    LShift,
+    RShift,
+    BitOr,
+    BitXor,
+    BitAnd,
+    FloorDiv,
+}
+impl<'py> ::pyo3::FromPyObject<'py> for Operator {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "operator")?;
+        match ob.get_type().name()? {
+            "Add" => Ok(Operator::Add),
+            "Sub" => Ok(Operator::Sub),
+            "Mult" => Ok(Operator::Mult),
+            "MatMult" => Ok(Operator::MatMult),
+            "Div" => Ok(Operator::Div),
+            "Mod" => Ok(Operator::Mod),
+            "Pow" => Ok(Operator::Pow),
+            "LShift" => Ok(Operator::LShift),
+            "RShift" => Ok(Operator::RShift),
+            "BitOr" => Ok(Operator::BitOr),
+            "BitXor" => Ok(Operator::BitXor),
+            "BitAnd" => Ok(Operator::BitAnd),
+            "FloorDiv" => Ok(Operator::FloorDiv),
+            other => Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                "Unknown ast.{}: {}",
+                "operator", other
+            ))),
+        }
+    }
+}
+/// `unaryop = Invert | Not | UAdd | USub`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnaryOperator {
+    Invert,
+    Not,
+    UAdd,
+    USub,
+}
+impl<'py> ::pyo3::FromPyObject<'py> for UnaryOperator {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "unaryop")?;
+        match ob.get_type().name()? {
+            "Invert" => Ok(UnaryOperator::Invert),
+            "Not" => Ok(UnaryOperator::Not),
+            "UAdd" => Ok(UnaryOperator::UAdd),
+            "USub" => Ok(UnaryOperator::USub),
+            other => Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                "Unknown ast.{}: {}",
+                "unaryop", other
+            ))),
+        }
+    }
+}
+/// `cmpop = Eq | NotEq | Lt | LtE | Gt | GtE | Is | IsNot | In | NotIn`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CmpOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtE,
+    Gt,
+    GtE,
+    Is,
+    IsNot,
+    In,
+    NotIn,
+}
+impl<'py> ::pyo3::FromPyObject<'py> for CmpOp {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "cmpop")?;
+        match ob.get_type().name()? {
+            "Eq" => Ok(CmpOp::Eq),
+            "NotEq" => Ok(CmpOp::NotEq),
+            "Lt" => Ok(CmpOp::Lt),
+            "LtE" => Ok(CmpOp::LtE),
+            "Gt" => Ok(CmpOp::Gt),
+            "GtE" => Ok(CmpOp::GtE),
+            "Is" => Ok(CmpOp::Is),
+            "IsNot" => Ok(CmpOp::IsNot),
+            "In" => Ok(CmpOp::In),
+            "NotIn" => Ok(CmpOp::NotIn),
+            other => Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                "Unknown ast.{}: {}",
+                "cmpop", other
+            ))),
+        }
+    }
+}
+import_pytype!(ast.comprehension as Comprehension);
+/// `comprehension(expr target, expr iter, expr* ifs, int is_async)`
+impl<'py> Comprehension<'py> {
+    /// `expr target`
+    pub fn target(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("target")?.extract()
+    }
+    /// `expr iter`
+    pub fn iter(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("iter")?.extract()
+    }
+    /// `expr* ifs`
+    pub fn ifs(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("ifs")?.extract()
+    }
+    /// `int is_async`
+    pub fn is_async(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("is_async")?.extract()
+    }
+}
+import_pytype!(ast.ExceptHandler);
+/// `ExceptHandler(expr? type, identifier? name, stmt* body)`
+impl<'py> ExceptHandler<'py> {
+    /// `expr? type`
+    pub fn r#type(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("type")?.extract()
+    }
+    /// `identifier? name`
+    pub fn name(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.arguments as Arguments);
+/// `arguments(arg* posonlyargs, arg* args, arg? vararg, arg* kwonlyargs, expr* kw_defaults, arg? kwarg, expr* defaults)`
+impl<'py> Arguments<'py> {
+    /// `arg* posonlyargs`
+    pub fn posonlyargs(&self) -> ::pyo3::PyResult<Vec<Arg<'py>>> {
+        self.0.getattr("posonlyargs")?.extract()
+    }
+    /// `arg* args`
+    pub fn args(&self) -> ::pyo3::PyResult<Vec<Arg<'py>>> {
+        self.0.getattr("args")?.extract()
+    }
+    /// `arg? vararg`
+    pub fn vararg(&self) -> ::pyo3::PyResult<Option<Arg<'py>>> {
+        self.0.getattr("vararg")?.extract()
+    }
+    /// `arg* kwonlyargs`
+    pub fn kwonlyargs(&self) -> ::pyo3::PyResult<Vec<Arg<'py>>> {
+        self.0.getattr("kwonlyargs")?.extract()
+    }
+    /// `expr* kw_defaults`
+    pub fn kw_defaults(&self) -> ::pyo3::PyResult<Vec<Option<Expressions<'py>>>> {
+        self.0.getattr("kw_defaults")?.extract()
+    }
+    /// `arg? kwarg`
+    pub fn kwarg(&self) -> ::pyo3::PyResult<Option<Arg<'py>>> {
+        self.0.getattr("kwarg")?.extract()
+    }
+    /// `expr* defaults`
+    pub fn defaults(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("defaults")?.extract()
+    }
+}
+import_pytype!(ast.arg as Arg);
+/// `arg(identifier arg, expr? annotation, string? type_comment)`
+impl<'py> Arg<'py> {
+    /// `identifier arg`
+    pub fn arg(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("arg")?.extract()
+    }
+    /// `expr? annotation`
+    pub fn annotation(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("annotation")?.extract()
+    }
+    /// `string? type_comment`
+    pub fn type_comment(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("type_comment")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.keyword as Keyword);
+/// `keyword(identifier? arg, expr value)`
+impl<'py> Keyword<'py> {
+    /// `identifier? arg`
+    pub fn arg(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("arg")?.extract()
+    }
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.alias as Alias);
+/// `alias(identifier name, identifier? asname)`
+impl<'py> Alias<'py> {
+    /// `identifier name`
+    pub fn name(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `identifier? asname`
+    pub fn asname(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("asname")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.withitem as WithItem);
+/// `withitem(expr context_expr, expr? optional_vars)`
+impl<'py> WithItem<'py> {
+    /// `expr context_expr`
+    pub fn context_expr(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("context_expr")?.extract()
+    }
+    /// `expr? optional_vars`
+    pub fn optional_vars(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("optional_vars")?.extract()
+    }
+}
+import_pytype!(ast.match_case as MatchCase);
+/// `match_case(pattern pattern, expr? guard, stmt* body)`
+impl<'py> MatchCase<'py> {
+    /// `pattern pattern`
+    pub fn pattern(&self) -> ::pyo3::PyResult<Pattern<'py>> {
+        self.0.getattr("pattern")?.extract()
+    }
+    /// `expr? guard`
+    pub fn guard(&self) -> ::pyo3::PyResult<Option<Expressions<'py>>> {
+        self.0.getattr("guard")?.extract()
+    }
+    /// `stmt* body`
+    pub fn body(&self) -> ::pyo3::PyResult<Vec<Statements<'py>>> {
+        self.0.getattr("body")?.extract()
+    }
+}
+/// `pattern`
+#[derive(Debug)]
+pub enum Pattern<'py> {
+    MatchValue(MatchValue<'py>),
+    MatchSingleton(MatchSingleton<'py>),
+    MatchSequence(MatchSequence<'py>),
+    MatchMapping(MatchMapping<'py>),
+    MatchClass(MatchClass<'py>),
+    MatchStar(MatchStar<'py>),
+    MatchAs(MatchAs<'py>),
+    MatchOr(MatchOr<'py>),
+    /// `ast.pattern` of a newer Python than the one generating this module
+    Other(&'py ::pyo3::PyAny),
+}
+impl<'py> ::pyo3::FromPyObject<'py> for Pattern<'py> {
+    fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+        check_instance(ob, "pattern")?;
+        Ok(match ob.get_type().name()? {
+            "MatchValue" => Pattern::MatchValue(ob.extract()?),
+            "MatchSingleton" => Pattern::MatchSingleton(ob.extract()?),
+            "MatchSequence" => Pattern::MatchSequence(ob.extract()?),
+            "MatchMapping" => Pattern::MatchMapping(ob.extract()?),
+            "MatchClass" => Pattern::MatchClass(ob.extract()?),
+            "MatchStar" => Pattern::MatchStar(ob.extract()?),
+            "MatchAs" => Pattern::MatchAs(ob.extract()?),
+            "MatchOr" => Pattern::MatchOr(ob.extract()?),
+            _ => Pattern::Other(ob),
+        })
+    }
+}
+impl<'py> Pattern<'py> {
+    /// The underlying Python object
+    pub fn as_any(&self) -> &'py ::pyo3::PyAny {
+        match self {
+            Pattern::MatchValue(node) => node.0,
+            Pattern::MatchSingleton(node) => node.0,
+            Pattern::MatchSequence(node) => node.0,
+            Pattern::MatchMapping(node) => node.0,
+            Pattern::MatchClass(node) => node.0,
+            Pattern::MatchStar(node) => node.0,
+            Pattern::MatchAs(node) => node.0,
+            Pattern::MatchOr(node) => node.0,
+            Pattern::Other(node) => node,
+        }
+    }
+}
+import_pytype!(ast.MatchValue);
+/// `MatchValue(expr value)`
+impl<'py> MatchValue<'py> {
+    /// `expr value`
+    pub fn value(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchSingleton);
+/// `MatchSingleton(constant value)`
+impl<'py> MatchSingleton<'py> {
+    /// `constant value`
+    pub fn value(&self) -> ::pyo3::PyResult<&'py ::pyo3::PyAny> {
+        self.0.getattr("value")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchSequence);
+/// `MatchSequence(pattern* patterns)`
+impl<'py> MatchSequence<'py> {
+    /// `pattern* patterns`
+    pub fn patterns(&self) -> ::pyo3::PyResult<Vec<Pattern<'py>>> {
+        self.0.getattr("patterns")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchMapping);
+/// `MatchMapping(expr* keys, pattern* patterns, identifier? rest)`
+impl<'py> MatchMapping<'py> {
+    /// `expr* keys`
+    pub fn keys(&self) -> ::pyo3::PyResult<Vec<Expressions<'py>>> {
+        self.0.getattr("keys")?.extract()
+    }
+    /// `pattern* patterns`
+    pub fn patterns(&self) -> ::pyo3::PyResult<Vec<Pattern<'py>>> {
+        self.0.getattr("patterns")?.extract()
+    }
+    /// `identifier? rest`
+    pub fn rest(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("rest")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchClass);
+/// `MatchClass(expr cls, pattern* patterns, identifier* kwd_attrs, pattern* kwd_patterns)`
+impl<'py> MatchClass<'py> {
+    /// `expr cls`
+    pub fn cls(&self) -> ::pyo3::PyResult<Expressions<'py>> {
+        self.0.getattr("cls")?.extract()
+    }
+    /// `pattern* patterns`
+    pub fn patterns(&self) -> ::pyo3::PyResult<Vec<Pattern<'py>>> {
+        self.0.getattr("patterns")?.extract()
+    }
+    /// `identifier* kwd_attrs`
+    pub fn kwd_attrs(&self) -> ::pyo3::PyResult<Vec<&'py str>> {
+        self.0.getattr("kwd_attrs")?.extract()
+    }
+    /// `pattern* kwd_patterns`
+    pub fn kwd_patterns(&self) -> ::pyo3::PyResult<Vec<Pattern<'py>>> {
+        self.0.getattr("kwd_patterns")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchStar);
+/// `MatchStar(identifier? name)`
+impl<'py> MatchStar<'py> {
+    /// `identifier? name`
+    pub fn name(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchAs);
+/// `MatchAs(pattern? pattern, identifier? name)`
+impl<'py> MatchAs<'py> {
+    /// `pattern? pattern`
+    pub fn pattern(&self) -> ::pyo3::PyResult<Option<Pattern<'py>>> {
+        self.0.getattr("pattern")?.extract()
+    }
+    /// `identifier? name`
+    pub fn name(&self) -> ::pyo3::PyResult<Option<&'py str>> {
+        self.0.getattr("name")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.MatchOr);
+/// `MatchOr(pattern* patterns)`
+impl<'py> MatchOr<'py> {
+    /// `pattern* patterns`
+    pub fn patterns(&self) -> ::pyo3::PyResult<Vec<Pattern<'py>>> {
+        self.0.getattr("patterns")?.extract()
+    }
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `int col_offset`
+    pub fn col_offset(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("col_offset")?.extract()
+    }
+    /// `int? end_lineno`
+    pub fn end_lineno(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_lineno")?.extract()
+    }
+    /// `int? end_col_offset`
+    pub fn end_col_offset(&self) -> ::pyo3::PyResult<Option<i64>> {
+        self.0.getattr("end_col_offset")?.extract()
+    }
+}
+import_pytype!(ast.TypeIgnore);
+/// `TypeIgnore(int lineno, string tag)`
+impl<'py> TypeIgnore<'py> {
+    /// `int lineno`
+    pub fn lineno(&self) -> ::pyo3::PyResult<i64> {
+        self.0.getattr("lineno")?.extract()
+    }
+    /// `string tag`
+    pub fn tag(&self) -> ::pyo3::PyResult<&'py str> {
+        self.0.getattr("tag")?.extract()
+    }
+}
@@ -1,6 +1,11 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use std::path::Path;
+use py2o2::{
+    codegen::{self, CodegenOptions},
+    frontend,
+    inspect::{InspectOptions, Interface},
+};
+use std::path::{Path, PathBuf};
 
 #[derive(Parser, Debug)]
 struct Cli {
@@ -11,7 +16,27 @@ struct Cli {
 #[derive(Subcommand, Debug)]
 enum Command {
     /// Serialize Python interface into JSON
-    Inspect { python_module_name_or_path: String },
+    Inspect {
+        python_module_name_or_path: String,
+        /// Infer types of unannotated parameters from docstrings
+        #[arg(long, default_value_t = false)]
+        infer_from_docstring: bool,
+        /// Directory searched for `.pyi` stubs, e.g. a checkout of typeshed
+        #[arg(long = "stub-path", value_name = "DIR")]
+        stub_paths: Vec<PathBuf>,
+        /// Read the source file without importing it, i.e. without Python interpreter
+        #[arg(long = "static", default_value_t = false)]
+        static_: bool,
+        /// Read annotations through Python's `ast` module without executing the module
+        #[arg(long, default_value_t = false)]
+        no_import: bool,
+        /// Python version which `sys.version_info` guards are evaluated against, e.g. `3.10`
+        #[arg(long, value_name = "X.Y", value_parser = parse_python_version)]
+        python_version: Option<(u8, u8)>,
+        /// `sys.platform` which platform guards are evaluated against, e.g. `win32`
+        #[arg(long)]
+        platform: Option<String>,
+    },
 
     /// Generate Rust code for Python module
     Codegen {
@@ -19,9 +44,37 @@ enum Command {
         /// Generate Rust code without creating modules
         #[arg(short, long, default_value_t = false)]
         bare: bool,
+        /// Also generate `gil` module of functions acquiring the GIL by themselves
+        #[arg(long, default_value_t = false)]
+        gil: bool,
+        /// Infer types of unannotated parameters from docstrings
+        #[arg(long, default_value_t = false)]
+        infer_from_docstring: bool,
+        /// Directory searched for `.pyi` stubs, e.g. a checkout of typeshed
+        #[arg(long = "stub-path", value_name = "DIR")]
+        stub_paths: Vec<PathBuf>,
+        /// Read the source file without importing it, i.e. without Python interpreter
+        #[arg(long = "static", default_value_t = false)]
+        static_: bool,
+        /// Read annotations through Python's `ast` module without executing the module
+        #[arg(long, default_value_t = false)]
+        no_import: bool,
+        /// Python version which `sys.version_info` guards are evaluated against, e.g. `3.10`
+        #[arg(long, value_name = "X.Y", value_parser = parse_python_version)]
+        python_version: Option<(u8, u8)>,
+        /// `sys.platform` which platform guards are evaluated against, e.g. `win32`
+        #[arg(long)]
+        platform: Option<String>,
     },
 }
 
+fn parse_python_version(version: &str) -> Result<(u8, u8)> {
+    let (major, minor) = version
+        .split_once('.')
+        .context("Python version must be given as `X.Y`, e.g. `3.10`")?;
+    Ok((major.parse()?, minor.parse()?))
+}
+
 fn seek_py_module(python_module_name_or_path: &str) -> Result<String> {
     let path = Path::new(&python_module_name_or_path);
     if path.exists() {
@@ -47,15 +100,74 @@ fn main() -> Result<()> {
         Command::Codegen {
             python_module_name_or_path,
             bare,
+            gil,
+            infer_from_docstring,
+            stub_paths,
+            static_,
+            no_import,
+            python_version,
+            platform,
         } => {
-            let pymod = seek_py_module(&python_module_name_or_path)?;
-            println!("{}", py2o2::generate(&pymod, bare)?);
+            let options = InspectOptions {
+                infer_from_docstring,
+                stub_paths,
+                no_import,
+                python_version,
+                platform,
+            };
+            let codegen_options = CodegenOptions { bare, gil };
+            let (pymod, interface) = if static_ {
+                let path = Path::new(&python_module_name_or_path);
+                let interface = Interface::from_source_path(path, &options)?;
+                (frontend::module_name(path)?, interface)
+            } else {
+                let pymod = seek_py_module(&python_module_name_or_path)?;
+                let interface = Interface::from_py_module_with_options(&pymod, &options)?;
+                (pymod, interface)
+            };
+            let unannotated = interface.unannotated();
+            if !unannotated.is_empty() {
+                eprintln!(
+                    "warning: Not annotated in `{}`, use PyObject instead: {}",
+                    pymod,
+                    unannotated.join(", ")
+                );
+            }
+            if !interface.without_signature.is_empty() {
+                eprintln!(
+                    "warning: No signature found in `{}`, skipped: {}",
+                    pymod,
+                    interface.without_signature.join(", ")
+                );
+            }
+            println!(
+                "{}",
+                codegen::generate_with_options(&pymod, &interface, &codegen_options)?
+            );
         }
         Command::Inspect {
             python_module_name_or_path,
+            infer_from_docstring,
+            stub_paths,
+            static_,
+            no_import,
+            python_version,
+            platform,
         } => {
+            let options = InspectOptions {
+                infer_from_docstring,
+                stub_paths,
+                no_import,
+                python_version,
+                platform,
+            };
+            if static_ {
+                let path = Path::new(&python_module_name_or_path);
+                println!("{}", py2o2::inspect_from_source_path(path, &options)?);
+                return Ok(());
+            }
             let pymod = seek_py_module(&python_module_name_or_path)?;
-            println!("{}", py2o2::inspect(&pymod)?);
+            println!("{}", py2o2::inspect_with_options(&pymod, &options)?);
         }
     }
     Ok(())
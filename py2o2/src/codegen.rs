@@ -1,14 +1,66 @@
 use crate::inspect::*;
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use std::collections::{hash_map::DefaultHasher, BTreeMap};
+use std::collections::{hash_map::DefaultHasher, BTreeMap, BTreeSet};
+use std::fmt::Write;
 use std::hash::{Hash, Hasher};
 
 fn format(tt: TokenStream2) -> String {
     prettyplease::unparse(&syn::parse_file(&tt.to_string()).unwrap())
 }
 
+/// Convert a document into `#[doc = "..."]` attributes, which are printed as `///` comments
+fn doc_attrs(doc: &str) -> TokenStream2 {
+    let lines = doc.lines().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", line)
+        }
+    });
+    quote! { #(#[doc = #lines])* }
+}
+
+fn function_doc(f: &Function) -> String {
+    let mut doc = String::new();
+    if let Some(docstring) = &f.doc {
+        // Only the summary is written as Markdown. The rest is kept in a text block
+        // since indented sections, e.g. NumPy style parameters, are code blocks for rustdoc.
+        let (summary, rest) = docstring.split_once("\n\n").unwrap_or((docstring, ""));
+        writeln!(doc, "{}\n", summary).unwrap();
+        if !rest.trim().is_empty() {
+            writeln!(doc, "```text\n{}\n```\n", rest.trim_end()).unwrap();
+        }
+    }
+    // Descriptions of parameters are already in the docstring above
+    let inferred: Vec<_> = f
+        .parameters
+        .iter()
+        .filter(|p| p.inferred)
+        .map(|p| format!("`{}`", p.name))
+        .collect();
+    if !inferred.is_empty() {
+        let (subject, verb) = if inferred.len() == 1 {
+            ("Type", "is")
+        } else {
+            ("Types", "are")
+        };
+        writeln!(
+            doc,
+            "{} of {} {} inferred from the docstring.\n",
+            subject,
+            inferred.join(", "),
+            verb
+        )
+        .unwrap();
+    }
+    if let Some(signature) = &f.signature {
+        writeln!(doc, "# Python signature\n\n```python\n{}\n```", signature).unwrap();
+    }
+    doc.trim_end().to_string()
+}
+
 fn union_trait_ident(args: &[Type]) -> syn::Ident {
     let mut s = DefaultHasher::new();
     for arg in args {
@@ -18,113 +70,388 @@ fn union_trait_ident(args: &[Type]) -> syn::Ident {
     quote::format_ident!("Union{:x}", hash)
 }
 
-fn callable_trait(args: &[Type], ret: &Type) -> TokenStream2 {
+fn callable_trait(
+    args: &[Type],
+    keywords: &[Parameter],
+    param_spec: &Option<String>,
+    ret: &Type,
+) -> Result<TokenStream2> {
+    // `-> ()` is omitted as in handwritten code
+    let out = if ret.unannotated() == &Type::None {
+        quote!()
+    } else {
+        let out = as_output_type(ret)?;
+        quote!(-> #out)
+    };
+    let args = args.iter().map(as_input_type).collect::<Result<Vec<_>>>()?;
+    if let Some(param_spec) = param_spec {
+        // PEP 612 forbids keyword-only parameters between `*args: P.args` and `**kwargs: P.kwargs`
+        if !keywords.is_empty() {
+            bail!(
+                "Keyword-only parameters cannot be combined with ParamSpec `{}`",
+                param_spec
+            );
+        }
+        // Arguments taken by `ParamSpec` are passed as they are
+        return Ok(quote!(
+            Fn((#(#args,)*), &::pyo3::types::PyTuple, Option<&::pyo3::types::PyDict>) #out + Send + 'static
+        ));
+    }
+    if !keywords.is_empty() {
+        // Keyword arguments may be omitted by the caller
+        let keywords = keywords
+            .iter()
+            .map(|p| as_input_type(&p.r#type))
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(quote!(Fn((#(#args,)*), (#(Option<#keywords>,)*)) #out + Send + 'static));
+    }
     let args = if args.is_empty() {
         quote! {}
     } else {
-        let args: Vec<_> = args.iter().map(as_input_type).collect();
         quote! {(#(#args,)*)}
     };
-    let out = as_output_type(ret);
-    quote!(Fn(#args) -> #out + Send + 'static)
+    Ok(quote!(Fn(#args) #out + Send + 'static))
 }
 
-pub fn as_input_type(ty: &Type) -> syn::Type {
-    match ty {
+/// Rust type specified by `Annotated[T, py2o2.Rust("...")]`
+fn annotated_rust_type(metadata: &[Annotation]) -> Result<Option<syn::Type>> {
+    for m in metadata {
+        if let Annotation::Rust { r#type } = m {
+            let ty = syn::parse_str(r#type)
+                .with_context(|| format!("Invalid Rust type in annotation: {}", r#type))?;
+            return Ok(Some(ty));
+        }
+    }
+    Ok(None)
+}
+
+/// Function not to be generated, i.e. annotated by `py2o2.Skip()` in its parameters or return
+fn is_skipped(f: &Function) -> bool {
+    std::iter::once(&f.r#return)
+        .chain(f.parameters.iter().map(|p| &p.r#type))
+        .any(|ty| ty.metadata().contains(&Annotation::Skip))
+}
+
+/// Rust identifier for a Python name
+///
+/// Both languages define identifiers by XID_Start and XID_Continue, and Python names are already in NFKC,
+/// so `λ_to_ν` is kept as it is. Characters Rust does not accept,
+/// e.g. ones added in a Unicode version newer than rustc knows, are escaped as `_u{hex}`.
+/// Rust keywords are written as raw identifiers, e.g. `r#type`,
+/// except for ones which cannot be raw, e.g. `self` is renamed into `self_`, and `_` into `underscore`.
+fn rust_ident(name: &str) -> syn::Ident {
+    let mut ident = String::with_capacity(name.len());
+    for (i, c) in name.chars().enumerate() {
+        let valid = if i == 0 {
+            c == '_' || unicode_ident::is_xid_start(c)
+        } else {
+            unicode_ident::is_xid_continue(c)
+        };
+        if valid {
+            ident.push(c);
+        } else {
+            write!(ident, "_u{:x}", c as u32).unwrap();
+        }
+    }
+    match ident.as_str() {
+        "self" | "Self" | "super" | "crate" => {
+            ident.push('_');
+            syn::Ident::new(&ident, Span::call_site())
+        }
+        "_" => syn::Ident::new("underscore", Span::call_site()),
+        _ if RUST_KEYWORDS.contains(&ident.as_str()) => {
+            syn::Ident::new_raw(&ident, Span::call_site())
+        }
+        _ => syn::Ident::new(&ident, Span::call_site()),
+    }
+}
+
+/// Strict and reserved keywords of Rust up to the 2024 edition, which can be raw identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Parameter name in Rust, which can be renamed by `Annotated[T, py2o2.Rename("...")]`
+fn param_ident(p: &Parameter) -> syn::Ident {
+    let name = p
+        .r#type
+        .metadata()
+        .iter()
+        .find_map(|m| match m {
+            Annotation::Rename { name } => Some(name),
+            _ => None,
+        })
+        .unwrap_or(&p.name);
+    rust_ident(name)
+}
+
+pub fn as_input_type(ty: &Type) -> Result<syn::Type> {
+    Ok(match ty {
         Type::Primitive(Primitive::Int) => syn::parse_quote!(i64),
         Type::Primitive(Primitive::Float) => syn::parse_quote!(f64),
         Type::Primitive(Primitive::Str) => syn::parse_quote!(&str),
         Type::None => syn::parse_quote!(()),
         Type::Ellipsis | Type::Exception => syn::parse_quote!(::pyo3::Py<::pyo3::PyAny>),
+        Type::Any => syn::parse_quote!(::pyo3::PyObject),
+        Type::Never => syn::parse_quote!(::std::convert::Infallible),
+        Type::Unknown => syn::parse_quote!(impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>),
+        Type::ClassObject { name, .. } => {
+            let ty = rust_ident(name);
+            syn::parse_quote!(::py2o2_runtime::ClassObject<#ty>)
+        }
         Type::Tuple { tags } => {
-            let tags: Vec<syn::Type> = tags.iter().map(as_input_type).collect();
+            let tags = tags.iter().map(as_input_type).collect::<Result<Vec<_>>>()?;
             syn::parse_quote! { (#(#tags),*) }
         }
         Type::List { .. } => syn::parse_quote! { &::pyo3::types::PyList },
         Type::Dict { .. } => syn::parse_quote! { &::pyo3::types::PyDict },
         Type::UserDefined { name, .. } => {
-            let ty = syn::Ident::new(name, Span::call_site());
+            let ty = rust_ident(name);
             syn::parse_quote!(#ty)
         }
         Type::Union { args } => {
             let ident = union_trait_ident(args);
             syn::parse_quote!(impl #ident)
         }
-        Type::Callable { args, r#return } => {
-            let t = callable_trait(args, r#return);
+        Type::Callable {
+            args,
+            keywords,
+            param_spec,
+            r#return,
+        } => {
+            let t = callable_trait(args, keywords, param_spec, r#return)?;
             syn::parse_quote!(impl #t)
         }
-    }
+        Type::Annotated { inner, metadata } => match annotated_rust_type(metadata)? {
+            Some(ty) => ty,
+            None => as_input_type(inner)?,
+        },
+    })
 }
 
-pub fn as_output_type(ty: &Type) -> syn::Type {
-    match ty {
+pub fn as_output_type(ty: &Type) -> Result<syn::Type> {
+    Ok(match ty {
         Type::Primitive(Primitive::Int) => syn::parse_quote!(i64),
         Type::Primitive(Primitive::Float) => syn::parse_quote!(f64),
         Type::Primitive(Primitive::Str) => syn::parse_quote!(::pyo3::Py<::pyo3::types::PyString>),
         Type::None => syn::parse_quote!(()),
         Type::Ellipsis | Type::Exception => syn::parse_quote!(::pyo3::Py<::pyo3::PyAny>),
+        Type::Any => syn::parse_quote!(::pyo3::PyObject),
+        Type::Never => syn::parse_quote!(::std::convert::Infallible),
+        Type::Unknown => syn::parse_quote!(::pyo3::PyObject),
+        Type::ClassObject { name, .. } => {
+            let ty = rust_ident(name);
+            syn::parse_quote!(::py2o2_runtime::ClassObject<#ty>)
+        }
         Type::Tuple { tags } => {
-            let tags: Vec<syn::Type> = tags.iter().map(as_output_type).collect();
+            let tags = tags
+                .iter()
+                .map(as_output_type)
+                .collect::<Result<Vec<_>>>()?;
             syn::parse_quote! { (#(#tags),*) }
         }
         Type::List { .. } => syn::parse_quote!(::pyo3::Py<::pyo3::types::PyList>),
         Type::Dict { .. } => syn::parse_quote!(::pyo3::Py<::pyo3::types::PyDict>),
         Type::UserDefined { name, .. } => {
-            let ty = syn::Ident::new(name, Span::call_site());
+            let ty = rust_ident(name);
             syn::parse_quote!(#ty)
         }
         Type::Union { args } => {
             let n = args.len();
             let enum_ = quote::format_ident!("Enum{}", n);
-            let out: Vec<_> = args.iter().map(as_output_type).collect();
+            let out = args
+                .iter()
+                .map(as_output_type)
+                .collect::<Result<Vec<_>>>()?;
             syn::parse_quote!( ::py2o2_runtime::#enum_ <#(#out),*>)
         }
-        Type::Callable { args, r#return } => {
-            let t = callable_trait(args, r#return);
+        Type::Callable {
+            args,
+            keywords,
+            param_spec,
+            r#return,
+        } => {
+            let t = callable_trait(args, keywords, param_spec, r#return)?;
             syn::parse_quote!(Box<#t>)
         }
+        Type::Annotated { inner, metadata } => match annotated_rust_type(metadata)? {
+            Some(ty) => ty,
+            None => as_output_type(inner)?,
+        },
+    })
+}
+
+/// Generate `MODULE` caching the Python module for the functions
+///
+/// `MODULE.reload(py)` reloads the module. It is not a function of the generated module
+/// so as not to conflict with the Python functions, e.g. `reload`.
+/// If the module is annotated with the markers, `py2o2` is registered before importing it.
+pub fn generate_module_cache(module_name: &str, interface: &Interface) -> TokenStream2 {
+    let constructor = if uses_markers(interface) {
+        quote! { with_markers }
+    } else {
+        quote! { new }
+    };
+    quote! {
+        /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+        pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::#constructor(#module_name);
     }
 }
 
-pub fn generate_function(module_name: &str, f: &Function) -> Result<TokenStream2> {
+/// Whether `Annotated` metadata appears in the interface, i.e. the module imports `py2o2`
+fn uses_markers(interface: &Interface) -> bool {
+    fn has_markers(ty: &Type) -> bool {
+        match ty {
+            Type::Annotated { .. } => true,
+            Type::Tuple { tags: inner }
+            | Type::List { inner }
+            | Type::Dict { inner }
+            | Type::Union { args: inner } => inner.iter().any(has_markers),
+            Type::Callable {
+                args,
+                keywords,
+                r#return,
+                ..
+            } => {
+                args.iter().any(has_markers)
+                    || keywords.iter().any(|p| has_markers(&p.r#type))
+                    || has_markers(r#return)
+            }
+            Type::UserDefined { supertype, .. } => has_markers(supertype),
+            _ => false,
+        }
+    }
+    let functions = interface
+        .functions
+        .values()
+        .flat_map(|f| std::iter::once(f).chain(&f.overloads));
+    let typedefs = interface.type_definitions.values();
+    functions
+        .flat_map(|f| f.parameters.iter().map(|p| &p.r#type).chain([&f.r#return]))
+        .chain(typedefs.map(|t| &t.supertype))
+        .any(has_markers)
+}
+
+/// Check that `{name}_{n}` given to overload variants does not clash with another function
+fn check_overload_names(interface: &Interface) -> Result<()> {
+    for f in interface.functions.values() {
+        for n in 1..=f.overloads.len() {
+            let ident = format!("{}_{}", f.name, n);
+            if interface.functions.contains_key(&ident) {
+                bail!(
+                    "`{}` generated for an overload variant of `{}` conflicts with the function of the same name",
+                    ident,
+                    f.name
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generate a function calling `f`, and a function `{name}_{n}` for the `n`-th overload variant
+///
+/// The Python function is looked up through `MODULE` given by [generate_module_cache].
+pub fn generate_function(f: &Function) -> Result<TokenStream2> {
+    let mut tt = vec![generate_function_as(&f.name, f)?];
+    for (n, overload) in f.overloads.iter().enumerate() {
+        let ident = format!("{}_{}", f.name, n + 1);
+        tt.push(generate_function_as(&ident, overload)?);
+    }
+    Ok(quote! { #(#tt)* })
+}
+
+fn generate_function_as(ident: &str, f: &Function) -> Result<TokenStream2> {
+    if is_skipped(f) {
+        return Ok(quote! {});
+    }
     let name = &f.name;
-    let ident = syn::Ident::new(name, Span::call_site());
-    let param_names: Vec<_> = f
-        .parameters
-        .iter()
-        .map(|p| syn::Ident::new(&p.name, Span::call_site()))
-        .collect();
-    let param_types: Vec<syn::Type> = f
+    let ident = rust_ident(ident);
+    let doc = doc_attrs(&function_doc(f));
+    let param_names: Vec<_> = f.parameters.iter().map(param_ident).collect();
+    let param_types = f
         .parameters
         .iter()
         .map(|p| as_input_type(&p.r#type))
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
     let input_tt = quote!(#(#param_names: #param_types),*);
 
-    let output = as_output_type(&f.r#return);
+    let output = as_output_type(&f.r#return)?;
 
     let call_tt = quote! {
-        py.import(#module_name)?.getattr(#name)?.call((#(#param_names,)*), None)?
+        MODULE.getattr(py, #name)?.call((#(#param_names,)*), None)?
     };
-    let inner_tt = if matches!(&f.r#return, Type::None) {
+    let never = f
+        .parameters
+        .iter()
+        .find(|p| p.r#type.unannotated() == &Type::Never);
+    let inner_tt = if let Some(never) = never {
+        // No value can be passed as `NoReturn`, so the function is never called
+        let never = param_ident(never);
         quote! {
-            let _ = #call_tt;
-            Ok(())
+            let _ = (py, #(#param_names),*);
+            match #never {}
         }
     } else {
-        quote! {
-            let result = #call_tt;
-            Ok(result.extract()?)
+        match f.r#return.unannotated() {
+            Type::None => quote! {
+                let _ = #call_tt;
+                Ok(())
+            },
+            Type::Never => {
+                let msg = format!("{} returned though annotated as NoReturn", name);
+                quote! {
+                    let _ = #call_tt;
+                    Err(::pyo3::exceptions::PyRuntimeError::new_err(#msg))
+                }
+            }
+            _ => quote! {
+                let result = #call_tt;
+                result.extract()
+            },
         }
     };
     let convert_callable: Vec<TokenStream2> = f
         .parameters
         .iter()
-        .flat_map(|p| match &p.r#type {
-            Type::Callable { args, .. } => {
-                let ident = syn::Ident::new(&p.name, Span::call_site());
-                Some(if args.is_empty() {
+        .flat_map(|p| match p.r#type.unannotated() {
+            Type::Callable {
+                args,
+                keywords,
+                param_spec,
+                ..
+            } => {
+                let ident = param_ident(p);
+                Some(if param_spec.is_some() || !keywords.is_empty() {
+                    let n = proc_macro2::Literal::usize_unsuffixed(args.len());
+                    let input = if args.is_empty() {
+                        quote!(())
+                    } else {
+                        quote!(args.get_slice(0, #n).extract()?)
+                    };
+                    let (check, rest) = if param_spec.is_some() {
+                        (quote!(), quote!(args.get_slice(#n, args.len()), kwargs))
+                    } else {
+                        let names: Vec<&String> = keywords.iter().map(|k| &k.name).collect();
+                        (
+                            quote!(::py2o2_runtime::check_arguments(args, kwargs, #n, &[#(#names),*])?;),
+                            quote!((#(::py2o2_runtime::extract_keyword(kwargs, #names)?,)*)),
+                        )
+                    };
+                    quote! {
+                        let #ident = ::py2o2_runtime::as_pycfunc_with_kwargs(
+                            py,
+                            move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                                #check
+                                Ok(#ident(#input, #rest))
+                            },
+                        )?;
+                    }
+                } else if args.is_empty() {
                     quote! {
                         let #ident = ::py2o2_runtime::as_pycfunc(py, move |_input: [usize; 0]| #ident())?;
                     }
@@ -139,6 +466,7 @@ pub fn generate_function(module_name: &str, f: &Function) -> Result<TokenStream2
         .collect();
 
     Ok(quote! {
+        #doc
         pub fn #ident<'py>(py: ::pyo3::Python<'py>, #input_tt) -> ::pyo3::PyResult<#output> {
             #(#convert_callable)*
             #inner_tt
@@ -146,13 +474,363 @@ pub fn generate_function(module_name: &str, f: &Function) -> Result<TokenStream2
     })
 }
 
+/// Owned Rust type taken by the wrapper in [generate_gil_wrappers] instead of one bound to the GIL,
+/// e.g. `Vec<f64>` instead of `&PyList`, or `None` if [as_input_type] does not depend on the GIL
+///
+/// Tuples are owned if any of their elements are, and converted back by [convert_owned_input].
+fn as_owned_input_type(ty: &Type) -> Result<Option<syn::Type>> {
+    Ok(match ty {
+        Type::List { .. } | Type::Dict { .. } => Some(as_owned_collection_type(ty)?),
+        Type::Tuple { tags } => {
+            let owned = tags
+                .iter()
+                .map(as_owned_input_type)
+                .collect::<Result<Vec<_>>>()?;
+            if owned.iter().all(Option::is_none) {
+                return Ok(None);
+            }
+            let tags = tags
+                .iter()
+                .zip(owned)
+                .map(|(ty, owned)| owned.map_or_else(|| as_input_type(ty), Ok))
+                .collect::<Result<Vec<_>>>()?;
+            Some(syn::parse_quote! { (#(#tags),*) })
+        }
+        Type::Annotated { inner, metadata } if annotated_rust_type(metadata)?.is_none() => {
+            as_owned_input_type(inner)?
+        }
+        _ => None,
+    })
+}
+
+/// Owned Rust type of a list or dict taken by the wrapper, which is always owned unlike the other types
+fn as_owned_collection_type(ty: &Type) -> Result<syn::Type> {
+    Ok(match ty {
+        Type::List { inner } => {
+            let inner = match inner.first() {
+                Some(ty) => as_owned_element_type(ty)?,
+                None => syn::parse_quote!(::pyo3::PyObject),
+            };
+            syn::parse_quote!(Vec<#inner>)
+        }
+        Type::Dict { inner } => {
+            let (key, value) = dict_key_value(inner);
+            let (key_ty, value_ty) = (as_owned_element_type(key)?, as_owned_element_type(value)?);
+            match key.unannotated() {
+                Type::Primitive(Primitive::Int | Primitive::Str) => {
+                    syn::parse_quote!(::std::collections::HashMap<#key_ty, #value_ty>)
+                }
+                // Keys such as `float` are not `Hash`
+                _ => syn::parse_quote!(Vec<(#key_ty, #value_ty)>),
+            }
+        }
+        _ => bail!("Not a list or dict: {:?}", ty),
+    })
+}
+
+/// Owned Rust type of an element of a list, dict or tuple taken by the wrapper, which cannot be `impl Trait`
+///
+/// Python objects without a Rust counterpart, e.g. `Any`, are taken as `PyObject`, which does not borrow the GIL.
+fn as_owned_element_type(ty: &Type) -> Result<syn::Type> {
+    Ok(match ty {
+        Type::Primitive(Primitive::Int) => syn::parse_quote!(i64),
+        Type::Primitive(Primitive::Float) => syn::parse_quote!(f64),
+        Type::Primitive(Primitive::Str) => syn::parse_quote!(String),
+        Type::None => syn::parse_quote!(()),
+        Type::UserDefined { .. } => as_input_type(ty)?,
+        Type::List { .. } | Type::Dict { .. } => as_owned_collection_type(ty)?,
+        Type::Tuple { tags } => {
+            let tags = tags
+                .iter()
+                .map(as_owned_element_type)
+                .collect::<Result<Vec<_>>>()?;
+            syn::parse_quote! { (#(#tags),*) }
+        }
+        Type::Annotated { inner, metadata } => match annotated_rust_type(metadata)? {
+            Some(ty) => ty,
+            None => as_owned_element_type(inner)?,
+        },
+        _ => syn::parse_quote!(::pyo3::PyObject),
+    })
+}
+
+/// Key and value types of `dict[K, V]`, or `Any` if not given
+fn dict_key_value(inner: &[Type]) -> (&Type, &Type) {
+    match inner {
+        [key, value] => (key, value),
+        _ => (&Type::Any, &Type::Any),
+    }
+}
+
+/// Expression converting `value` of [as_owned_input_type] into [as_input_type]
+/// in the closure of `Python::with_gil` where `py` is given
+fn convert_owned_input(ty: &Type, value: TokenStream2) -> Result<TokenStream2> {
+    Ok(match ty {
+        Type::List { inner } => {
+            let inner = inner.first().unwrap_or(&Type::Any);
+            if !needs_conversion(inner) {
+                return Ok(quote!(::pyo3::types::PyList::new(py, #value)));
+            }
+            let item = convert_owned_element(inner, quote!(item))?;
+            if contains_dict(inner) {
+                // Building a dict may fail, e.g. for an unhashable key
+                quote! {
+                    ::pyo3::types::PyList::new(
+                        py,
+                        #value
+                            .into_iter()
+                            .map(|item| Ok(#item))
+                            .collect::<::pyo3::PyResult<Vec<_>>>()?,
+                    )
+                }
+            } else {
+                quote!(::pyo3::types::PyList::new(py, #value.into_iter().map(|item| #item)))
+            }
+        }
+        Type::Dict { .. } if !needs_conversion(ty) => {
+            quote!(::pyo3::types::IntoPyDict::into_py_dict(#value, py))
+        }
+        Type::Dict { inner } => {
+            // Also for `Vec<(K, V)>`, so that it reaches Python as a dict
+            let (key, value_ty) = dict_key_value(inner);
+            let key = convert_owned_element(key, quote!(key))?;
+            let item = convert_owned_element(value_ty, quote!(value))?;
+            quote! {{
+                let dict = ::pyo3::types::PyDict::new(py);
+                for (key, value) in #value {
+                    dict.set_item(#key, #item)?;
+                }
+                dict
+            }}
+        }
+        Type::Tuple { tags } => {
+            let names = tuple_names(tags);
+            let mut converted = Vec::new();
+            for (ty, name) in tags.iter().zip(&names) {
+                converted.push(match as_owned_input_type(ty)? {
+                    Some(_) => convert_owned_input(ty, quote!(#name))?,
+                    None => quote!(#name),
+                });
+            }
+            quote! {{
+                let (#(#names),*) = #value;
+                (#(#converted),*)
+            }}
+        }
+        Type::Annotated { inner, .. } => convert_owned_input(inner, value)?,
+        _ => value,
+    })
+}
+
+/// Expression converting `value` of [as_owned_element_type] into a value implementing `ToPyObject`
+fn convert_owned_element(ty: &Type, value: TokenStream2) -> Result<TokenStream2> {
+    if !needs_conversion(ty) {
+        return Ok(value);
+    }
+    Ok(match ty {
+        Type::List { .. } | Type::Dict { .. } => convert_owned_input(ty, value)?,
+        Type::Tuple { tags } => {
+            let names = tuple_names(tags);
+            let converted = tags
+                .iter()
+                .zip(&names)
+                .map(|(ty, name)| convert_owned_element(ty, quote!(#name)))
+                .collect::<Result<Vec<_>>>()?;
+            quote! {{
+                let (#(#names),*) = #value;
+                (#(#converted),*)
+            }}
+        }
+        Type::Annotated { inner, metadata } if annotated_rust_type(metadata)?.is_none() => {
+            convert_owned_element(inner, value)?
+        }
+        _ => quote!(::pyo3::IntoPy::<::pyo3::PyObject>::into_py(#value, py)),
+    })
+}
+
+/// Whether [as_owned_element_type] of the type does not become the Python value by `ToPyObject`,
+/// e.g. `Vec<(f64, i64)>` for `dict[float, int]` or a `NewType`
+fn needs_conversion(ty: &Type) -> bool {
+    match ty {
+        Type::Dict { inner } => {
+            let (key, value) = dict_key_value(inner);
+            !matches!(
+                key.unannotated(),
+                Type::Primitive(Primitive::Int | Primitive::Str)
+            ) || needs_conversion(key)
+                || needs_conversion(value)
+        }
+        Type::List { inner } | Type::Tuple { tags: inner } => inner.iter().any(needs_conversion),
+        Type::UserDefined { .. } => true,
+        Type::Annotated { inner, metadata } => {
+            metadata
+                .iter()
+                .any(|m| matches!(m, Annotation::Rust { .. }))
+                || needs_conversion(inner)
+        }
+        _ => false,
+    }
+}
+
+/// Names binding the elements of a tuple, e.g. `tag0` and `tag1`
+fn tuple_names(tags: &[Type]) -> Vec<syn::Ident> {
+    (0..tags.len())
+        .map(|n| quote::format_ident!("tag{}", n))
+        .collect()
+}
+
+fn contains_dict(ty: &Type) -> bool {
+    match ty {
+        Type::Dict { .. } => true,
+        Type::List { inner } | Type::Tuple { tags: inner } => inner.iter().any(contains_dict),
+        Type::Annotated { inner, .. } => contains_dict(inner),
+        _ => false,
+    }
+}
+
+/// Owned Rust type returned by the wrapper in [generate_gil_wrappers],
+/// e.g. `String` instead of `Py<PyString>`, or `None` if [as_output_type] is used as it is
+fn as_owned_output_type(ty: &Type) -> Result<Option<syn::Type>> {
+    let owned = |ty: &Type| -> Result<syn::Type> {
+        match as_owned_output_type(ty)? {
+            Some(ty) => Ok(ty),
+            None => as_output_type(ty),
+        }
+    };
+    Ok(match ty {
+        Type::Primitive(Primitive::Str) => Some(syn::parse_quote!(String)),
+        Type::List { inner } => {
+            let inner = match inner.first() {
+                Some(ty) => owned(ty)?,
+                None => syn::parse_quote!(::pyo3::PyObject),
+            };
+            Some(syn::parse_quote!(Vec<#inner>))
+        }
+        Type::Dict { inner } => match &inner[..] {
+            [key, value]
+                if matches!(
+                    key.unannotated(),
+                    Type::Primitive(Primitive::Int | Primitive::Str)
+                ) =>
+            {
+                let (key, value) = (owned(key)?, owned(value)?);
+                Some(syn::parse_quote!(::std::collections::HashMap<#key, #value>))
+            }
+            _ => None,
+        },
+        Type::Tuple { tags } => {
+            let mut changed = false;
+            for ty in tags {
+                changed |= as_owned_output_type(ty)?.is_some();
+            }
+            if changed {
+                let tags = tags.iter().map(owned).collect::<Result<Vec<_>>>()?;
+                Some(syn::parse_quote! { (#(#tags),*) })
+            } else {
+                None
+            }
+        }
+        Type::Annotated { inner, metadata } if annotated_rust_type(metadata)?.is_none() => {
+            as_owned_output_type(inner)?
+        }
+        _ => None,
+    })
+}
+
+/// Generate `gil` module of wrappers acquiring the GIL by themselves, which take and return owned Rust values
+///
+/// Each wrapper calls the function of the same name in the parent module generated by [generate_function].
+/// Lists, dicts and tuples are converted recursively, and Python objects without a Rust counterpart,
+/// e.g. `Any`, are taken and returned as `PyObject`, which does not borrow the GIL.
+pub fn generate_gil_wrappers(interface: &Interface) -> Result<TokenStream2> {
+    let mut wrappers = Vec::new();
+    for f in interface.functions.values() {
+        wrappers.push(generate_gil_wrapper_as(&f.name, f)?);
+        for (n, overload) in f.overloads.iter().enumerate() {
+            let ident = format!("{}_{}", f.name, n + 1);
+            wrappers.push(generate_gil_wrapper_as(&ident, overload)?);
+        }
+    }
+    Ok(quote! {
+        /// Functions acquiring the GIL by themselves, which take and return owned Rust values
+        pub mod gil {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#wrappers)*
+        }
+    })
+}
+
+fn generate_gil_wrapper_as(ident: &str, f: &Function) -> Result<TokenStream2> {
+    if is_skipped(f) {
+        return Ok(quote! {});
+    }
+    let ident = rust_ident(ident);
+    let doc = format!(
+        " Same as [`super::{}`], but acquires the GIL and returns an owned value",
+        ident
+    );
+    let mut param_names = Vec::new();
+    let mut param_types = Vec::new();
+    let mut convert_inputs = Vec::new();
+    for p in &f.parameters {
+        let name = param_ident(p);
+        match as_owned_input_type(&p.r#type)? {
+            Some(ty) => {
+                param_types.push(ty);
+                let value = convert_owned_input(&p.r#type, quote!(#name))?;
+                convert_inputs.push(quote! {
+                    let #name = #value;
+                });
+            }
+            None => param_types.push(as_input_type(&p.r#type)?),
+        }
+        param_names.push(name);
+    }
+    let call_tt = quote!(super::#ident(py, #(#param_names),*));
+    let (output, inner_tt) = match as_owned_output_type(&f.r#return)? {
+        Some(output) => (
+            output,
+            quote! {
+                let result = #call_tt?;
+                ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+            },
+        ),
+        None if param_names.is_empty() => {
+            let output = as_output_type(&f.r#return)?;
+            return Ok(quote! {
+                #[doc = #doc]
+                pub fn #ident() -> ::pyo3::PyResult<#output> {
+                    ::pyo3::Python::with_gil(super::#ident)
+                }
+            });
+        }
+        None => (as_output_type(&f.r#return)?, call_tt),
+    };
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn #ident(#(#param_names: #param_types),*) -> ::pyo3::PyResult<#output> {
+            ::pyo3::Python::with_gil(|py| {
+                #(#convert_inputs)*
+                #inner_tt
+            })
+        }
+    })
+}
+
 pub fn generate_type_definitions(typedef: &TypeDefinition) -> Result<TokenStream2> {
     let TypeDefinition {
-        name, supertype, ..
+        name,
+        supertype,
+        doc,
+        ..
     } = typedef;
-    let inner = as_output_type(supertype);
-    let name = syn::Ident::new(name, Span::call_site());
+    let inner = as_output_type(supertype)?;
+    let name = rust_ident(name);
+    let doc = doc_attrs(doc.as_deref().unwrap_or_default());
     Ok(quote! {
+        #doc
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct #name(pub #inner);
 
@@ -164,20 +842,104 @@ pub fn generate_type_definitions(typedef: &TypeDefinition) -> Result<TokenStream
     })
 }
 
+fn collect_class_objects<'t>(ty: &'t Type, classes: &mut BTreeSet<(&'t str, &'t str)>) {
+    match ty {
+        Type::ClassObject { module, name } => {
+            classes.insert((module, name));
+        }
+        Type::Tuple { tags: inner }
+        | Type::List { inner }
+        | Type::Dict { inner }
+        | Type::Union { args: inner } => {
+            for ty in inner {
+                collect_class_objects(ty, classes);
+            }
+        }
+        Type::Callable {
+            args,
+            keywords,
+            r#return,
+            ..
+        } => {
+            for ty in args.iter().chain(keywords.iter().map(|p| &p.r#type)) {
+                collect_class_objects(ty, classes);
+            }
+            collect_class_objects(r#return, classes);
+        }
+        Type::UserDefined { supertype, .. } => collect_class_objects(supertype, classes),
+        Type::Annotated { inner, .. } => collect_class_objects(inner, classes),
+        _ => {}
+    }
+}
+
+/// Generate marker types of Python classes used in `type[T]`
+pub fn generate_class_markers(interface: &Interface) -> Result<TokenStream2> {
+    let mut classes = BTreeSet::new();
+    let functions = interface
+        .functions
+        .values()
+        .flat_map(|f| std::iter::once(f).chain(&f.overloads))
+        .filter(|f| !is_skipped(f));
+    for f in functions {
+        for p in &f.parameters {
+            collect_class_objects(&p.r#type, &mut classes);
+        }
+        collect_class_objects(&f.r#return, &mut classes);
+    }
+    // Markers are named after the class, so classes of the same name from different modules cannot coexist
+    let mut idents: BTreeMap<String, &str> = BTreeMap::new();
+    for (module, name) in &classes {
+        if let Some(other) = idents.insert(rust_ident(name).to_string(), module) {
+            bail!(
+                "Classes `{}.{}` and `{}.{}` are both used in `type[T]` but cannot share the marker `{}`",
+                other,
+                name,
+                module,
+                name,
+                name
+            );
+        }
+    }
+    let markers = classes.into_iter().map(|(module, name)| {
+        let ident = rust_ident(name);
+        let doc = format!(" Marker of Python class `{}.{}`", module, name);
+        let path = module.split('.');
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug)]
+            pub struct #ident;
+
+            impl ::py2o2_runtime::PyTypeInfoUser for #ident {
+                const NAME: &'static str = #name;
+                const MODULE: &'static [&'static str] = &[#(#path),*];
+                fn type_object(py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<&::pyo3::types::PyType> {
+                    py.import(#module)?.getattr(#name)?.extract()
+                }
+            }
+        }
+    });
+    Ok(quote! { #(#markers)* })
+}
+
 pub fn generate_union_traits(interface: &Interface) -> Result<TokenStream2> {
     let mut traits: BTreeMap<syn::Ident, TokenStream2> = BTreeMap::new();
-    for f in interface.functions.values() {
+    let functions = interface
+        .functions
+        .values()
+        .flat_map(|f| std::iter::once(f).chain(&f.overloads))
+        .filter(|f| !is_skipped(f));
+    for f in functions {
         for p in &f.parameters {
-            match &p.r#type {
+            match p.r#type.unannotated() {
                 Type::Union { args } => {
                     let trait_ident = union_trait_ident(args);
-                    let args: Vec<_> = args
+                    let args = args
                         .iter()
                         .map(|ty| match ty {
                             Type::Primitive(_) => as_input_type(ty),
                             _ => unimplemented!(),
                         })
-                        .collect();
+                        .collect::<Result<Vec<_>>>()?;
                     traits.entry(trait_ident.clone()).or_insert(quote! {
                         pub trait #trait_ident: ::pyo3::conversion::IntoPy<::pyo3::PyObject> {}
                         #(
@@ -193,12 +955,34 @@ pub fn generate_union_traits(interface: &Interface) -> Result<TokenStream2> {
     Ok(quote! { #(#traits)* })
 }
 
+/// Options of code generation, where the default is the same as [generate] without `bare`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodegenOptions {
+    /// Generate Rust code without creating modules
+    pub bare: bool,
+    /// Also generate `gil` module of wrappers acquiring the GIL by themselves, see [generate_gil_wrappers]
+    pub gil: bool,
+}
+
 pub fn generate(module_name: &str, interface: &Interface, bare: bool) -> Result<String> {
+    let options = CodegenOptions {
+        bare,
+        ..Default::default()
+    };
+    generate_with_options(module_name, interface, &options)
+}
+
+pub fn generate_with_options(
+    module_name: &str,
+    interface: &Interface,
+    options: &CodegenOptions,
+) -> Result<String> {
+    check_overload_names(interface)?;
     let mut tt = Vec::new();
     let f_tt = interface
         .functions
         .values()
-        .map(|f| generate_function(module_name, f))
+        .map(generate_function)
         .collect::<Result<Vec<_>>>()?;
     let typedef_tt = interface
         .type_definitions
@@ -206,20 +990,33 @@ pub fn generate(module_name: &str, interface: &Interface, bare: bool) -> Result<
         .map(generate_type_definitions)
         .collect::<Result<Vec<_>>>()?;
     let union_traits = generate_union_traits(interface)?;
-    if !bare {
-        let module_ident = syn::Ident::new(module_name, Span::call_site());
+    let class_markers = generate_class_markers(interface)?;
+    let module_cache = generate_module_cache(module_name, interface);
+    let gil_wrappers = if options.gil {
+        generate_gil_wrappers(interface)?
+    } else {
+        quote! {}
+    };
+    if !options.bare {
+        let module_ident = rust_ident(module_name);
         tt.push(quote! {
             pub mod #module_ident {
+                #module_cache
                 #(#typedef_tt)*
+                #class_markers
                 #union_traits
                 #(#f_tt)*
+                #gil_wrappers
             }
         })
     } else {
         tt.push(quote! {
+            #module_cache
             #(#typedef_tt)*
+            #class_markers
             #union_traits
             #(#f_tt)*
+            #gil_wrappers
         })
     }
     Ok(format(quote! { #(#tt)* }))
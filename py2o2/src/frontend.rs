@@ -0,0 +1,1461 @@
+//! Pure-Rust frontend building [Interface] from `.py` and `.pyi` source files
+//!
+//! Unlike [Interface::from_py_module], the module is parsed by [crate::parser]
+//! without importing it, so neither a Python interpreter nor the dependencies
+//! of the module are required, e.g. in build scripts.
+//! Names in annotations are resolved through the imports, assignments and classes
+//! at the top level of the module.
+//! Legacy `# type:` comments are read as annotations, and `sys.version_info` and `sys.platform` guards
+//! are evaluated against [InspectOptions::python_version] and [InspectOptions::platform].
+//!
+//! [Interface::from_py_ast] reads the same information from the syntax tree
+//! given by Python's `ast` module instead, see [crate::ast].
+//!
+
+use crate::{
+    ast,
+    inspect::*,
+    parser::{
+        self, BoolOperator, ClassDef, CmpOp, Constant, Diagnostic, Expr, FunctionDef, FunctionType,
+        Input, Operator, Span, Stmt, UnaryOperator,
+    },
+};
+use anyhow::{anyhow, bail, Context, Result};
+use nom::{combinator::all_consuming, Finish, Parser};
+use pyo3::Python;
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+impl Interface {
+    /// Build the interface from the source of the module named `module_name`
+    ///
+    /// Stubs in [InspectOptions::stub_paths] override the signatures in the source.
+    pub fn from_source(module_name: &str, source: &str, options: &InspectOptions) -> Result<Self> {
+        let stub = find_stub(module_name, None, &options.stub_paths);
+        build(
+            module_name,
+            Some((module_name, source)),
+            stub.as_deref(),
+            options,
+        )
+    }
+
+    /// Build the interface from a `.py` file, a package directory or a `.pyi` stub
+    ///
+    /// Stubs are searched in [InspectOptions::stub_paths] and then next to the module.
+    pub fn from_source_path(path: &Path, options: &InspectOptions) -> Result<Self> {
+        let module_name = module_name(path)?;
+        let path = if path.is_dir() {
+            let init = path.join("__init__.py");
+            if init.exists() {
+                init
+            } else {
+                path.join("__init__.pyi")
+            }
+        } else {
+            path.to_owned()
+        };
+        if path.extension().is_some_and(|ext| ext == "pyi") {
+            return build(&module_name, None, Some(&path), options);
+        }
+        let source =
+            fs::read_to_string(&path).with_context(|| format!("Cannot read {}", path.display()))?;
+        let stub = find_stub(&module_name, Some(&path), &options.stub_paths);
+        build(
+            &module_name,
+            Some((&path.display().to_string(), &source)),
+            stub.as_deref(),
+            options,
+        )
+    }
+
+    /// Build the interface declared by the `.pyi` stub of the module named `module_name`
+    ///
+    /// Unlike [Interface::from_source_path], functions whose annotations are not supported,
+    /// e.g. protocols in `_typeshed`, are skipped with a warning instead of failing the whole stub,
+    /// so that the caller can inspect them otherwise. Used for stubs of imported modules in [get_inspect_json].
+    pub fn from_stub(module_name: &str, path: &Path, options: &InspectOptions) -> Result<Self> {
+        let source =
+            fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+        let stmts = parse_module(&path.display().to_string(), &source)?;
+        let stmts = top_level(&stmts, &Target::new(options));
+        Scope::new(module_name, &stmts).interface(&stmts, Source::Stub, options, true)
+    }
+
+    /// Build the interface from the syntax tree given by Python's `ast` module
+    ///
+    /// The module is located on `sys.path` but neither it nor its parent packages are executed,
+    /// so side effects at import time do not run.
+    /// Stubs are searched in [InspectOptions::stub_paths] and then next to the module.
+    pub fn from_py_ast(target: &str, options: &InspectOptions) -> Result<Self> {
+        Python::with_gil(|py| {
+            // `importlib.util.find_spec` imports the parent packages of `pkg.sub`,
+            // so each package is located by `PathFinder` in the search path of its parent instead
+            let finder = py
+                .import("importlib.machinery")?
+                .getattr("PathFinder")?
+                .getattr("find_spec")?;
+            let mut spec = py.None().into_ref(py);
+            let mut name = String::new();
+            for part in target.split('.') {
+                let path = if name.is_empty() {
+                    py.None().into_ref(py)
+                } else {
+                    let path = spec.getattr("submodule_search_locations")?;
+                    if path.is_none() {
+                        bail!("`{}` is not a package", name);
+                    }
+                    path
+                };
+                if !name.is_empty() {
+                    name.push('.');
+                }
+                name.push_str(part);
+                spec = finder.call1((&name, path))?;
+                if spec.is_none() {
+                    bail!("Module `{}` is not found", name);
+                }
+            }
+            // `origin` is e.g. `built-in` for builtin modules, which has no file
+            let origin: Option<PathBuf> = spec.getattr("origin")?.extract()?;
+            let origin = origin.filter(|path| path.is_file());
+            let stub = find_stub(target, origin.as_deref(), &options.stub_paths);
+            let origin = origin.filter(|path| path.extension().is_some_and(|ext| ext == "py"));
+            if origin.is_none() && stub.is_none() {
+                bail!("Neither source nor stub of `{}` is found", target);
+            }
+
+            let parse = |path: &Path| -> Result<Vec<Stmt>> {
+                let source = fs::read_to_string(path)
+                    .with_context(|| format!("Cannot read {}", path.display()))?;
+                Ok(ast::parse(py, &source)?.to_stmts()?)
+            };
+            let stmts = origin.as_deref().map(parse).transpose()?;
+            let stub_stmts = stub.as_deref().map(parse).transpose()?;
+            merge(target, stmts.as_deref(), stub_stmts.as_deref(), options)
+        })
+    }
+}
+
+/// Module name of a `.py` or `.pyi` file, or of a package directory
+pub fn module_name(path: &Path) -> Result<String> {
+    let path = if path.file_stem().is_some_and(|stem| stem == "__init__") {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+    let name = if path.is_dir() {
+        path.file_name()
+    } else {
+        path.file_stem()
+    };
+    Ok(name
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("Cannot get module name of {}", path.display()))?
+        .to_string())
+}
+
+/// First `.pyi` stub found for the module in `stub_paths` and then next to `module_file`
+///
+/// Also given to `inspect_module.py` through `parse_stub` by [get_inspect_json_with_options].
+pub(crate) fn find_stub(
+    module_name: &str,
+    module_file: Option<&Path>,
+    stub_paths: &[PathBuf],
+) -> Option<PathBuf> {
+    let mut candidates = stub_candidates(module_name, stub_paths);
+    if let Some(path) = module_file {
+        // e.g. `foo.cpython-311-x86_64-linux-gnu.so` or `__init__.py`
+        let name = path.file_name()?.to_str()?;
+        let stem = name.split('.').next().unwrap_or(name);
+        candidates.push(path.with_file_name(format!("{}.pyi", stem)));
+    }
+    candidates.into_iter().find(|path| path.is_file())
+}
+
+/// Paths in `stub_paths` where a `.pyi` stub of the module may be placed, in priority order
+///
+/// Each directory is searched like a typeshed checkout or a `MYPYPATH` entry,
+/// i.e. `foo/bar.pyi` or `foo/bar/__init__.pyi` for `foo.bar`,
+/// also in the PEP 561 stub-only package `foo-stubs`.
+fn stub_candidates(module_name: &str, stub_paths: &[PathBuf]) -> Vec<PathBuf> {
+    let parts: Vec<&str> = module_name.split('.').collect();
+    let mut candidates = Vec::new();
+    for root in stub_paths {
+        let stubs = format!("{}-stubs", parts[0]);
+        for top in [parts[0], &stubs] {
+            let base = parts[1..]
+                .iter()
+                .fold(root.join(top), |base, p| base.join(p));
+            candidates.push(base.with_extension("pyi"));
+            candidates.push(base.join("__init__.pyi"));
+        }
+        // typeshed layout, i.e. `stdlib/foo/bar.pyi` and `stubs/<distribution>/foo/bar.pyi`
+        let mut distributions: Vec<PathBuf> = fs::read_dir(root.join("stubs"))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.is_dir())
+            .collect();
+        distributions.sort();
+        for top in std::iter::once(root.join("stdlib")).chain(distributions) {
+            let base = parts.iter().fold(top, |base, p| base.join(p));
+            candidates.push(base.with_extension("pyi"));
+            candidates.push(base.join("__init__.pyi"));
+        }
+    }
+    candidates
+}
+
+/// Parse the module, where `path` is shown in the diagnostic on a syntax error
+fn parse_module<'input>(path: &str, source: &'input str) -> Result<Vec<Stmt<'input>>> {
+    let (_, stmts) = parser::module(Input::new(source))
+        .finish()
+        .map_err(|e| Diagnostic::new(path, &e))?;
+    Ok(stmts)
+}
+
+/// Interface of the module from `source`, given with the path shown in its diagnostics, and `stub`
+fn build(
+    module_name: &str,
+    source: Option<(&str, &str)>,
+    stub: Option<&Path>,
+    options: &InspectOptions,
+) -> Result<Interface> {
+    let stmts = source
+        .map(|(path, source)| parse_module(path, source))
+        .transpose()?;
+    let stub_source = stub
+        .map(|stub| {
+            fs::read_to_string(stub).with_context(|| format!("Cannot read {}", stub.display()))
+        })
+        .transpose()?;
+    let stub_stmts = stub_source
+        .as_deref()
+        .zip(stub)
+        .map(|(source, stub)| parse_module(&stub.display().to_string(), source))
+        .transpose()?;
+    merge(
+        module_name,
+        stmts.as_deref(),
+        stub_stmts.as_deref(),
+        options,
+    )
+}
+
+/// Interface of the module overridden by its stub
+fn merge(
+    module_name: &str,
+    stmts: Option<&[Stmt]>,
+    stub_stmts: Option<&[Stmt]>,
+    options: &InspectOptions,
+) -> Result<Interface> {
+    let target = Target::new(options);
+    let stmts = stmts.map(|stmts| top_level(stmts, &target));
+    let stmts = stmts.as_deref();
+    let stub_stmts = stub_stmts.map(|stmts| top_level(stmts, &target));
+    let stub_stmts = stub_stmts.as_deref();
+    let mut interface = Interface {
+        functions: BTreeMap::new(),
+        type_definitions: BTreeMap::new(),
+        without_signature: Vec::new(),
+    };
+    let scope = stmts.map(|stmts| Scope::new(module_name, stmts));
+    if let (Some(scope), Some(stmts)) = (&scope, stmts) {
+        interface = scope.interface(stmts, Source::Static, options, false)?;
+    }
+
+    let Some(stub_stmts) = stub_stmts else {
+        return Ok(interface);
+    };
+    let stub_interface =
+        Scope::new(module_name, stub_stmts).interface(stub_stmts, Source::Stub, options, false)?;
+    for (name, mut f) in stub_interface.functions {
+        if let Some(runtime) = interface.functions.get(&name) {
+            f.doc = f.doc.or_else(|| runtime.doc.clone());
+        } else if scope
+            .as_ref()
+            .is_some_and(|scope| !scope.bound.contains(name.as_str()))
+        {
+            // Declared in the stub but does not exist in the module
+            continue;
+        }
+        interface.functions.insert(name, f);
+    }
+    interface
+        .type_definitions
+        .extend(stub_interface.type_definitions);
+    Ok(interface)
+}
+
+/// Statements at the top level of a module, where the branches of `if TYPE_CHECKING:`
+/// and of version and platform guards taken for the target are inlined
+fn top_level<'input>(stmts: &[Stmt<'input>], target: &Target) -> Vec<Stmt<'input>> {
+    let mut inlined = Vec::new();
+    for stmt in stmts {
+        match stmt {
+            Stmt::If { test, body, orelse } => match target.evaluate(test) {
+                Some(true) => inlined.extend(top_level(body, target)),
+                Some(false) => inlined.extend(top_level(orelse, target)),
+                None => inlined.push(stmt.clone()),
+            },
+            _ => inlined.push(stmt.clone()),
+        }
+    }
+    inlined
+}
+
+/// Python version and platform which guards in the module are evaluated against
+struct Target<'a> {
+    version: (u8, u8),
+    platform: &'a str,
+}
+
+impl<'a> Target<'a> {
+    fn new(options: &'a InspectOptions) -> Self {
+        let host = match std::env::consts::OS {
+            "windows" => "win32",
+            "macos" => "darwin",
+            os => os,
+        };
+        Target {
+            version: options.python_version.unwrap_or(LATEST_PYTHON_VERSION),
+            platform: options.platform.as_deref().unwrap_or(host),
+        }
+    }
+
+    /// Value of the condition of `if`, or `None` if it depends on other than the target
+    ///
+    /// Supported are the forms in typeshed, e.g. `sys.version_info >= (3, 10)`,
+    /// `sys.platform == "win32"` and `sys.platform.startswith("linux")`, combined by `not`, `and` and `or`.
+    fn evaluate(&self, test: &Expr) -> Option<bool> {
+        match test {
+            _ if is_type_checking(test) => Some(true),
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                operand,
+                ..
+            } => self.evaluate(operand).map(|value| !value),
+            Expr::BoolOp { op, values, .. } => {
+                // `and` is false if any is false even when others are unknown, and vice versa for `or`
+                let short = *op == BoolOperator::Or;
+                let values: Vec<_> = values.iter().map(|value| self.evaluate(value)).collect();
+                if values.contains(&Some(short)) {
+                    Some(short)
+                } else if values.iter().all(Option::is_some) {
+                    Some(!short)
+                } else {
+                    None
+                }
+            }
+            Expr::Compare {
+                left,
+                ops,
+                comparators,
+                ..
+            } => {
+                let ([op], [right]) = (&ops[..], &comparators[..]) else {
+                    return None;
+                };
+                let ordering = if is_sys(left, "version_info") {
+                    let Expr::Tuple { elts, .. } = right else {
+                        return None;
+                    };
+                    let version = elts
+                        .iter()
+                        .map(|elt| match elt {
+                            Expr::Constant {
+                                value: Constant::Int(n),
+                                ..
+                            } => Some(*n),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    self.compare_version(&version)?
+                } else if is_sys(left, "platform") {
+                    let Expr::Constant {
+                        value: Constant::String(platform),
+                        ..
+                    } = right
+                    else {
+                        return None;
+                    };
+                    self.platform.cmp(platform.as_ref())
+                } else {
+                    return None;
+                };
+                match op {
+                    CmpOp::Eq => Some(ordering.is_eq()),
+                    CmpOp::NotEq => Some(ordering.is_ne()),
+                    CmpOp::Lt => Some(ordering.is_lt()),
+                    CmpOp::LtE => Some(ordering.is_le()),
+                    CmpOp::Gt => Some(ordering.is_gt()),
+                    CmpOp::GtE => Some(ordering.is_ge()),
+                    _ => None,
+                }
+            }
+            // `sys.platform.startswith("linux")`
+            Expr::Call {
+                func,
+                args,
+                keywords,
+                ..
+            } if keywords.is_empty() => match (func.as_ref(), &args[..]) {
+                (
+                    Expr::Attribute { value, attr, .. },
+                    [Expr::Constant {
+                        value: Constant::String(prefix),
+                        ..
+                    }],
+                ) if attr == "startswith" && is_sys(value, "platform") => {
+                    Some(self.platform.starts_with(prefix.as_ref()))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `sys.version_info` of the target compared with a tuple like `(3, 10)`
+    fn compare_version(&self, version: &[i64]) -> Option<Ordering> {
+        let target = [self.version.0 as i64, self.version.1 as i64];
+        for (target, version) in target.iter().zip(version) {
+            if target != version {
+                return Some(target.cmp(version));
+            }
+        }
+        // `sys.version_info` also has the micro version, which is not known
+        (version.len() <= target.len()).then_some(Ordering::Greater)
+    }
+}
+
+/// `sys.<attr>`, e.g. `sys.version_info`
+fn is_sys(e: &Expr, attr: &str) -> bool {
+    matches!(e, Expr::Attribute { value, attr: a, .. }
+        if a == attr && matches!(value.as_ref(), Expr::Name { id, .. } if id == "sys"))
+}
+
+/// `TYPE_CHECKING` or `typing.TYPE_CHECKING`
+fn is_type_checking(e: &Expr) -> bool {
+    match e {
+        Expr::Name { id, .. } => id == "TYPE_CHECKING",
+        Expr::Attribute { value, attr, .. } => {
+            attr == "TYPE_CHECKING"
+                && matches!(value.as_ref(), Expr::Name { id, .. } if id == "typing")
+        }
+        _ => false,
+    }
+}
+
+/// Annotation given as a string, e.g. a forward reference or a type comment
+fn parse_annotation(s: &str) -> Result<Expr<'_>> {
+    let (_, e) = all_consuming(parser::expr)
+        .parse(Input::new(s.trim()))
+        .finish()
+        .map_err(|_| anyhow!("Cannot parse annotation `{}`", s))?;
+    Ok(e)
+}
+
+/// Signature in a type comment, e.g. `(int, str) -> bool`
+fn parse_func_type(s: &str) -> Result<FunctionType<'_>> {
+    let (_, func_type) = all_consuming(parser::func_type)
+        .parse(Input::new(s.trim()))
+        .finish()
+        .map_err(|_| anyhow!("Cannot parse type comment `{}`", s))?;
+    Ok(func_type)
+}
+
+/// Names defined at the top level of a module
+struct Scope<'a> {
+    module: &'a str,
+    /// Qualified names bound by imports, e.g. `Any` to `typing.Any` for `from typing import Any`
+    imports: HashMap<&'a str, String>,
+    /// Values of assignments, e.g. type aliases or `NewType`
+    assigns: HashMap<&'a str, &'a Expr<'a>>,
+    classes: HashMap<&'a str, &'a ClassDef<'a>>,
+    /// All names bound in the module including functions
+    bound: HashSet<&'a str>,
+    /// Type aliases being resolved, to reject recursive ones, e.g. `Json = list["Json"]`
+    resolving: RefCell<HashSet<&'a str>>,
+}
+
+const BUILTINS: &[&str] = &[
+    "int",
+    "str",
+    "float",
+    "bool",
+    "bytes",
+    "complex",
+    "object",
+    "type",
+    "tuple",
+    "list",
+    "dict",
+    "set",
+    "frozenset",
+    "Exception",
+];
+
+impl<'a> Scope<'a> {
+    fn new(module: &'a str, stmts: &'a [Stmt<'a>]) -> Self {
+        let mut scope = Scope {
+            module,
+            imports: HashMap::new(),
+            assigns: HashMap::new(),
+            classes: HashMap::new(),
+            bound: HashSet::new(),
+            resolving: RefCell::new(HashSet::new()),
+        };
+        for stmt in stmts {
+            match stmt {
+                Stmt::Import { names } => {
+                    for alias in names {
+                        if let Some(asname) = alias.asname.as_deref() {
+                            scope.imports.insert(asname, alias.name.to_string());
+                        } else {
+                            // `import a.b` binds `a`
+                            let top = alias.name.split('.').next().unwrap();
+                            scope.imports.insert(top, top.to_string());
+                        }
+                    }
+                }
+                Stmt::ImportFrom {
+                    module,
+                    names,
+                    level,
+                } => {
+                    let module = format!(
+                        "{}{}",
+                        ".".repeat(*level),
+                        module.as_deref().unwrap_or_default()
+                    );
+                    for alias in names {
+                        if alias.name == "*" {
+                            continue;
+                        }
+                        let name = alias.asname.as_deref().unwrap_or(&alias.name);
+                        scope
+                            .imports
+                            .insert(name, format!("{}.{}", module, alias.name));
+                    }
+                }
+                Stmt::Assign { targets, value } => {
+                    for target in targets {
+                        if let Expr::Name { id, .. } = target {
+                            scope.assigns.insert(id.as_ref(), value);
+                        }
+                    }
+                }
+                Stmt::AnnAssign {
+                    target: Expr::Name { id, .. },
+                    value: Some(value),
+                    ..
+                } => {
+                    scope.assigns.insert(id.as_ref(), value);
+                }
+                Stmt::ClassDef(class) => {
+                    scope.classes.insert(&class.name, class);
+                }
+                _ => {}
+            }
+        }
+        scope.bound.extend(scope.imports.keys());
+        scope.bound.extend(scope.assigns.keys());
+        scope.bound.extend(scope.classes.keys());
+        for stmt in stmts {
+            if let Stmt::FunctionDef(f) | Stmt::AsyncFunctionDef(f) = stmt {
+                scope.bound.insert(&f.name);
+            }
+        }
+        scope
+    }
+
+    /// Qualified name, e.g. `typing.Any` for `Any` imported by `from typing import Any`
+    fn qualname(&self, e: &Expr) -> Option<String> {
+        match e {
+            Expr::Name { id, .. } => {
+                let id = id.as_ref();
+                if let Some(name) = self.imports.get(id) {
+                    return Some(name.clone());
+                }
+                if self.assigns.contains_key(id) || self.classes.contains_key(id) {
+                    return Some(format!("{}.{}", self.module, id));
+                }
+                if BUILTINS.contains(&id) {
+                    return Some(format!("builtins.{}", id));
+                }
+                None
+            }
+            Expr::Attribute { value, attr, .. } => {
+                Some(format!("{}.{}", self.qualname(value)?, attr))
+            }
+            _ => None,
+        }
+    }
+
+    /// Functions and `NewType` declared in the statements
+    ///
+    /// If `skip_invalid` is set, items with unsupported annotations are skipped with a warning.
+    fn interface(
+        &self,
+        stmts: &[Stmt],
+        source: Source,
+        options: &InspectOptions,
+        skip_invalid: bool,
+    ) -> Result<Interface> {
+        let mut functions = BTreeMap::new();
+        let mut overloads: HashMap<&str, Vec<Function>> = HashMap::new();
+        let mut skipped = HashSet::new();
+        let mut type_definitions = BTreeMap::new();
+        let mut docs = HashMap::new();
+        let skip = |name: &str, e: anyhow::Error| -> Result<()> {
+            if !skip_invalid {
+                return Err(e);
+            }
+            eprintln!("warning: `{}.{}` is skipped: {:#}", self.module, name, e);
+            Ok(())
+        };
+        for stmt in stmts {
+            match stmt {
+                Stmt::FunctionDef(f) | Stmt::AsyncFunctionDef(f) => {
+                    if f.decorators.iter().any(|d| self.is_overload(d)) {
+                        match self.function(f, Vec::new(), source, options) {
+                            Ok(overload) => overloads.entry(&f.name).or_default().push(overload),
+                            Err(e) if !skipped.contains(f.name.as_ref()) => {
+                                skip(&f.name, e)?;
+                                skipped.insert(f.name.as_ref());
+                            }
+                            Err(_) => {}
+                        }
+                        continue;
+                    }
+                    let variants = overloads.remove(f.name.as_ref()).unwrap_or_default();
+                    if skipped.contains(f.name.as_ref()) {
+                        continue;
+                    }
+                    match self.function(f, variants, source, options) {
+                        Ok(function) => {
+                            functions.insert(f.name.to_string(), function);
+                        }
+                        Err(e) => skip(&f.name, e)?,
+                    }
+                }
+                Stmt::Assign { targets, value } => {
+                    for target in targets {
+                        match target {
+                            Expr::Name { id, .. } => {
+                                let new_type = match self.new_type(value) {
+                                    Ok(new_type) => new_type,
+                                    Err(e) => {
+                                        skip(id, e)?;
+                                        None
+                                    }
+                                };
+                                if let Some(Type::UserDefined {
+                                    module,
+                                    name,
+                                    supertype,
+                                }) = new_type
+                                {
+                                    let typedef = TypeDefinition {
+                                        name,
+                                        module,
+                                        supertype: *supertype,
+                                        doc: None,
+                                        source,
+                                    };
+                                    type_definitions.insert(id.to_string(), typedef);
+                                }
+                            }
+                            // `Pixel.__doc__ = "..."`
+                            Expr::Attribute {
+                                value: target,
+                                attr,
+                                ..
+                            } if *attr == "__doc__" => {
+                                if let (
+                                    Expr::Name { id, .. },
+                                    Expr::Constant {
+                                        value: Constant::String(doc),
+                                        ..
+                                    },
+                                ) = (target.as_ref(), value)
+                                {
+                                    docs.insert(id.as_ref(), cleandoc(doc));
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        // Only overloads are declared, e.g. in stubs
+        for (name, mut variants) in overloads {
+            if skipped.contains(name) {
+                continue;
+            }
+            let mut function = variants.last().unwrap().clone();
+            variants.iter_mut().for_each(|f| f.overloads.clear());
+            function.overloads = variants;
+            functions.insert(name.to_string(), function);
+        }
+        for (name, typedef) in type_definitions.iter_mut() {
+            typedef.doc = docs.remove(name.as_str());
+        }
+        Ok(Interface {
+            functions,
+            type_definitions,
+            without_signature: Vec::new(),
+        })
+    }
+
+    fn is_overload(&self, decorator: &Expr) -> bool {
+        matches!(
+            self.qualname(decorator).as_deref(),
+            Some("typing.overload" | "typing_extensions.overload")
+        )
+    }
+
+    fn function(
+        &self,
+        f: &FunctionDef,
+        overloads: Vec<Function>,
+        source: Source,
+        options: &InspectOptions,
+    ) -> Result<Function> {
+        let doc = docstring(&f.body);
+        let sections = doc.as_deref().map(param_sections).unwrap_or_default();
+        let args = &f.args;
+        let args: Vec<_> = args
+            .positional_only
+            .iter()
+            .chain(&args.args)
+            .chain(&args.var_args)
+            .chain(&args.keyword_only)
+            .chain(&args.kw_args)
+            .collect();
+        // Legacy annotations given as `# type: (int, str) -> bool` and `a,  # type: int`
+        let func_type = f.type_comment.as_deref().map(parse_func_type).transpose()?;
+        let mut comment_types = vec![None; args.len()];
+        if let Some(func_type) = &func_type {
+            if !matches!(&func_type.argtypes[..], [Expr::Ellipsis { .. }]) {
+                // `self` or `cls` of methods is omitted
+                let skip = args
+                    .len()
+                    .checked_sub(func_type.argtypes.len())
+                    .filter(|skip| *skip <= 1)
+                    .with_context(|| {
+                        format!("Type comment of `{}` does not match its arguments", f.name)
+                    })?;
+                for (ty, argtype) in comment_types[skip..].iter_mut().zip(&func_type.argtypes) {
+                    *ty = Some(argtype);
+                }
+            }
+        }
+        let arg_comments = args
+            .iter()
+            .map(|arg| {
+                arg.type_comment
+                    .as_deref()
+                    .map(parse_annotation)
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut parameters = Vec::new();
+        for ((arg, comment_type), arg_comment) in args.iter().zip(comment_types).zip(&arg_comments)
+        {
+            let section = sections.get(arg.name.as_ref());
+            let mut ty = match arg.ty.as_ref().or(arg_comment.as_ref()).or(comment_type) {
+                Some(ty) => self.type_of(ty)?,
+                None => Type::Unknown,
+            };
+            let mut inferred = false;
+            if ty == Type::Unknown && options.infer_from_docstring {
+                if let Some(tag) = section
+                    .and_then(|s| s.ty.as_deref())
+                    .and_then(|ty| self.docstring_type(ty))
+                {
+                    ty = tag;
+                    inferred = true;
+                }
+            }
+            parameters.push(Parameter {
+                name: arg.name.to_string(),
+                r#type: ty,
+                doc: section.map(|s| s.doc.clone()).filter(|doc| !doc.is_empty()),
+                inferred,
+            });
+        }
+        Ok(Function {
+            name: f.name.to_string(),
+            parameters,
+            r#return: match f
+                .returns
+                .as_ref()
+                .or(func_type.as_ref().map(|t| &t.returns))
+            {
+                Some(ty) => self.type_of(ty)?,
+                None => Type::Unknown,
+            },
+            doc,
+            signature: Some(signature(f)),
+            overloads,
+            source,
+        })
+    }
+
+    fn type_of(&self, e: &Expr) -> Result<Type> {
+        match e {
+            Expr::None { .. } => Ok(Type::None),
+            Expr::Ellipsis { .. } => Ok(Type::Ellipsis),
+            // Forward reference, e.g. `"Vector"`
+            Expr::Constant {
+                value: Constant::String(s),
+                ..
+            } => self.type_of(&parse_annotation(s)?),
+            Expr::BinOp {
+                left,
+                op: Operator::BitOr,
+                right,
+                ..
+            } => Ok(union(vec![self.type_of(left)?, self.type_of(right)?])),
+            Expr::Subscript { value, slice, .. } => self.subscript(value, slice),
+            Expr::Name { .. } | Expr::Attribute { .. } => self.named(e),
+            _ => bail!("Unsupported type = {}", e),
+        }
+    }
+
+    fn named(&self, e: &Expr) -> Result<Type> {
+        if let Expr::Name { id, .. } = e {
+            if let Some((&name, value)) = self.assigns.get_key_value(id.as_ref()) {
+                if !self.resolving.borrow_mut().insert(name) {
+                    bail!("Recursive type alias `{}` in `{}`", name, self.module);
+                }
+                let resolved = match self.new_type(value) {
+                    Ok(Some(new_type)) => Ok(new_type),
+                    // Type alias, e.g. `Vector = list[float]`
+                    Ok(None) => self.type_of(value),
+                    Err(e) => Err(e),
+                };
+                self.resolving.borrow_mut().remove(name);
+                return resolved;
+            }
+            if let Some(class) = self.classes.get(id.as_ref()) {
+                if self.is_callback_protocol(class) {
+                    return self.callback_protocol(class);
+                }
+            }
+        }
+        let name = self
+            .qualname(e)
+            .with_context(|| format!("Cannot resolve `{}` in `{}`", e, self.module))?;
+        Ok(match name.as_str() {
+            "builtins.int" => Type::Primitive(Primitive::Int),
+            "builtins.str" => Type::Primitive(Primitive::Str),
+            "builtins.float" => Type::Primitive(Primitive::Float),
+            "builtins.Exception" => Type::Exception,
+            "builtins.object" | "typing.Any" => Type::Any,
+            "typing.NoReturn" | "typing.Never" => Type::Never,
+            _ => bail!("Unsupported type = {}", name),
+        })
+    }
+
+    fn subscript(&self, value: &Expr, slice: &Expr) -> Result<Type> {
+        let args: Vec<&Expr> = match slice {
+            Expr::Tuple { elts, .. } => elts.iter().collect(),
+            e => vec![e],
+        };
+        let types = || -> Result<Vec<Type>> { args.iter().map(|e| self.type_of(e)).collect() };
+        let name = self
+            .qualname(value)
+            .with_context(|| format!("Cannot resolve `{}` in `{}`", value, self.module))?;
+        Ok(match name.as_str() {
+            "builtins.list" | "typing.List" | "collections.abc.Sequence" | "typing.Sequence" => {
+                Type::List { inner: types()? }
+            }
+            "builtins.tuple" | "typing.Tuple" => Type::Tuple { tags: types()? },
+            "builtins.dict" | "typing.Dict" => Type::Dict { inner: types()? },
+            "typing.Union" => union(types()?),
+            "typing.Optional" => {
+                let [arg] = args[..] else {
+                    bail!("Optional takes a type: {}", slice);
+                };
+                union(vec![self.type_of(arg)?, Type::None])
+            }
+            "builtins.type" | "typing.Type" => {
+                let [arg] = args[..] else {
+                    bail!("type takes a class: {}", slice);
+                };
+                self.class_object(arg)?
+            }
+            "typing.Annotated" | "typing_extensions.Annotated" => {
+                let Some((inner, metadata)) = args.split_first() else {
+                    bail!("Annotated takes a type and metadata: {}", slice);
+                };
+                let inner = self.type_of(inner)?;
+                let metadata: Vec<Annotation> =
+                    metadata.iter().filter_map(|m| self.marker(m)).collect();
+                if metadata.is_empty() {
+                    inner
+                } else {
+                    Type::Annotated {
+                        inner: Box::new(inner),
+                        metadata,
+                    }
+                }
+            }
+            "collections.abc.Callable" | "typing.Callable" => {
+                let [params, ret] = args[..] else {
+                    bail!("Callable takes parameters and a return type: {}", slice);
+                };
+                let (args, param_spec) = match params {
+                    Expr::List { elts, .. } => (
+                        elts.iter()
+                            .map(|e| self.type_of(e))
+                            .collect::<Result<_>>()?,
+                        None,
+                    ),
+                    Expr::Ellipsis { .. } => (vec![Type::Ellipsis], None),
+                    Expr::Subscript { value, slice, .. }
+                        if self.qualname(value).as_deref() == Some("typing.Concatenate") =>
+                    {
+                        let Some((spec, args)) = (match slice.as_ref() {
+                            Expr::Tuple { elts, .. } => elts.split_last(),
+                            _ => None,
+                        }) else {
+                            bail!("Concatenate takes types and a ParamSpec: {}", slice);
+                        };
+                        (
+                            args.iter()
+                                .map(|e| self.type_of(e))
+                                .collect::<Result<_>>()?,
+                            Some(self.param_spec(spec)?),
+                        )
+                    }
+                    spec => (Vec::new(), Some(self.param_spec(spec)?)),
+                };
+                Type::Callable {
+                    args,
+                    keywords: Vec::new(),
+                    param_spec,
+                    r#return: Box::new(self.type_of(ret)?),
+                }
+            }
+            _ => bail!(
+                "Unsupported type = {}",
+                Expr::Subscript {
+                    value: Box::new(value.clone()),
+                    slice: Box::new(slice.clone()),
+                    span: Span::default(),
+                }
+            ),
+        })
+    }
+
+    /// `NewType("UserId", int)`
+    fn new_type(&self, value: &Expr) -> Result<Option<Type>> {
+        let Expr::Call { func, args, .. } = value else {
+            return Ok(None);
+        };
+        if self.qualname(func).as_deref() != Some("typing.NewType") {
+            return Ok(None);
+        }
+        let [Expr::Constant {
+            value: Constant::String(name),
+            ..
+        }, supertype] = &args[..]
+        else {
+            bail!("Invalid NewType: {}", value);
+        };
+        Ok(Some(Type::UserDefined {
+            module: self.module.to_string(),
+            name: name.to_string(),
+            supertype: Box::new(self.type_of(supertype)?),
+        }))
+    }
+
+    /// Name of `ParamSpec` defined by `P = ParamSpec("P")`
+    fn param_spec(&self, e: &Expr) -> Result<String> {
+        if let Expr::Name { id, .. } = e {
+            if let Some(Expr::Call { func, args, .. }) = self.assigns.get(id.as_ref()) {
+                if self.qualname(func).as_deref() == Some("typing.ParamSpec") {
+                    if let Some(Expr::Constant {
+                        value: Constant::String(name),
+                        ..
+                    }) = args.first()
+                    {
+                        return Ok(name.to_string());
+                    }
+                }
+            }
+        }
+        bail!("Not a ParamSpec: {}", e)
+    }
+
+    /// `type[T]`
+    fn class_object(&self, e: &Expr) -> Result<Type> {
+        let name = self
+            .qualname(e)
+            .with_context(|| format!("Cannot resolve `{}` in `{}`", e, self.module))?;
+        let name = match name.as_str() {
+            "typing.Any" => "builtins.object",
+            name => name,
+        };
+        let (module, name) = name
+            .rsplit_once('.')
+            .with_context(|| format!("`{}` is not a class", name))?;
+        if let Some(class) = module.strip_prefix(&format!("{}.", self.module)) {
+            if self
+                .classes
+                .contains_key(class.split('.').next().unwrap_or(class))
+            {
+                bail!(
+                    "Nested class {}.{} is not supported in type[T]",
+                    module,
+                    name
+                );
+            }
+        }
+        Ok(Type::ClassObject {
+            module: module.to_string(),
+            name: name.to_string(),
+        })
+    }
+
+    /// Markers defined in `py2o2.py`, e.g. `py2o2.Rust("u8")`
+    fn marker(&self, e: &Expr) -> Option<Annotation> {
+        let Expr::Call {
+            func,
+            args,
+            keywords,
+            ..
+        } = e
+        else {
+            return None;
+        };
+        let name = self.qualname(func)?;
+        let string_arg = |keyword: &str| {
+            let value = args.first().or_else(|| {
+                keywords
+                    .iter()
+                    .find(|k| k.arg.as_deref() == Some(keyword))
+                    .map(|k| &k.value)
+            });
+            match value {
+                Some(Expr::Constant {
+                    value: Constant::String(s),
+                    ..
+                }) => Some(s.to_string()),
+                _ => None,
+            }
+        };
+        match name.strip_prefix("py2o2.")? {
+            "Rust" => Some(Annotation::Rust {
+                r#type: string_arg("type")?,
+            }),
+            "Rename" => Some(Annotation::Rename {
+                name: string_arg("name")?,
+            }),
+            "Skip" => Some(Annotation::Skip),
+            _ => None,
+        }
+    }
+
+    fn is_callback_protocol(&self, class: &ClassDef) -> bool {
+        class.bases.iter().any(|base| {
+            matches!(
+                self.qualname(base).as_deref(),
+                Some("typing.Protocol" | "typing_extensions.Protocol")
+            )
+        }) && call_method(class).is_some()
+    }
+
+    fn callback_protocol(&self, class: &ClassDef) -> Result<Type> {
+        let f = call_method(class).unwrap();
+        let args = &f.args;
+        let mut param_spec = None;
+        if let Some(Expr::Attribute { value, attr, .. }) =
+            args.var_args.as_ref().and_then(|arg| arg.ty.as_ref())
+        {
+            if *attr == "args" {
+                param_spec = Some(self.param_spec(value)?);
+            }
+        }
+        let type_of = |ty: &Option<Expr>| match ty {
+            Some(ty) => self.type_of(ty),
+            None => Ok(Type::Unknown),
+        };
+        Ok(Type::Callable {
+            // Skip `self`
+            args: args
+                .positional_only
+                .iter()
+                .chain(&args.args)
+                .skip(1)
+                .map(|arg| type_of(&arg.ty))
+                .collect::<Result<_>>()?,
+            keywords: args
+                .keyword_only
+                .iter()
+                .map(|arg| {
+                    Ok(Parameter {
+                        name: arg.name.to_string(),
+                        r#type: type_of(&arg.ty)?,
+                        doc: None,
+                        inferred: false,
+                    })
+                })
+                .collect::<Result<_>>()?,
+            param_spec,
+            r#return: Box::new(type_of(&f.returns)?),
+        })
+    }
+
+    /// Type written in docstring, e.g. `int`, `list of float` or `str, optional`
+    fn docstring_type(&self, ty: &str) -> Option<Type> {
+        let ty = ty.trim();
+        let ty = ty
+            .strip_suffix("optional")
+            .and_then(|ty| ty.trim_end().strip_suffix(','))
+            .unwrap_or(ty);
+        let ty = match ty
+            .strip_prefix("list of ")
+            .or_else(|| ty.strip_prefix("sequence of "))
+        {
+            Some(inner) => format!("list[{}]", inner),
+            None => ty.to_string(),
+        };
+        // Only names, attributes and subscripts
+        if !ty
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_.[], |".contains(c))
+        {
+            return None;
+        }
+        let (_, e) = all_consuming(parser::expr)
+            .parse(Input::new(&ty))
+            .finish()
+            .ok()?;
+        self.type_of(&e).ok()
+    }
+}
+
+fn call_method<'a>(class: &'a ClassDef<'a>) -> Option<&'a FunctionDef<'a>> {
+    class.body.iter().find_map(|stmt| match stmt {
+        Stmt::FunctionDef(f) if f.name == "__call__" => Some(f.as_ref()),
+        _ => None,
+    })
+}
+
+/// Flatten nested unions and remove duplicates as `typing.Union` does
+fn union(types: Vec<Type>) -> Type {
+    let mut args: Vec<Type> = Vec::new();
+    for ty in types {
+        let inner = match ty {
+            Type::Union { args } => args,
+            ty => vec![ty],
+        };
+        for ty in inner {
+            if !args.contains(&ty) {
+                args.push(ty);
+            }
+        }
+    }
+    if args.len() == 1 {
+        args.pop().unwrap()
+    } else {
+        Type::Union { args }
+    }
+}
+
+/// Docstring given as the first statement of the body
+fn docstring(body: &[Stmt]) -> Option<String> {
+    match body.first()? {
+        Stmt::Expr {
+            value:
+                Expr::Constant {
+                    value: Constant::String(doc),
+                    ..
+                },
+        } => Some(cleandoc(doc)),
+        _ => None,
+    }
+}
+
+/// Port of `inspect.cleandoc`
+fn cleandoc(doc: &str) -> String {
+    let mut lines: Vec<String> = doc.lines().map(expand_tabs).collect();
+    let margin = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim_start().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min();
+    if let Some(first) = lines.first_mut() {
+        *first = first.trim_start().to_string();
+    }
+    if let Some(margin) = margin {
+        for line in lines.iter_mut().skip(1) {
+            *line = line.get(margin..).unwrap_or_default().to_string();
+        }
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let start = lines.iter().take_while(|line| line.is_empty()).count();
+    lines[start..].join("\n")
+}
+
+fn expand_tabs(line: &str) -> String {
+    let mut out = String::new();
+    for c in line.chars() {
+        if c == '\t' {
+            out.push_str(&" ".repeat(8 - out.chars().count() % 8));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Type and description of a parameter in docstring
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub(crate) struct Section {
+    #[serde(rename = "type")]
+    pub(crate) ty: Option<String>,
+    pub(crate) doc: String,
+}
+
+impl Section {
+    fn append(&mut self, line: &str) {
+        self.doc = format!("{} {}", self.doc, line.trim()).trim().to_string();
+    }
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Types and descriptions of parameters in Google, NumPy or Sphinx style docstring
+///
+/// Also given to `inspect_module.py` as `param_sections` by [get_inspect_json_with_options].
+pub(crate) fn param_sections(doc: &str) -> HashMap<&str, Section> {
+    let lines: Vec<&str> = doc.lines().collect();
+    let mut params = sphinx_params(&lines);
+    params.extend(numpy_params(&lines));
+    params.extend(google_params(&lines));
+    params
+}
+
+fn google_params<'a>(lines: &[&'a str]) -> HashMap<&'a str, Section> {
+    let mut params: HashMap<&str, Section> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        if !["Args:", "Arguments:", "Parameters:"].contains(&line.trim()) {
+            continue;
+        }
+        let base = indent(line);
+        let mut entry = None;
+        let mut current = None;
+        for body in &lines[i + 1..] {
+            if body.trim().is_empty() {
+                continue;
+            }
+            if indent(body) <= base {
+                break;
+            }
+            let entry = *entry.get_or_insert(indent(body));
+            if indent(body) == entry {
+                // `name (type): description`
+                let Some((name, ty, doc)) = google_entry(body) else {
+                    break;
+                };
+                current = Some(name);
+                params.insert(
+                    name,
+                    Section {
+                        ty: ty.map(str::to_string),
+                        doc: doc.trim().to_string(),
+                    },
+                );
+            } else if let Some(current) = current {
+                params.get_mut(current).unwrap().append(body);
+            }
+        }
+    }
+    params
+}
+
+fn google_entry(line: &str) -> Option<(&str, Option<&str>, &str)> {
+    let line = line.trim_start().trim_start_matches('*');
+    let end = line
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(line.len());
+    if end == 0 {
+        return None;
+    }
+    let (name, rest) = line.split_at(end);
+    let rest = rest.trim_start();
+    if let Some(inner) = rest.strip_prefix('(') {
+        for (close, _) in inner.rmatch_indices(')') {
+            if let Some(doc) = inner[close + 1..].trim_start().strip_prefix(':') {
+                return Some((name, Some(&inner[..close]), doc));
+            }
+        }
+    }
+    Some((name, None, rest.strip_prefix(':')?))
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && line.chars().all(|c| c == '-')
+}
+
+fn numpy_params<'a>(lines: &[&'a str]) -> HashMap<&'a str, Section> {
+    let mut params: HashMap<&str, Section> = HashMap::new();
+    for i in 0..lines.len().saturating_sub(1) {
+        if !["Parameters", "Other Parameters"].contains(&lines[i].trim()) || !is_rule(lines[i + 1])
+        {
+            continue;
+        }
+        let base = indent(lines[i]);
+        let mut current = Vec::new();
+        let body = &lines[i + 2..];
+        for (j, line) in body.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            // next section header, e.g. `Returns` followed by `-------`
+            if body.get(j + 1).is_some_and(|next| is_rule(next)) {
+                break;
+            }
+            if indent(line) < base {
+                break;
+            }
+            if indent(line) == base {
+                let (names, ty) = line.split_once(':').unwrap_or((line, ""));
+                current = names
+                    .split(',')
+                    .map(|name| name.trim().trim_start_matches('*'))
+                    .collect();
+                for name in &current {
+                    let ty = Some(ty.trim()).filter(|ty| !ty.is_empty());
+                    params.insert(
+                        name,
+                        Section {
+                            ty: ty.map(str::to_string),
+                            doc: String::new(),
+                        },
+                    );
+                }
+            } else {
+                for name in &current {
+                    params.get_mut(name).unwrap().append(line);
+                }
+            }
+        }
+    }
+    params
+}
+
+fn sphinx_params<'a>(lines: &[&'a str]) -> HashMap<&'a str, Section> {
+    let mut params: HashMap<&str, Section> = HashMap::new();
+    let mut types = Vec::new();
+    let mut current = None;
+    let is_name = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_');
+    for line in lines {
+        let field = |name: &str| {
+            let rest = line.trim_start().strip_prefix(name)?;
+            if !rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let (head, doc) = rest.split_once(':')?;
+            let words: Vec<&str> = head.split_whitespace().collect();
+            Some((words, doc))
+        };
+        if let Some((words, doc)) = field(":param") {
+            // `:param name:` or `:param type name:`
+            let (ty, name) = match words[..] {
+                [name] => (None, name),
+                [ty, name] => (Some(ty), name),
+                _ => (None, ""),
+            };
+            let name = name.trim_start_matches('*');
+            if is_name(name) {
+                current = Some(name);
+                params.insert(
+                    name,
+                    Section {
+                        ty: ty.map(str::to_string),
+                        doc: doc.trim().to_string(),
+                    },
+                );
+                continue;
+            }
+        }
+        if let Some((words, ty)) = field(":type") {
+            if let [name] = words[..] {
+                let name = name.trim_start_matches('*');
+                if is_name(name) {
+                    current = None;
+                    types.push((name, ty.trim()));
+                    continue;
+                }
+            }
+        }
+        match current {
+            Some(name) if indent(line) > 0 && !line.trim().is_empty() => {
+                params.get_mut(name).unwrap().append(line);
+            }
+            _ => current = None,
+        }
+    }
+    for (name, ty) in types {
+        params.entry(name).or_default().ty = Some(ty.to_string());
+    }
+    params
+}
+
+/// Python signature in the form of `inspect.Signature`, e.g. `def f(x: int, /, *, y=1) -> str`
+fn signature(f: &FunctionDef) -> String {
+    let mut s = format!("def {}({})", f.name, f.args);
+    if let Some(ret) = &f.returns {
+        s += &format!(" -> {}", ret);
+    }
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clean_docstring() {
+        assert_eq!(
+            cleandoc("Summary.\n\n    Details\n      indented\n    "),
+            "Summary.\n\nDetails\n  indented"
+        );
+        assert_eq!(cleandoc("\n    Summary.\n    "), "Summary.");
+    }
+
+    #[test]
+    fn docstring_sections() {
+        let sections = param_sections(
+            "Summary.\n\nArgs:\n    x (int): The first.\n    y: The second\n        continued.",
+        );
+        assert_eq!(sections["x"].ty.as_deref(), Some("int"));
+        assert_eq!(sections["y"].doc, "The second continued.");
+
+        let sections =
+            param_sections(":param str a: The first.\n:param b: The second.\n:type b: int");
+        assert_eq!(sections["a"].ty.as_deref(), Some("str"));
+        assert_eq!(sections["b"].ty.as_deref(), Some("int"));
+    }
+}
@@ -1,15 +1,28 @@
 use anyhow::Result;
-use pyo3::{types::PyModule, PyResult, Python};
-use serde::Deserialize;
-use std::collections::BTreeMap;
+use pyo3::{
+    exceptions::PyValueError,
+    types::{PyCFunction, PyDict, PyModule, PyTuple},
+    PyResult, Python,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::PathBuf};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "kind")]
 pub enum Type {
     Primitive(Primitive),
     Exception,
     Ellipsis,
+    /// `typing.Any` or `object`
+    Any,
+    /// `typing.NoReturn` or `typing.Never`
+    Never,
+    /// `type[T]` for a class `T`
+    ClassObject {
+        module: String,
+        name: String,
+    },
     Tuple {
         tags: Vec<Type>,
     },
@@ -29,12 +42,56 @@ pub enum Type {
     },
     Callable {
         args: Vec<Type>,
+        /// Keyword-only parameters, e.g. of `__call__` in a callback protocol
+        #[serde(default)]
+        keywords: Vec<Parameter>,
+        /// `ParamSpec` taking the rest of arguments, e.g. `P` in `Callable[Concatenate[int, P], R]`
+        #[serde(default)]
+        param_spec: Option<String>,
         r#return: Box<Type>,
     },
+    /// `Annotated[T, ...]` with markers defined in `py2o2.py`, see [py2o2_runtime::MARKERS]
+    Annotated {
+        inner: Box<Type>,
+        metadata: Vec<Annotation>,
+    },
+    /// Not annotated
+    Unknown,
     None,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+impl Type {
+    /// Type without `Annotated` metadata
+    pub fn unannotated(&self) -> &Type {
+        match self {
+            Type::Annotated { inner, .. } => inner.unannotated(),
+            _ => self,
+        }
+    }
+
+    /// Metadata given by `Annotated[T, ...]`
+    pub fn metadata(&self) -> &[Annotation] {
+        match self {
+            Type::Annotated { metadata, .. } => metadata,
+            _ => &[],
+        }
+    }
+}
+
+/// Metadata in `typing.Annotated` to steer code generation
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "kind")]
+pub enum Annotation {
+    /// `py2o2.Rust("u32")`, use the Rust type instead
+    Rust { r#type: String },
+    /// `py2o2.Rename("kind")`, rename the parameter in Rust
+    Rename { name: String },
+    /// `py2o2.Skip()`, do not generate the function if used in its return or any parameter
+    Skip,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[serde(tag = "name")]
 pub enum Primitive {
@@ -43,30 +100,73 @@ pub enum Primitive {
     Str,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: String,
     pub r#type: Type,
+    /// Description of the parameter in the docstring of the function
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// The type is not annotated but inferred from the docstring
+    #[serde(default)]
+    pub inferred: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Function {
     pub name: String,
     pub parameters: Vec<Parameter>,
     pub r#return: Type,
+    /// Docstring given by `inspect.getdoc`
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// Python signature, e.g. `def f(x: int) -> str`
+    #[serde(default)]
+    pub signature: Option<String>,
+    /// Variants declared by `@typing.overload`
+    #[serde(default)]
+    pub overloads: Vec<Function>,
+    /// Where the signature is taken from
+    #[serde(default)]
+    pub source: Source,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+/// Origin of the type information of an item
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    /// Annotations of the object loaded by Python
+    #[default]
+    Runtime,
+    /// `.pyi` stub file placed next to the module or in a stub directory
+    Stub,
+    /// `__text_signature__` of builtin or extension functions
+    TextSignature,
+    /// Annotations in the `.py` source file read without importing it, see [crate::frontend]
+    Static,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct TypeDefinition {
     pub name: String,
     pub module: String,
     pub supertype: Type,
+    #[serde(default)]
+    pub doc: Option<String>,
+    #[serde(default)]
+    pub source: Source,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Interface {
     pub functions: BTreeMap<String, Function>,
     pub type_definitions: BTreeMap<String, TypeDefinition>,
+    /// Builtin or extension functions skipped since neither a stub nor `__text_signature__` gives their signature,
+    /// e.g. `math.hypot`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub without_signature: Vec<String>,
 }
 
 impl Interface {
@@ -74,18 +174,107 @@ impl Interface {
         Ok(serde_json::from_str(json)?)
     }
 
+    /// Parameters and return values without annotation, e.g. `f(x)` or `f -> return`
+    pub fn unannotated(&self) -> Vec<String> {
+        let mut items = Vec::new();
+        for f in self.functions.values() {
+            for p in &f.parameters {
+                if p.r#type == Type::Unknown {
+                    items.push(format!("{}({})", f.name, p.name));
+                }
+            }
+            if f.r#return == Type::Unknown {
+                items.push(format!("{} -> return", f.name));
+            }
+        }
+        items
+    }
+
     pub fn from_py_module(target: &str) -> Result<Self> {
-        let json = get_inspect_json(target)?;
+        Self::from_py_module_with_options(target, &InspectOptions::default())
+    }
+
+    pub fn from_py_module_with_options(target: &str, options: &InspectOptions) -> Result<Self> {
+        if options.no_import {
+            return Self::from_py_ast(target, options);
+        }
+        let json = get_inspect_json_with_options(target, options)?;
         Self::from_json(&json)
     }
 }
 
+/// Options for inspecting Python modules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InspectOptions {
+    /// Infer types of unannotated parameters from Google, NumPy or Sphinx style docstrings
+    pub infer_from_docstring: bool,
+    /// Directories searched for `.pyi` stubs before the one next to the module,
+    /// e.g. a local checkout of typeshed
+    pub stub_paths: Vec<PathBuf>,
+    /// Read annotations from the syntax tree given by Python's `ast` module
+    /// instead of importing the module, see [Interface::from_py_ast]
+    pub no_import: bool,
+    /// Python version which `sys.version_info` guards are evaluated against without importing the module,
+    /// e.g. `(3, 10)`, or [LATEST_PYTHON_VERSION] if not given
+    pub python_version: Option<(u8, u8)>,
+    /// `sys.platform` which platform guards are evaluated against without importing the module,
+    /// e.g. `"win32"`, or the one of the host if not given
+    pub platform: Option<String>,
+}
+
+/// Target version of guards when [InspectOptions::python_version] is not given
+pub const LATEST_PYTHON_VERSION: (u8, u8) = (3, 12);
+
 pub fn get_inspect_json(target: &str) -> Result<String> {
+    get_inspect_json_with_options(target, &InspectOptions::default())
+}
+
+pub fn get_inspect_json_with_options(target: &str, options: &InspectOptions) -> Result<String> {
+    if options.no_import {
+        let interface = Interface::from_py_ast(target, options)?;
+        return Ok(serde_json::to_string_pretty(&interface)?);
+    }
     const PY: &str = include_str!("../../inspect_module.py");
     let json = Python::with_gil(|py: Python<'_>| -> PyResult<String> {
+        py2o2_runtime::import_markers(py)?;
         let module = PyModule::from_code(py, PY, "", "")?;
         let f = module.getattr("inspect_module")?;
-        let json = f.call1((target,))?.extract()?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("infer_from_docstring", options.infer_from_docstring)?;
+        // Stubs are found and read by the frontend instead of being executed by Python
+        let stub_options = options.clone();
+        let parse_stub = PyCFunction::new_closure(
+            py,
+            Some("parse_stub"),
+            None,
+            move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<Option<String>> {
+                let (module_name, module_file): (String, Option<PathBuf>) = args.extract()?;
+                let Some(path) = crate::frontend::find_stub(
+                    &module_name,
+                    module_file.as_deref(),
+                    &stub_options.stub_paths,
+                ) else {
+                    return Ok(None);
+                };
+                Interface::from_stub(&module_name, &path, &stub_options)
+                    .and_then(|interface| Ok(Some(serde_json::to_string(&interface)?)))
+                    .map_err(|e| PyValueError::new_err(format!("{:#}", e)))
+            },
+        )?;
+        kwargs.set_item("parse_stub", parse_stub)?;
+        // Docstrings are parsed by the same rules as the frontend
+        let param_sections = PyCFunction::new_closure(
+            py,
+            Some("param_sections"),
+            None,
+            |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<String> {
+                let (doc,): (&str,) = args.extract()?;
+                serde_json::to_string(&crate::frontend::param_sections(doc))
+                    .map_err(|e| PyValueError::new_err(e.to_string()))
+            },
+        )?;
+        kwargs.set_item("param_sections", param_sections)?;
+        let json = f.call((target,), Some(kwargs))?.extract()?;
         Ok(json)
     })?;
     Ok(json)
@@ -100,6 +289,9 @@ mod test {
         let ty: Type = serde_json::from_str(r#"{"kind": "none"}"#)?;
         assert_eq!(ty, Type::None);
 
+        let ty: Type = serde_json::from_str(r#"{"kind": "unknown"}"#)?;
+        assert_eq!(ty, Type::Unknown);
+
         let ty: Type = serde_json::from_str(r#"{"kind": "primitive", "name": "int"}"#)?;
         assert_eq!(ty, Type::Primitive(Primitive::Int));
 
@@ -112,6 +304,16 @@ mod test {
                 inner: vec![Type::Primitive(Primitive::Int)]
             }
         );
+        let ty: Type = serde_json::from_str(
+            r#"{"kind": "annotated", "inner": {"kind": "primitive", "name": "int"}, "metadata": [{"kind": "rust", "type": "u32"}]}"#,
+        )?;
+        assert_eq!(ty.unannotated(), &Type::Primitive(Primitive::Int));
+        assert_eq!(
+            ty.metadata(),
+            &[Annotation::Rust {
+                r#type: "u32".to_string()
+            }]
+        );
         Ok(())
     }
 
@@ -123,9 +325,16 @@ mod test {
             p,
             Parameter {
                 name: "x".to_string(),
-                r#type: Type::Primitive(Primitive::Int)
+                r#type: Type::Primitive(Primitive::Int),
+                doc: None,
+                inferred: false,
             }
         );
+
+        let p: Parameter = serde_json::from_str(
+            r#"{"name": "x", "type": {"kind": "primitive", "name": "int"}, "doc": "Input"}"#,
+        )?;
+        assert_eq!(p.doc.as_deref(), Some("Input"));
         Ok(())
     }
 }
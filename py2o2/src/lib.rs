@@ -1,17 +1,60 @@
 pub mod ast;
 pub mod codegen;
+pub mod frontend;
 pub mod inspect;
 pub mod parser;
 
 use anyhow::Result;
-use inspect::get_inspect_json;
+use codegen::CodegenOptions;
+use inspect::{get_inspect_json_with_options, InspectOptions, Interface};
+use std::path::Path;
 
 pub fn generate(python_module_name: &str, bare: bool) -> Result<String> {
-    let interface = inspect::Interface::from_py_module(python_module_name)?;
-    let generated = codegen::generate(python_module_name, &interface, bare)?;
-    Ok(generated)
+    generate_with_options(python_module_name, bare, &InspectOptions::default())
+}
+
+pub fn generate_with_options(
+    python_module_name: &str,
+    bare: bool,
+    options: &InspectOptions,
+) -> Result<String> {
+    let codegen_options = CodegenOptions {
+        bare,
+        ..Default::default()
+    };
+    generate_with_codegen_options(python_module_name, options, &codegen_options)
+}
+
+pub fn generate_with_codegen_options(
+    python_module_name: &str,
+    options: &InspectOptions,
+    codegen_options: &CodegenOptions,
+) -> Result<String> {
+    let interface = Interface::from_py_module_with_options(python_module_name, options)?;
+    codegen::generate_with_options(python_module_name, &interface, codegen_options)
+}
+
+/// Generate Rust code from a `.py` file without importing it, see [frontend]
+pub fn generate_from_source_path(
+    path: &Path,
+    options: &InspectOptions,
+    codegen_options: &CodegenOptions,
+) -> Result<String> {
+    let python_module_name = frontend::module_name(path)?;
+    let interface = Interface::from_source_path(path, options)?;
+    codegen::generate_with_options(&python_module_name, &interface, codegen_options)
 }
 
 pub fn inspect(python_module_name: &str) -> Result<String> {
-    get_inspect_json(python_module_name)
+    inspect_with_options(python_module_name, &InspectOptions::default())
+}
+
+pub fn inspect_with_options(python_module_name: &str, options: &InspectOptions) -> Result<String> {
+    get_inspect_json_with_options(python_module_name, options)
+}
+
+/// Serialize the interface of a `.py` file into JSON without importing it, see [frontend]
+pub fn inspect_from_source_path(path: &Path, options: &InspectOptions) -> Result<String> {
+    let interface = Interface::from_source_path(path, options)?;
+    Ok(serde_json::to_string_pretty(&interface)?)
 }
@@ -2,16 +2,40 @@
 //!
 //! Abstract syntax tree of Python is defined at <https://docs.python.org/3/library/ast.html>
 //!
+//! Identifiers and strings in the tree are [std::borrow::Cow] borrowing from the input.
+//! Trees converted from Python's `ast` module by [crate::ast] own them,
+//! i.e. `Stmt<'static>`, and compare equal to the parsed ones.
+//!
+//! Parsers take an [Input] which keeps the position in the source,
+//! so that expressions, arguments and functions have the [Span] where they are parsed from,
+//! and errors can be rendered with the source line as a [Diagnostic].
+//!
+//! Trees can be walked by [visit::Visit], rewritten by [fold::Fold],
+//! and rendered back to Python source by [std::fmt::Display] or [unparse].
 
 mod builtin;
+mod class_def;
+mod diagnostic;
 mod expr;
+pub mod fold;
 mod function_def;
+mod input;
+mod literal;
+mod stmt;
+mod unparse;
+pub mod visit;
 
 pub use builtin::*;
+pub use class_def::*;
+pub use diagnostic::*;
 pub use expr::*;
 pub use function_def::*;
+pub use input::*;
+pub use literal::*;
+pub use stmt::*;
+pub use unparse::*;
 
-pub type ParseResult<'input, T> = nom::IResult<&'input str, T>;
+pub type ParseResult<'input, T> = nom::IResult<Input<'input>, T>;
 
 #[cfg(test)]
 mod test {
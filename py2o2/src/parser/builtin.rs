@@ -1,40 +1,58 @@
-use super::ParseResult;
+use super::{Input, ParseResult};
+use std::borrow::Cow;
+use unicode_ident::{is_xid_continue, is_xid_start};
+use unicode_normalization::{is_nfkc_quick, IsNormalized, UnicodeNormalization};
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
     character::complete::*,
-    multi::many0,
+    combinator::recognize,
+    multi::{many0_count, many1_count},
+    sequence::pair,
     Parser,
 };
 
-pub fn multiline_string_literal(input: &str) -> ParseResult<&str> {
-    let (input, _start) = tag(r#"""""#).parse(input)?;
-    let (input, doc) = take_until(r#"""""#).parse(input)?;
-    let (input, _end) = tag(r#"""""#).parse(input)?;
-    Ok((input, doc))
+/// Whitespace, comment or backslash continuation inside a logical line
+fn blank(input: Input<'_>) -> ParseResult<'_, Input<'_>> {
+    alt((
+        multispace1,
+        recognize(pair(char('\\'), line_ending)),
+        recognize(pair(char('#'), not_line_ending)),
+    ))
+    .parse(input)
 }
 
-pub fn string_literal(input: &str) -> ParseResult<&str> {
-    let (input, _start) = char('"').parse(input)?;
-    let (input, doc) = take_until(r#"""#).parse(input)?;
-    let (input, _end) = char('"').parse(input)?;
-    Ok((input, doc))
+/// Zero or more whitespaces, where comments and backslash continuations are also skipped
+pub fn ws(input: Input<'_>) -> ParseResult<'_, Input<'_>> {
+    recognize(many0_count(blank)).parse(input)
 }
 
-pub fn string(input: &str) -> ParseResult<&str> {
-    alt((multiline_string_literal, string_literal)).parse(input)
+/// One or more whitespaces, see [ws]
+pub fn ws1(input: Input<'_>) -> ParseResult<'_, Input<'_>> {
+    recognize(many1_count(blank)).parse(input)
 }
 
-pub fn identifier(input0: &str) -> ParseResult<&str> {
-    // TODO: Support more unicode
-    // https://docs.python.org/ja/3/reference/lexical_analysis.html#identifiers
-    let alpha_1 = satisfy(|c| matches!(c, 'a'..='z' | 'A'..='Z' | '_'));
-    let alphanum_1 = satisfy(|c| matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_'));
-    let (input, _head) = alpha_1(input0)?;
-    let (_input, tail) = many0(alphanum_1).parse(input)?;
-    let n = tail.len() + 1;
-    Ok((&input0[n..], &input0[..n]))
+/// Identifier normalized into NFKC as Python does, e.g. `ﬁle` into `file`
+///
+/// See <https://docs.python.org/3/reference/lexical_analysis.html#identifiers>
+pub fn identifier(input: Input<'_>) -> ParseResult<'_, Cow<'_, str>> {
+    let start = satisfy(|c| c == '_' || is_xid_start(c));
+    let continue_ = satisfy(is_xid_continue);
+    let (input, ident) = recognize(pair(start, many0_count(continue_))).parse(input)?;
+    Ok((input, normalize_identifier(ident.fragment())))
+}
+
+/// NFKC normalization of an identifier, where ones already normalized are borrowed as they are
+pub fn normalize_identifier(ident: &str) -> Cow<'_, str> {
+    if is_nfkc_quick(ident.chars()) == IsNormalized::Yes {
+        return Cow::Borrowed(ident);
+    }
+    let normalized: String = ident.nfkc().collect();
+    if normalized == ident {
+        Cow::Borrowed(ident)
+    } else {
+        Cow::Owned(normalized)
+    }
 }
 
 #[cfg(test)]
@@ -44,18 +62,35 @@ mod test {
 
     #[test]
     fn parse_ident() {
-        assert_eq!(identifier("abc").finish().unwrap(), ("", "abc"));
-        assert_eq!(identifier("abc0").finish().unwrap(), ("", "abc0"));
-        assert_eq!(identifier("abc def").finish().unwrap(), (" def", "abc"));
+        let ident = |input| {
+            let (rest, id) = identifier(Input::new(input)).finish().unwrap();
+            (rest.fragment(), id)
+        };
+        assert_eq!(ident("abc"), ("", "abc".into()));
+        assert_eq!(ident("abc0"), ("", "abc0".into()));
+        assert_eq!(ident("abc def"), (" def", "abc".into()));
+
+        // Normalized into NFKC
+        assert_eq!(ident("λ_to_ν"), ("", "λ_to_ν".into()));
+        assert_eq!(ident("ﬁle"), ("", "file".into()));
+        assert_eq!(ident("ℎ²"), ("²", "h".into()));
+        // Combining characters are composed
+        assert_eq!(ident("cafe\u{301}"), ("", "café".into()));
+        assert!(matches!(ident("café").1, Cow::Borrowed(_)));
+        assert!(matches!(ident("abc").1, Cow::Borrowed(_)));
 
-        assert!(identifier("0abc").finish().is_err());
+        assert!(identifier(Input::new("0abc")).finish().is_err());
     }
 
     #[test]
-    fn parse_string() {
-        assert_eq!(
-            string(r#""""document""""#).finish().unwrap(),
-            ("", "document")
-        );
+    fn parse_ws() {
+        let sp = |input| {
+            let (rest, sp) = ws(Input::new(input)).finish().unwrap();
+            (rest.fragment(), sp.fragment())
+        };
+        assert_eq!(sp(" \\\n  x"), ("x", " \\\n  "));
+        assert_eq!(sp("# comment\n  x"), ("x", "# comment\n  "));
+        assert_eq!(sp("x"), ("x", ""));
+        assert!(ws1(Input::new("x")).finish().is_err());
     }
 }
@@ -0,0 +1,151 @@
+use super::{builtin::*, expr::*, function_def::*, stmt::*, Input, ParseResult, Span};
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::*,
+    combinator::{cut, opt},
+    Parser,
+};
+
+/// `ClassDef(identifier name, expr* bases, keyword* keywords, stmt* body, expr* decorator_list)`
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+pub struct ClassDef<'input> {
+    pub(crate) name: Cow<'input, str>,
+    pub(crate) bases: Vec<Expr<'input>>,
+    /// e.g. `metaclass=ABCMeta` or `total=False` of `TypedDict`
+    pub(crate) keywords: Vec<Keyword<'input>>,
+    pub(crate) decorators: Vec<Expr<'input>>,
+    pub(crate) body: Vec<Stmt<'input>>,
+    /// From `class` to the end of the body, i.e. decorators are not included
+    pub(crate) span: Span,
+}
+
+impl<'input> ClassDef<'input> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn bases(&self) -> &[Expr<'input>] {
+        &self.bases
+    }
+
+    /// Keyword arguments in the parentheses, e.g. `metaclass=ABCMeta`
+    pub fn keywords(&self) -> &[Keyword<'input>] {
+        &self.keywords
+    }
+
+    /// `metaclass=...` among [ClassDef::keywords]
+    pub fn metaclass(&self) -> Option<&Expr<'input>> {
+        self.keywords
+            .iter()
+            .find(|keyword| keyword.arg() == Some("metaclass"))
+            .map(Keyword::value)
+    }
+
+    pub fn decorators(&self) -> &[Expr<'input>] {
+        &self.decorators
+    }
+
+    pub fn body(&self) -> &[Stmt<'input>] {
+        &self.body
+    }
+
+    /// String literal at the beginning of the body as it is written, i.e. without `inspect.cleandoc`
+    pub fn docstring(&self) -> Option<&str> {
+        match self.body.first()? {
+            Stmt::Expr {
+                value:
+                    Expr::Constant {
+                        value: Constant::String(doc),
+                        ..
+                    },
+            } => Some(doc),
+            _ => None,
+        }
+    }
+
+    /// From `class` to the end of the body, or the default for ones converted by [crate::ast]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+pub fn class_def(input: Input<'_>) -> ParseResult<'_, ClassDef<'_>> {
+    let (start, decorators) = decorators(input)?;
+    let (input, _class) = tag("class").parse(start)?;
+    let (input, _space) = ws1(input)?;
+    let (input, name) = cut(identifier)(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, args) = opt(function_args).parse(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, _colon) = cut(char(':')).parse(input)?;
+    let (input, body) = cut(suite)(input)?;
+    // The body may be followed by blank lines
+    let consumed = &start[..start.len() - input.len()];
+    let span = Span {
+        start: start.offset(),
+        end: start.offset() + consumed.trim_end().len(),
+    };
+    let (bases, keywords) = args.unwrap_or_default();
+    Ok((
+        input,
+        ClassDef {
+            name,
+            bases,
+            keywords,
+            decorators,
+            body,
+            span,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::CheckParsed, *};
+
+    #[test]
+    fn parse_class_def() {
+        let class = class_def(Input::new(
+            r#"@dataclass(frozen=True)
+class Point(Base, Generic[T], metaclass=ABCMeta, total=False):
+    """A point"""
+    x: int
+    y: int = 0
+
+    def norm(self) -> float: ...
+"#,
+        ))
+        .check_parsed();
+        assert_eq!(class.name(), "Point");
+        assert_eq!(class.decorators().len(), 1);
+        assert_eq!(class.bases().len(), 2);
+        let keywords: Vec<_> = class.keywords().iter().map(Keyword::arg).collect();
+        assert_eq!(keywords, [Some("metaclass"), Some("total")]);
+        assert!(matches!(class.metaclass(), Some(Expr::Name { id, .. }) if id == "ABCMeta"));
+        assert_eq!(class.docstring(), Some("A point"));
+        assert!(matches!(
+            class.body(),
+            [
+                Stmt::Expr { .. },
+                Stmt::AnnAssign { value: None, .. },
+                Stmt::AnnAssign { value: Some(_), .. },
+                Stmt::FunctionDef(_),
+            ]
+        ));
+        assert_eq!(
+            class.span(),
+            Span {
+                start: 24,
+                end: 172
+            }
+        );
+
+        let class = class_def(Input::new("class Color(Enum): RED = 1\n")).check_parsed();
+        assert_eq!(class.docstring(), None);
+        assert!(class.keywords().is_empty() && class.metaclass().is_none());
+    }
+}
@@ -0,0 +1,112 @@
+//! Human-readable parse errors
+
+use super::{Input, Location};
+use nom::error::{Error, ErrorKind};
+use std::fmt;
+
+/// Parse error rendered with the line of the source and a caret, e.g.
+///
+/// ```text
+/// error: invalid syntax
+///  --> example.py:1:13
+///   |
+/// 1 | def f(x: int -> int:
+///   |             ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// Path or name of the module shown in the header
+    pub path: String,
+    pub location: Location,
+    /// The line where the error is found
+    pub line: String,
+}
+
+impl Diagnostic {
+    pub fn new(path: &str, error: &Error<Input>) -> Self {
+        let input = error.input;
+        let location = input.location();
+        let line = input
+            .source()
+            .lines()
+            .nth(location.line - 1)
+            .unwrap_or_default()
+            .to_string();
+        Diagnostic {
+            message: message(error.code).to_string(),
+            path: path.to_string(),
+            location,
+            line,
+        }
+    }
+}
+
+fn message(kind: ErrorKind) -> &'static str {
+    match kind {
+        // `block` fails with `Verify` for a line indented without a compound statement
+        ErrorKind::Verify => "unexpected indent",
+        // `block` fails with `Eof` for a line between the indentations of the block and its body,
+        // and `module` for the rest not parsed as statements
+        ErrorKind::Eof => "unindent does not match any outer indentation level",
+        _ => "invalid syntax",
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Location { line, column } = self.location;
+        let gutter = " ".repeat(line.to_string().len());
+        // Keep tabs so that the caret is aligned with the line
+        let padding: String = self
+            .line
+            .chars()
+            .take(column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, self.path, line, column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line, self.line)?;
+        write!(f, "{} | {}^", gutter, padding)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::module;
+    use nom::Finish;
+
+    fn diagnose(source: &str) -> String {
+        let error = module(Input::new(source)).finish().unwrap_err();
+        Diagnostic::new("example.py", &error).to_string()
+    }
+
+    #[test]
+    fn render() {
+        insta::assert_snapshot!(diagnose("import os\n\ndef f(x: int -> int:\n    pass\n"), @r###"
+        error: invalid syntax
+         --> example.py:3:14
+          |
+        3 | def f(x: int -> int:
+          |              ^
+        "###);
+        insta::assert_snapshot!(diagnose("import os\n    import sys\n"), @r###"
+        error: unexpected indent
+         --> example.py:2:5
+          |
+        2 |     import sys
+          |     ^
+        "###);
+        insta::assert_snapshot!(diagnose("class A:\n    def f(self):\n        pass\n  x = 1\n"), @r###"
+        error: unindent does not match any outer indentation level
+         --> example.py:4:3
+          |
+        4 |   x = 1
+          |   ^
+        "###);
+    }
+}
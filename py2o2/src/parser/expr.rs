@@ -1,95 +1,720 @@
 use super::*;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, multispace0, multispace1},
-    combinator::opt,
-    multi::separated_list0,
-    number::complete::double,
-    sequence::tuple,
+    character::complete::char,
+    combinator::{not, opt},
+    error::{Error, ErrorKind},
+    multi::{many0, many1, separated_list0},
+    sequence::{preceded, terminated, tuple},
     Parser,
 };
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+/// Expression
+///
+/// ```text
+/// expr = BoolOp(boolop op, expr* values) | NamedExpr(expr target, expr value) | ...
+/// ```
+///
+/// Every variant has the [Span] where it is parsed from, see [Expr::span].
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
 pub enum Expr<'input> {
     /// `Name(identifier id, expr_context ctx)`
     Name {
-        id: &'input str,
+        id: Cow<'input, str>,
+        span: Span,
     },
     /// `Constant(constant value, string? kind)`
     Constant {
         value: Constant<'input>,
+        span: Span,
     },
     /// `Tuple(expr* elts, expr_context ctx)`
     Tuple {
         elts: Vec<Self>,
+        span: Span,
     },
     /// `Compare(expr left, cmpop* ops, expr* comparators)`
     Compare {
         left: Box<Self>,
-        ops: CmpOp,
-        comparators: Box<Self>,
+        ops: Vec<CmpOp>,
+        comparators: Vec<Self>,
+        span: Span,
     },
     /// `Attribute(expr value, identifier attr, expr_context ctx)`
     Attribute {
         value: Box<Self>,
-        attr: &'input str,
+        attr: Cow<'input, str>,
+        span: Span,
     },
     /// `Call(expr func, expr* args, keyword* keywords)`
     Call {
         func: Box<Self>,
         args: Vec<Self>,
         keywords: Vec<Keyword<'input>>,
+        span: Span,
+    },
+    /// `Subscript(expr value, expr slice, expr_context ctx)`
+    Subscript {
+        value: Box<Self>,
+        slice: Box<Self>,
+        span: Span,
+    },
+    /// `BinOp(expr left, operator op, expr right)`
+    BinOp {
+        left: Box<Self>,
+        op: Operator,
+        right: Box<Self>,
+        span: Span,
+    },
+    /// `List(expr* elts, expr_context ctx)`
+    List {
+        elts: Vec<Self>,
+        span: Span,
+    },
+    None {
+        span: Span,
+    },
+    Ellipsis {
+        span: Span,
     },
-    None,
-    Ellipsis,
-    Pass,
-}
-
-pub fn expr(input: &str) -> ParseResult<Expr> {
-    let (mut input, mut e) = alt((
-        tag("None").map(|_| Expr::None),
-        tag("...").map(|_| Expr::Ellipsis),
-        tag("pass").map(|_| Expr::Pass),
-        constant.map(|value| Expr::Constant { value }),
-        identifier.map(|id| Expr::Name { id }),
-        expr_tuple.map(|elts| Expr::Tuple { elts }),
+    /// `BoolOp(boolop op, expr* values)`
+    BoolOp {
+        op: BoolOperator,
+        values: Vec<Self>,
+        span: Span,
+    },
+    /// `NamedExpr(expr target, expr value)`
+    NamedExpr {
+        target: Box<Self>,
+        value: Box<Self>,
+        span: Span,
+    },
+    /// `UnaryOp(unaryop op, expr operand)`
+    UnaryOp {
+        op: UnaryOperator,
+        operand: Box<Self>,
+        span: Span,
+    },
+    /// `Lambda(arguments args, expr body)`
+    Lambda {
+        args: Box<Arguments<'input>>,
+        body: Box<Self>,
+        span: Span,
+    },
+    /// `IfExp(expr test, expr body, expr orelse)`
+    IfExp {
+        test: Box<Self>,
+        body: Box<Self>,
+        orelse: Box<Self>,
+        span: Span,
+    },
+    /// `Dict(expr* keys, expr* values)`, where the key of `**d` is `None`
+    Dict {
+        keys: Vec<Option<Self>>,
+        values: Vec<Self>,
+        span: Span,
+    },
+    /// `Set(expr* elts)`
+    Set {
+        elts: Vec<Self>,
+        span: Span,
+    },
+    /// `ListComp(expr elt, comprehension* generators)`
+    ListComp {
+        elt: Box<Self>,
+        generators: Vec<Comprehension<'input>>,
+        span: Span,
+    },
+    /// `SetComp(expr elt, comprehension* generators)`
+    SetComp {
+        elt: Box<Self>,
+        generators: Vec<Comprehension<'input>>,
+        span: Span,
+    },
+    /// `DictComp(expr key, expr value, comprehension* generators)`
+    DictComp {
+        key: Box<Self>,
+        value: Box<Self>,
+        generators: Vec<Comprehension<'input>>,
+        span: Span,
+    },
+    /// `GeneratorExp(expr elt, comprehension* generators)`
+    GeneratorExp {
+        elt: Box<Self>,
+        generators: Vec<Comprehension<'input>>,
+        span: Span,
+    },
+    /// `Await(expr value)`
+    Await {
+        value: Box<Self>,
+        span: Span,
+    },
+    /// `Yield(expr? value)`
+    Yield {
+        value: Option<Box<Self>>,
+        span: Span,
+    },
+    /// `YieldFrom(expr value)`
+    YieldFrom {
+        value: Box<Self>,
+        span: Span,
+    },
+    /// `Starred(expr value, expr_context ctx)`
+    Starred {
+        value: Box<Self>,
+        span: Span,
+    },
+    /// `JoinedStr(expr* values)`, i.e. f-string
+    JoinedStr {
+        values: Vec<Self>,
+        span: Span,
+    },
+    /// `FormattedValue(expr value, int conversion, expr? format_spec)`
+    ///
+    /// `conversion` is `s`, `r` or `a` of `!r`,
+    /// and `format_spec` is a [Expr::JoinedStr] following `:`.
+    FormattedValue {
+        value: Box<Self>,
+        conversion: Option<char>,
+        format_spec: Option<Box<Self>>,
+        span: Span,
+    },
+    /// `Slice(expr? lower, expr? upper, expr? step)`
+    Slice {
+        lower: Option<Box<Self>>,
+        upper: Option<Box<Self>>,
+        step: Option<Box<Self>>,
+        span: Span,
+    },
+}
+
+impl Expr<'_> {
+    /// Where the expression is parsed from, or the default for ones converted by [crate::ast]
+    pub fn span(&self) -> Span {
+        match self {
+            Expr::Name { span, .. }
+            | Expr::Constant { span, .. }
+            | Expr::Tuple { span, .. }
+            | Expr::Compare { span, .. }
+            | Expr::Attribute { span, .. }
+            | Expr::Call { span, .. }
+            | Expr::Subscript { span, .. }
+            | Expr::BinOp { span, .. }
+            | Expr::List { span, .. }
+            | Expr::None { span }
+            | Expr::Ellipsis { span }
+            | Expr::BoolOp { span, .. }
+            | Expr::NamedExpr { span, .. }
+            | Expr::UnaryOp { span, .. }
+            | Expr::Lambda { span, .. }
+            | Expr::IfExp { span, .. }
+            | Expr::Dict { span, .. }
+            | Expr::Set { span, .. }
+            | Expr::ListComp { span, .. }
+            | Expr::SetComp { span, .. }
+            | Expr::DictComp { span, .. }
+            | Expr::GeneratorExp { span, .. }
+            | Expr::Await { span, .. }
+            | Expr::Yield { span, .. }
+            | Expr::YieldFrom { span, .. }
+            | Expr::Starred { span, .. }
+            | Expr::JoinedStr { span, .. }
+            | Expr::FormattedValue { span, .. }
+            | Expr::Slice { span, .. } => *span,
+        }
+    }
+}
+
+/// Keywords which cannot be used as names
+pub const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+fn error(input: Input<'_>) -> nom::Err<Error<Input<'_>>> {
+    nom::Err::Error(Error::new(input, ErrorKind::Tag))
+}
+
+/// Keyword `word` not followed by a character of identifiers, e.g. `in` but not `int`
+pub fn reserved(word: &'static str) -> impl Fn(Input) -> ParseResult<'_, Input<'_>> {
+    move |input| {
+        let (rest, matched) = tag(word)(input)?;
+        if rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+            return Err(error(input));
+        }
+        Ok((rest, matched))
+    }
+}
+
+/// Identifier which is not a keyword
+pub fn name(input: Input<'_>) -> ParseResult<'_, Cow<'_, str>> {
+    let (rest, id) = identifier(input)?;
+    if KEYWORDS.contains(&id.as_ref()) {
+        return Err(error(input));
+    }
+    Ok((rest, id))
+}
+
+/// [name] as [Expr::Name]
+fn name_expr(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    spanned(name, |id, span| Expr::Name { id, span }).parse(input)
+}
+
+/// `expression: disjunction 'if' disjunction 'else' expression | disjunction | lambdef`
+pub fn expr(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    if let Ok(lambda) = lambda(input) {
+        return Ok(lambda);
+    }
+    let (input, body) = disjunction(input)?;
+    let mut if_else = tuple((
+        ws,
+        reserved("if"),
+        ws,
+        disjunction,
+        ws,
+        reserved("else"),
+        ws,
+        expr,
+    ));
+    if let Ok((input, (_sp1, _if, _sp2, test, _sp3, _else, _sp4, orelse))) = if_else.parse(input) {
+        return Ok((
+            input,
+            Expr::IfExp {
+                test: Box::new(test),
+                body: Box::new(body),
+                orelse: Box::new(orelse),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    Ok((input, body))
+}
+
+/// `named_expression: NAME ':=' expression | expression`
+pub fn named_expr(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let mut walrus = tuple((name_expr, ws, tag(":="), ws, expr));
+    if let Ok((input, (target, _sp1, _walrus, _sp2, value))) = walrus.parse(input) {
+        return Ok((
+            input,
+            Expr::NamedExpr {
+                target: Box::new(target),
+                value: Box::new(value),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    expr(input)
+}
+
+/// `'*' bitwise_or`
+fn starred(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    spanned(
+        tuple((char('*'), ws, bitwise_or)),
+        |(_star, _sp, value), span| Expr::Starred {
+            value: Box::new(value),
+            span,
+        },
+    )
+    .parse(input)
+}
+
+/// Element of tuples, lists and sets, e.g. `x`, `*xs` or `(y := 1)`
+fn star_named_expr(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    alt((starred, named_expr)).parse(input)
+}
+
+/// Remaining comma-separated elements after the first one, and whether a trailing comma follows
+fn rest_elements<'input, O>(
+    input: Input<'input>,
+    element: impl Parser<Input<'input>, O, Error<Input<'input>>>,
+) -> ParseResult<'input, (Vec<O>, bool)> {
+    let (input, rest) = many0(preceded(tuple((ws, char(','), ws)), element)).parse(input)?;
+    let (input, trailing) = opt(tuple((ws, char(',')))).parse(input)?;
+    Ok((input, (rest, trailing.is_some())))
+}
+
+/// Elements as a tuple without parentheses, e.g. `1, 2` in `x = 1, 2`
+///
+/// A single element without trailing comma is returned as is.
+pub fn star_expressions(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, first) = alt((starred, expr)).parse(input)?;
+    let (input, (rest, trailing)) = rest_elements(input, alt((starred, expr)))?;
+    if rest.is_empty() && !trailing {
+        return Ok((input, first));
+    }
+    let mut elts = vec![first];
+    elts.extend(rest);
+    Ok((
+        input,
+        Expr::Tuple {
+            elts,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+/// `yield` or `yield from` expression
+pub fn yield_expr(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, _yield) = reserved("yield")(input)?;
+    let mut yield_from = tuple((ws1, reserved("from"), ws, expr));
+    if let Ok((input, (_sp1, _from, _sp2, value))) = yield_from.parse(input) {
+        return Ok((
+            input,
+            Expr::YieldFrom {
+                value: Box::new(value),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    let (input, value) = opt(preceded(ws, star_expressions)).parse(input)?;
+    Ok((
+        input,
+        Expr::Yield {
+            value: value.map(Box::new),
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+fn lambda(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, _lambda) = reserved("lambda")(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, params) = separated_list0(tuple((ws, char(','), ws)), lambda_param).parse(input)?;
+    let (input, _trailing) = opt(tuple((ws, char(',')))).parse(input)?;
+    let (input, _colon) = tuple((ws, char(':'), ws)).parse(input)?;
+    let (input, body) = expr(input)?;
+    Ok((
+        input,
+        Expr::Lambda {
+            args: Box::new(params.into()),
+            body: Box::new(body),
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+/// Parameter of `lambda`, which cannot be annotated
+fn lambda_param(input: Input<'_>) -> ParseResult<'_, ArgLike<'_>> {
+    let param = |input| -> ParseResult<'_, super::Arg<'_>> {
+        let (rest, name) = name(input)?;
+        let (rest, default) = opt(preceded(tuple((ws, char('='), ws)), expr)).parse(rest)?;
+        Ok((
+            rest,
+            super::Arg {
+                name,
+                ty: None,
+                default,
+                type_comment: None,
+                span: input.span_to(&rest),
+            },
+        ))
+    };
+    alt((
+        char('/').map(|_| ArgLike::PositionalSep),
+        preceded(tuple((tag("**"), ws)), param).map(ArgLike::KwArg),
+        preceded(tuple((char('*'), ws)), param).map(ArgLike::VarArg),
+        char('*').map(|_| ArgLike::KeywordSep),
+        param.map(ArgLike::Arg),
     ))
-    .parse(input)?;
+    .parse(input)
+}
+
+/// Boolean operator
+///
+/// ```text
+/// boolop = And | Or
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum BoolOperator {
+    And,
+    Or,
+}
+
+/// Operands joined by `and` or `or`, e.g. `a or b or c` into `BoolOp(Or, [a, b, c])`
+fn bool_op<'input>(
+    input: Input<'input>,
+    operand: fn(Input) -> ParseResult<'_, Expr<'_>>,
+    op: BoolOperator,
+) -> ParseResult<'input, Expr<'input>> {
+    let word = match op {
+        BoolOperator::And => "and",
+        BoolOperator::Or => "or",
+    };
+    let start = input;
+    let (input, first) = operand(input)?;
+    let (input, rest) = many0(preceded(tuple((ws, reserved(word), ws)), operand)).parse(input)?;
+    if rest.is_empty() {
+        return Ok((input, first));
+    }
+    let mut values = vec![first];
+    values.extend(rest);
+    Ok((
+        input,
+        Expr::BoolOp {
+            op,
+            values,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+fn disjunction(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    bool_op(input, conjunction, BoolOperator::Or)
+}
+
+fn conjunction(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    bool_op(input, inversion, BoolOperator::And)
+}
+
+fn inversion(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let mut not_ = tuple((reserved("not"), ws, inversion));
+    if let Ok((input, (_not, _sp, operand))) = not_.parse(input) {
+        return Ok((
+            input,
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                operand: Box::new(operand),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    comparison(input)
+}
+
+fn comparison(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, left) = bitwise_or(input)?;
+    let (input, pairs) =
+        many0(tuple((ws, cmpop, ws, bitwise_or)).map(|(_sp1, op, _sp2, e)| (op, e)))
+            .parse(input)?;
+    if pairs.is_empty() {
+        return Ok((input, left));
+    }
+    let (ops, comparators) = pairs.into_iter().unzip();
+    Ok((
+        input,
+        Expr::Compare {
+            left: Box::new(left),
+            ops,
+            comparators,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+/// Binary operator
+///
+/// ```text
+/// operator = Add | Sub | Mult | MatMult | Div | Mod | Pow | LShift
+///          | RShift | BitOr | BitXor | BitAnd | FloorDiv
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mult,
+    MatMult,
+    Div,
+    Mod,
+    Pow,
+    LShift,
+    RShift,
+    BitOr,
+    BitXor,
+    BitAnd,
+    FloorDiv,
+}
+
+/// Token of a binary operator, the longest one is taken, e.g. `**` instead of `*`
+pub fn operator(input: Input<'_>) -> ParseResult<'_, Operator> {
+    alt((
+        tag("**").map(|_| Operator::Pow),
+        tag("//").map(|_| Operator::FloorDiv),
+        tag("<<").map(|_| Operator::LShift),
+        tag(">>").map(|_| Operator::RShift),
+        char('+').map(|_| Operator::Add),
+        char('-').map(|_| Operator::Sub),
+        char('*').map(|_| Operator::Mult),
+        char('@').map(|_| Operator::MatMult),
+        char('/').map(|_| Operator::Div),
+        char('%').map(|_| Operator::Mod),
+        char('|').map(|_| Operator::BitOr),
+        char('^').map(|_| Operator::BitXor),
+        char('&').map(|_| Operator::BitAnd),
+    ))
+    .parse(input)
+}
+
+/// Left-associative binary operation of one of `ops`, e.g. `a - b - c` into `(a - b) - c`
+fn binary<'input>(
+    input: Input<'input>,
+    operand: fn(Input) -> ParseResult<'_, Expr<'_>>,
+    ops: &[Operator],
+) -> ParseResult<'input, Expr<'input>> {
+    let start = input;
+    let (mut input, mut left) = operand(input)?;
+    while let Ok((rest, (_sp1, op, _sp2, right))) = tuple((ws, operator, ws, operand)).parse(input)
+    {
+        if !ops.contains(&op) {
+            break;
+        }
+        input = rest;
+        left = Expr::BinOp {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+            span: start.span_to(&input),
+        };
+    }
+    Ok((input, left))
+}
+
+fn bitwise_or(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    binary(input, bitwise_xor, &[Operator::BitOr])
+}
+
+fn bitwise_xor(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    binary(input, bitwise_and, &[Operator::BitXor])
+}
+
+fn bitwise_and(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    binary(input, shift_expr, &[Operator::BitAnd])
+}
+
+fn shift_expr(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    binary(input, sum, &[Operator::LShift, Operator::RShift])
+}
+
+fn sum(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    binary(input, term, &[Operator::Add, Operator::Sub])
+}
+
+fn term(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    binary(
+        input,
+        factor,
+        &[
+            Operator::Mult,
+            Operator::Div,
+            Operator::FloorDiv,
+            Operator::Mod,
+            Operator::MatMult,
+        ],
+    )
+}
+
+/// Unary operator
+///
+/// ```text
+/// unaryop = Invert | Not | UAdd | USub
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum UnaryOperator {
+    Invert,
+    Not,
+    UAdd,
+    USub,
+}
+
+fn factor(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let op = alt((
+        char('+').map(|_| UnaryOperator::UAdd),
+        char('-').map(|_| UnaryOperator::USub),
+        char('~').map(|_| UnaryOperator::Invert),
+    ));
+    if let Ok((input, (op, _sp, operand))) = tuple((op, ws, factor)).parse(input) {
+        return Ok((
+            input,
+            Expr::UnaryOp {
+                op,
+                operand: Box::new(operand),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    power(input)
+}
+
+/// `**` binds tighter than unary operators on its left, e.g. `-x ** y` is `-(x ** y)`
+fn power(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, base) = await_primary(input)?;
+    let mut exponent = tuple((ws, tag("**"), ws, factor));
+    if let Ok((input, (_sp1, _pow, _sp2, exponent))) = exponent.parse(input) {
+        return Ok((
+            input,
+            Expr::BinOp {
+                left: Box::new(base),
+                op: Operator::Pow,
+                right: Box::new(exponent),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    Ok((input, base))
+}
+
+fn await_primary(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let mut await_ = tuple((reserved("await"), ws, primary));
+    if let Ok((input, (_await, _sp, value))) = await_.parse(input) {
+        return Ok((
+            input,
+            Expr::Await {
+                value: Box::new(value),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    primary(input)
+}
+
+/// Atom followed by attributes, subscripts and calls, e.g. `f(1).g[0]`
+fn primary(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (mut input, mut e) = atom(input)?;
     loop {
-        let (input_new, comparators) = opt(tuple((multispace0, cmpop, multispace0, expr))
-            .map(|(_sp1, ops, _sp2, comparators)| (ops, comparators)))
-        .parse(input)?;
-        if let Some((ops, comparators)) = comparators {
-            input = input_new;
-            e = Expr::Compare {
-                left: Box::new(e),
-                ops,
-                comparators: Box::new(comparators),
+        let mut attribute = preceded(tuple((ws, char('.'), ws)), identifier);
+        if let Ok((rest, attr)) = attribute.parse(input) {
+            input = rest;
+            e = Expr::Attribute {
+                value: Box::new(e),
+                attr,
+                span: start.span_to(&input),
             };
             continue;
         }
 
-        let (input_new, attr) = opt(tuple((multispace0, char('.'), multispace0, identifier))
-            .map(|(_sp1, _dot, _sp2, attr)| attr))
-        .parse(input)?;
-        if let Some(attr) = attr {
-            input = input_new;
-            e = Expr::Attribute {
+        if let Ok((rest, slice)) = preceded(ws, subscript_slice).parse(input) {
+            input = rest;
+            e = Expr::Subscript {
                 value: Box::new(e),
-                attr,
+                slice: Box::new(slice),
+                span: start.span_to(&input),
             };
             continue;
         }
 
-        let (input_new, call_args) =
-            opt(tuple((multispace0, function_args)).map(|(_sp, args)| args)).parse(input)?;
-        if let Some((args, keywords)) = call_args {
-            input = input_new;
+        if let Ok((rest, (args, keywords))) = preceded(ws, function_args).parse(input) {
+            input = rest;
             e = Expr::Call {
                 func: Box::new(e),
                 args,
                 keywords,
+                span: start.span_to(&input),
             };
             continue;
         }
@@ -98,12 +723,265 @@ pub fn expr(input: &str) -> ParseResult<Expr> {
     }
 }
 
+fn atom(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    alt((
+        spanned(reserved("None"), |_, span| Expr::None { span }),
+        // `True` and `False` are kept as names
+        spanned(alt((reserved("True"), reserved("False"))), |id, span| {
+            Expr::Name {
+                id: id.fragment().into(),
+                span,
+            }
+        }),
+        spanned(tag("..."), |_, span| Expr::Ellipsis { span }),
+        // before names not to take prefixes such as `f` in `f"..."` as names
+        strings,
+        name_expr,
+        spanned(number, |value, span| Expr::Constant { value, span }),
+        group,
+        list_display,
+        dict_or_set,
+    ))
+    .parse(input)
+}
+
+/// `(...)`, i.e. a parenthesized expression, a tuple, a generator expression or `yield`
+fn group(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, _open) = tuple((char('('), ws)).parse(input)?;
+    let close = |input| tuple((ws, char(')'))).parse(input);
+    if let Ok((input, _close)) = close(input) {
+        return Ok((
+            input,
+            Expr::Tuple {
+                elts: Vec::new(),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    if let Ok((input, e)) = terminated(yield_expr, close).parse(input) {
+        return Ok((input, e));
+    }
+    let (input, first) = star_named_expr(input)?;
+    if let Ok((input, generators)) = terminated(comprehensions, close).parse(input) {
+        return Ok((
+            input,
+            Expr::GeneratorExp {
+                elt: Box::new(first),
+                generators,
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    let (input, (rest, trailing)) = rest_elements(input, star_named_expr)?;
+    let (input, _close) = close(input)?;
+    if rest.is_empty() && !trailing {
+        return Ok((input, first));
+    }
+    let mut elts = vec![first];
+    elts.extend(rest);
+    Ok((
+        input,
+        Expr::Tuple {
+            elts,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+/// `[...]`, i.e. a list or a list comprehension
+fn list_display(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, _open) = tuple((char('['), ws)).parse(input)?;
+    let close = |input| tuple((ws, char(']'))).parse(input);
+    if let Ok((input, _close)) = close(input) {
+        return Ok((
+            input,
+            Expr::List {
+                elts: Vec::new(),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    let (input, first) = star_named_expr(input)?;
+    if let Ok((input, generators)) = terminated(comprehensions, close).parse(input) {
+        return Ok((
+            input,
+            Expr::ListComp {
+                elt: Box::new(first),
+                generators,
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    let (input, (rest, _trailing)) = rest_elements(input, star_named_expr)?;
+    let (input, _close) = close(input)?;
+    let mut elts = vec![first];
+    elts.extend(rest);
+    Ok((
+        input,
+        Expr::List {
+            elts,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+/// `key: value` or `**mapping` in a dict display
+fn dict_item(input: Input<'_>) -> ParseResult<'_, (Option<Expr<'_>>, Expr<'_>)> {
+    alt((
+        preceded(tuple((tag("**"), ws)), bitwise_or).map(|value| (None, value)),
+        tuple((expr, ws, char(':'), ws, expr))
+            .map(|(key, _sp1, _colon, _sp2, value)| (Some(key), value)),
+    ))
+    .parse(input)
+}
+
+/// `{...}`, i.e. a dict, a set or their comprehension
+fn dict_or_set(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let (input, _open) = tuple((char('{'), ws)).parse(input)?;
+    let close = |input| tuple((ws, char('}'))).parse(input);
+    if let Ok((input, _close)) = close(input) {
+        return Ok((
+            input,
+            Expr::Dict {
+                keys: Vec::new(),
+                values: Vec::new(),
+                span: start.span_to(&input),
+            },
+        ));
+    }
+
+    if let Ok((input, (key, value))) = dict_item(input) {
+        if let Some(key) = &key {
+            if let Ok((input, generators)) = terminated(comprehensions, close).parse(input) {
+                return Ok((
+                    input,
+                    Expr::DictComp {
+                        key: Box::new(key.clone()),
+                        value: Box::new(value),
+                        generators,
+                        span: start.span_to(&input),
+                    },
+                ));
+            }
+        }
+        let (input, (rest, _trailing)) = rest_elements(input, dict_item)?;
+        let (input, _close) = close(input)?;
+        let (keys, values) = std::iter::once((key, value)).chain(rest).unzip();
+        return Ok((
+            input,
+            Expr::Dict {
+                keys,
+                values,
+                span: start.span_to(&input),
+            },
+        ));
+    }
+
+    let (input, first) = star_named_expr(input)?;
+    if let Ok((input, generators)) = terminated(comprehensions, close).parse(input) {
+        return Ok((
+            input,
+            Expr::SetComp {
+                elt: Box::new(first),
+                generators,
+                span: start.span_to(&input),
+            },
+        ));
+    }
+    let (input, (rest, _trailing)) = rest_elements(input, star_named_expr)?;
+    let (input, _close) = close(input)?;
+    let mut elts = vec![first];
+    elts.extend(rest);
+    Ok((
+        input,
+        Expr::Set {
+            elts,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
+/// `comprehension = (expr target, expr iter, expr* ifs, int is_async)`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Comprehension<'input> {
+    pub(crate) target: Expr<'input>,
+    pub(crate) iter: Expr<'input>,
+    pub(crate) ifs: Vec<Expr<'input>>,
+    pub(crate) is_async: bool,
+}
+
+impl<'input> Comprehension<'input> {
+    pub fn target(&self) -> &Expr<'input> {
+        &self.target
+    }
+
+    pub fn iter(&self) -> &Expr<'input> {
+        &self.iter
+    }
+
+    pub fn ifs(&self) -> &[Expr<'input>] {
+        &self.ifs
+    }
+
+    /// `async for`
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+}
+
+/// `for` clauses following the element of a comprehension, e.g. ` for x in xs if x`
+pub fn comprehensions(input: Input<'_>) -> ParseResult<'_, Vec<Comprehension<'_>>> {
+    many1(comprehension).parse(input)
+}
+
+fn comprehension(input: Input<'_>) -> ParseResult<'_, Comprehension<'_>> {
+    let (input, is_async) = opt(tuple((ws, reserved("async"), ws1))).parse(input)?;
+    let (input, _for) = tuple((ws, reserved("for"), ws)).parse(input)?;
+    let (input, target) = star_targets(input)?;
+    let (input, _in) = tuple((ws, reserved("in"), ws)).parse(input)?;
+    let (input, iter) = disjunction(input)?;
+    let (input, ifs) =
+        many0(preceded(tuple((ws, reserved("if"), ws)), disjunction)).parse(input)?;
+    Ok((
+        input,
+        Comprehension {
+            target,
+            iter,
+            ifs,
+            is_async: is_async.is_some(),
+        },
+    ))
+}
+
+/// Targets of `for`, e.g. `i, (x, y)`
+pub fn star_targets(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    let target = |input| alt((starred, bitwise_or)).parse(input);
+    let (input, first) = target(input)?;
+    let (input, (rest, trailing)) = rest_elements(input, target)?;
+    if rest.is_empty() && !trailing {
+        return Ok((input, first));
+    }
+    let mut elts = vec![first];
+    elts.extend(rest);
+    Ok((
+        input,
+        Expr::Tuple {
+            elts,
+            span: start.span_to(&input),
+        },
+    ))
+}
+
 /// Comparison operator
 ///
 /// ```text
 /// cmpop = Eq | NotEq | Lt | LtE | Gt | GtE | Is | IsNot | In | NotIn
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum CmpOp {
     Eq,
     NotEq,
@@ -117,64 +995,108 @@ pub enum CmpOp {
     NotIn,
 }
 
-pub fn cmpop(input: &str) -> ParseResult<CmpOp> {
+pub fn cmpop(input: Input<'_>) -> ParseResult<'_, CmpOp> {
     alt((
-        tuple((tag("is"), multispace1, tag("not"))).map(|_| CmpOp::IsNot),
-        tag("is").map(|_| CmpOp::Is),
-        tuple((tag("not"), multispace1, tag("in"))).map(|_| CmpOp::NotIn),
-        tag("in").map(|_| CmpOp::In),
+        tuple((reserved("is"), ws1, reserved("not"))).map(|_| CmpOp::IsNot),
+        reserved("is").map(|_| CmpOp::Is),
+        tuple((reserved("not"), ws1, reserved("in"))).map(|_| CmpOp::NotIn),
+        reserved("in").map(|_| CmpOp::In),
         tag("<=").map(|_| CmpOp::LtE),
-        tag("<").map(|_| CmpOp::Lt),
+        terminated(tag("<"), not(char('<'))).map(|_| CmpOp::Lt),
         tag(">=").map(|_| CmpOp::GtE),
-        tag(">").map(|_| CmpOp::Gt),
+        terminated(tag(">"), not(char('>'))).map(|_| CmpOp::Gt),
         tag("!=").map(|_| CmpOp::NotEq),
         tag("==").map(|_| CmpOp::Eq),
     ))
     .parse(input)
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+/// Value of `Constant(constant value, string? kind)`, see [number] and [strings]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum Constant<'input> {
-    String(&'input str),
+    String(Cow<'input, str>),
+    Bytes(Vec<u8>),
     Float(f64),
     Int(i64),
+    /// Integer which does not fit in [i64], in decimal digits
+    BigInt(String),
+    Complex {
+        real: f64,
+        imag: f64,
+    },
 }
 
-pub fn constant(input: &str) -> ParseResult<Constant> {
-    alt((double.map(Constant::Float), string.map(Constant::String))).parse(input)
+/// `[...]` following a value, e.g. `[int]` in `list[int]` or `[str, int]` in `dict[str, int]`
+///
+/// Multiple elements are returned as an [Expr::Tuple] as `ast.parse` does.
+fn subscript_slice(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let (start, _open) = tuple((char('['), ws)).parse(input)?;
+    let (input, first) = slice(start)?;
+    let (input, (rest, trailing)) = rest_elements(input, slice)?;
+    let span = start.span_to(&input);
+    let (input, _close) = tuple((ws, char(']'))).parse(input)?;
+    if rest.is_empty() && !trailing {
+        return Ok((input, first));
+    }
+    let mut elts = vec![first];
+    elts.extend(rest);
+    Ok((input, Expr::Tuple { elts, span }))
 }
 
-pub fn expr_tuple(input: &str) -> ParseResult<Vec<Expr>> {
-    let (input, _open) = char('(').parse(input)?;
-    let (input, _sp) = multispace0(input)?;
-
-    let (input, inner) =
-        separated_list0(tuple((multispace0, char(','), multispace0)), expr).parse(input)?;
-
-    let (input, _sp) = multispace0(input)?;
-    let (input, _close) = char(')').parse(input)?;
-    Ok((input, inner))
+/// Element of subscripts, e.g. `1:2`, `::2` or `*Ts`
+fn slice(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let start = input;
+    if let Ok((input, e)) = alt((starred, named_expr)).parse(input) {
+        if !input.trim_start().starts_with(':') {
+            return Ok((input, e));
+        }
+    }
+    let (input, lower) = opt(expr).parse(input)?;
+    let (input, _colon) = tuple((ws, char(':'), ws)).parse(input)?;
+    let (input, upper) = opt(expr).parse(input)?;
+    let (input, step) = opt(preceded(tuple((ws, char(':'), ws)), opt(expr))).parse(input)?;
+    Ok((
+        input,
+        Expr::Slice {
+            lower: lower.map(Box::new),
+            upper: upper.map(Box::new),
+            step: step.flatten().map(Box::new),
+            span: start.span_to(&input),
+        },
+    ))
 }
 
 /// `keyword = (identifier? arg, expr value)`
 ///
 /// keyword arguments supplied to call (NULL identifier for `**kwargs`)
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Keyword<'input> {
-    arg: Option<&'input str>,
-    value: Expr<'input>,
+    pub(crate) arg: Option<Cow<'input, str>>,
+    pub(crate) value: Expr<'input>,
+}
+
+impl<'input> Keyword<'input> {
+    /// Name of the argument, or `None` for `**kwargs`
+    pub fn arg(&self) -> Option<&str> {
+        self.arg.as_deref()
+    }
+
+    pub fn value(&self) -> &Expr<'input> {
+        &self.value
+    }
 }
 
-pub fn keyword(input: &str) -> ParseResult<Keyword> {
+pub fn keyword(input: Input<'_>) -> ParseResult<'_, Keyword<'_>> {
     let (input, star) = opt(tag("**")).parse(input)?;
 
     if let Some(_star) = star {
+        let (input, _sp) = ws(input)?;
         let (input, value) = expr(input)?;
         return Ok((input, Keyword { arg: None, value }));
     }
 
-    let (input, arg) = identifier(input)?;
-    let (input, _) = tuple((multispace0, char('='), multispace0)).parse(input)?;
+    let (input, arg) = name(input)?;
+    let (input, _) = tuple((ws, char('='), not(char('=')), ws)).parse(input)?;
     let (input, value) = expr(input)?;
 
     Ok((
@@ -191,19 +1113,35 @@ enum Arg<'input> {
     Positional(Expr<'input>),
 }
 
-fn arg(input: &str) -> ParseResult<Arg> {
-    alt((keyword.map(Arg::Keyword), expr.map(Arg::Positional))).parse(input)
+fn arg(input: Input<'_>) -> ParseResult<'_, Arg<'_>> {
+    alt((
+        keyword.map(Arg::Keyword),
+        starred.map(Arg::Positional),
+        named_expr.map(Arg::Positional),
+    ))
+    .parse(input)
 }
 
-pub fn function_args(input: &str) -> ParseResult<(Vec<Expr>, Vec<Keyword>)> {
+pub fn function_args(input: Input<'_>) -> ParseResult<'_, (Vec<Expr<'_>>, Vec<Keyword<'_>>)> {
+    let start = input;
     let (input, _open) = char('(').parse(input)?;
-    let (input, _sp) = multispace0(input)?;
+    let (input, _sp) = ws(input)?;
+    let close = |input| tuple((ws, char(')'))).parse(input);
 
-    let (input, inner) =
-        separated_list0(tuple((multispace0, char(','), multispace0)), arg).parse(input)?;
+    // Generator expression as the only argument, e.g. `f(x for x in xs)`
+    let mut genexp = tuple((named_expr, comprehensions, close));
+    if let Ok((input, (elt, generators, _close))) = genexp.parse(input) {
+        let genexp = Expr::GeneratorExp {
+            elt: Box::new(elt),
+            generators,
+            span: start.span_to(&input),
+        };
+        return Ok((input, (vec![genexp], Vec::new())));
+    }
 
-    let (input, _sp) = multispace0(input)?;
-    let (input, _close) = char(')').parse(input)?;
+    let (input, inner) = separated_list0(tuple((ws, char(','), ws)), arg).parse(input)?;
+    let (input, _trailing) = opt(tuple((ws, char(',')))).parse(input)?;
+    let (input, _close) = close(input)?;
 
     let mut positional = Vec::new();
     let mut keywords = Vec::new();
@@ -224,24 +1162,27 @@ mod test {
 
     #[test]
     fn test_keyword() {
-        let (res, out) = keyword("a=None").finish().unwrap();
+        let (res, out) = keyword(Input::new("a=None")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Keyword {
             arg: Some(
                 "a",
             ),
-            value: None,
+            value: None {
+                span: 2..6,
+            },
         }
         "###);
 
-        let (res, out) = keyword("**kwargs").finish().unwrap();
+        let (res, out) = keyword(Input::new("**kwargs")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Keyword {
             arg: None,
             value: Name {
                 id: "kwargs",
+                span: 2..8,
             },
         }
         "###);
@@ -249,160 +1190,194 @@ mod test {
 
     #[test]
     fn test_cmpop() {
-        assert_eq!(cmpop(">").finish().unwrap(), ("", CmpOp::Gt));
-        assert_eq!(cmpop(">=").finish().unwrap(), ("", CmpOp::GtE));
-        assert_eq!(cmpop("<").finish().unwrap(), ("", CmpOp::Lt));
-        assert_eq!(cmpop("<=").finish().unwrap(), ("", CmpOp::LtE));
-        assert_eq!(cmpop("==").finish().unwrap(), ("", CmpOp::Eq));
-        assert_eq!(cmpop("!=").finish().unwrap(), ("", CmpOp::NotEq));
-        assert_eq!(cmpop("is").finish().unwrap(), ("", CmpOp::Is));
-        assert_eq!(cmpop("is not").finish().unwrap(), ("", CmpOp::IsNot));
-        assert_eq!(cmpop("in").finish().unwrap(), ("", CmpOp::In));
-        assert_eq!(cmpop("not in").finish().unwrap(), ("", CmpOp::NotIn));
+        let cmpop = |input| {
+            let (rest, op) = cmpop(Input::new(input)).finish().unwrap();
+            (rest.fragment(), op)
+        };
+        assert_eq!(cmpop(">"), ("", CmpOp::Gt));
+        assert_eq!(cmpop(">="), ("", CmpOp::GtE));
+        assert_eq!(cmpop("<"), ("", CmpOp::Lt));
+        assert_eq!(cmpop("<="), ("", CmpOp::LtE));
+        assert_eq!(cmpop("=="), ("", CmpOp::Eq));
+        assert_eq!(cmpop("!="), ("", CmpOp::NotEq));
+        assert_eq!(cmpop("is"), ("", CmpOp::Is));
+        assert_eq!(cmpop("is not"), ("", CmpOp::IsNot));
+        assert_eq!(cmpop("in"), ("", CmpOp::In));
+        assert_eq!(cmpop("not in"), ("", CmpOp::NotIn));
     }
 
     #[test]
     fn test_expr() {
         // Name
-        let (res, out) = expr("a").finish().unwrap();
+        let (res, out) = expr(Input::new("a")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Name {
             id: "a",
+            span: 0..1,
         }
         "###);
 
         // Attribute
-        let (res, out) = expr("m.a.b").finish().unwrap();
+        let (res, out) = expr(Input::new("m.a.b")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Attribute {
             value: Attribute {
                 value: Name {
                     id: "m",
+                    span: 0..1,
                 },
                 attr: "a",
+                span: 0..3,
             },
             attr: "b",
+            span: 0..5,
         }
         "###);
 
         // Constant
-        let (res, out) = expr("1.0").finish().unwrap();
+        let (res, out) = expr(Input::new("1.0")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Constant {
             value: Float(
                 1.0,
             ),
+            span: 0..3,
         }
         "###);
 
         // Tuples
-        let (res, out) = expr("()").finish().unwrap();
+        let (res, out) = expr(Input::new("()")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Tuple {
             elts: [],
+            span: 0..2,
         }
         "###);
-        let (res, out) = expr("(a, 1.0)").finish().unwrap();
+        let (res, out) = expr(Input::new("(a, 1.0)")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Tuple {
             elts: [
                 Name {
                     id: "a",
+                    span: 1..2,
                 },
                 Constant {
                     value: Float(
                         1.0,
                     ),
+                    span: 4..7,
                 },
             ],
+            span: 0..8,
         }
         "###);
 
         // Compare
-        let (res, out) = expr("a < b").finish().unwrap();
+        let (res, out) = expr(Input::new("a < b")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Compare {
             left: Name {
                 id: "a",
+                span: 0..1,
             },
-            ops: Lt,
-            comparators: Name {
-                id: "b",
-            },
+            ops: [
+                Lt,
+            ],
+            comparators: [
+                Name {
+                    id: "b",
+                    span: 4..5,
+                },
+            ],
+            span: 0..5,
         }
         "###);
-        let (res, out) = expr("a < b < c").finish().unwrap();
+        let (res, out) = expr(Input::new("a < b < c")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Compare {
             left: Name {
                 id: "a",
+                span: 0..1,
             },
-            ops: Lt,
-            comparators: Compare {
-                left: Name {
+            ops: [
+                Lt,
+                Lt,
+            ],
+            comparators: [
+                Name {
                     id: "b",
+                    span: 4..5,
                 },
-                ops: Lt,
-                comparators: Name {
+                Name {
                     id: "c",
+                    span: 8..9,
                 },
-            },
+            ],
+            span: 0..9,
         }
         "###);
 
         // Call
-        let (res, out) = expr("f()").finish().unwrap();
+        let (res, out) = expr(Input::new("f()")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Call {
             func: Name {
                 id: "f",
+                span: 0..1,
             },
             args: [],
             keywords: [],
+            span: 0..3,
         }
         "###);
-        let (res, out) = expr("f(1, 2)").finish().unwrap();
+        let (res, out) = expr(Input::new("f(1, 2)")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Call {
             func: Name {
                 id: "f",
+                span: 0..1,
             },
             args: [
                 Constant {
-                    value: Float(
-                        1.0,
+                    value: Int(
+                        1,
                     ),
+                    span: 2..3,
                 },
                 Constant {
-                    value: Float(
-                        2.0,
+                    value: Int(
+                        2,
                     ),
+                    span: 5..6,
                 },
             ],
             keywords: [],
+            span: 0..7,
         }
         "###);
-        let (res, out) = expr("f(1, a = 2)").finish().unwrap();
+        let (res, out) = expr(Input::new("f(1, a = 2)")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Call {
             func: Name {
                 id: "f",
+                span: 0..1,
             },
             args: [
                 Constant {
-                    value: Float(
-                        1.0,
+                    value: Int(
+                        1,
                     ),
+                    span: 2..3,
                 },
             ],
             keywords: [
@@ -411,48 +1386,57 @@ mod test {
                         "a",
                     ),
                     value: Constant {
-                        value: Float(
-                            2.0,
+                        value: Int(
+                            2,
                         ),
+                        span: 9..10,
                     },
                 },
             ],
+            span: 0..11,
         }
         "###);
-        let (res, out) = expr(r#"f(1, "test")"#).finish().unwrap();
+        let (res, out) = expr(Input::new(r#"f(1, "test")"#)).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Call {
             func: Name {
                 id: "f",
+                span: 0..1,
             },
             args: [
                 Constant {
-                    value: Float(
-                        1.0,
+                    value: Int(
+                        1,
                     ),
+                    span: 2..3,
                 },
                 Constant {
                     value: String(
                         "test",
                     ),
+                    span: 5..11,
                 },
             ],
             keywords: [],
+            span: 0..12,
         }
         "###);
-        let (res, out) = expr("None ()").finish().unwrap();
+        let (res, out) = expr(Input::new("None ()")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Call {
-            func: None,
+            func: None {
+                span: 0..4,
+            },
             args: [],
             keywords: [],
+            span: 0..7,
         }
         "###);
 
         // Combinations
-        let (res, out) = expr("f(1).g.h(2, 3)").finish().unwrap();
+        let (res, out) = expr(Input::new("f(1).g.h(2, 3)")).finish().unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Call {
@@ -461,60 +1445,314 @@ mod test {
                     value: Call {
                         func: Name {
                             id: "f",
+                            span: 0..1,
                         },
                         args: [
                             Constant {
-                                value: Float(
-                                    1.0,
+                                value: Int(
+                                    1,
                                 ),
+                                span: 2..3,
                             },
                         ],
                         keywords: [],
+                        span: 0..4,
                     },
                     attr: "g",
+                    span: 0..6,
                 },
                 attr: "h",
+                span: 0..8,
             },
             args: [
                 Constant {
-                    value: Float(
-                        2.0,
+                    value: Int(
+                        2,
                     ),
+                    span: 9..10,
                 },
                 Constant {
-                    value: Float(
-                        3.0,
+                    value: Int(
+                        3,
                     ),
+                    span: 12..13,
                 },
             ],
             keywords: [],
+            span: 0..14,
         }
         "###);
-        let (res, out) = expr("sys.version_info >= (3, 9)").finish().unwrap();
+        let (res, out) = expr(Input::new("sys.version_info >= (3, 9)"))
+            .finish()
+            .unwrap();
         assert_eq!(res, "");
         insta::assert_debug_snapshot!(out, @r###"
         Compare {
             left: Attribute {
                 value: Name {
                     id: "sys",
+                    span: 0..3,
                 },
                 attr: "version_info",
+                span: 0..16,
             },
-            ops: GtE,
-            comparators: Tuple {
-                elts: [
-                    Constant {
-                        value: Float(
-                            3.0,
+            ops: [
+                GtE,
+            ],
+            comparators: [
+                Tuple {
+                    elts: [
+                        Constant {
+                            value: Int(
+                                3,
+                            ),
+                            span: 21..22,
+                        },
+                        Constant {
+                            value: Int(
+                                9,
+                            ),
+                            span: 24..25,
+                        },
+                    ],
+                    span: 20..26,
+                },
+            ],
+            span: 0..26,
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_precedence() {
+        let (res, out) = expr(Input::new("a | b * -c ** d")).finish().unwrap();
+        assert_eq!(res, "");
+        insta::assert_debug_snapshot!(out, @r###"
+        BinOp {
+            left: Name {
+                id: "a",
+                span: 0..1,
+            },
+            op: BitOr,
+            right: BinOp {
+                left: Name {
+                    id: "b",
+                    span: 4..5,
+                },
+                op: Mult,
+                right: UnaryOp {
+                    op: USub,
+                    operand: BinOp {
+                        left: Name {
+                            id: "c",
+                            span: 9..10,
+                        },
+                        op: Pow,
+                        right: Name {
+                            id: "d",
+                            span: 14..15,
+                        },
+                        span: 9..15,
+                    },
+                    span: 8..15,
+                },
+                span: 4..15,
+            },
+            span: 0..15,
+        }
+        "###);
+
+        let (res, out) = expr(Input::new("not a in b and c")).finish().unwrap();
+        assert_eq!(res, "");
+        insta::assert_debug_snapshot!(out, @r###"
+        BoolOp {
+            op: And,
+            values: [
+                UnaryOp {
+                    op: Not,
+                    operand: Compare {
+                        left: Name {
+                            id: "a",
+                            span: 4..5,
+                        },
+                        ops: [
+                            In,
+                        ],
+                        comparators: [
+                            Name {
+                                id: "b",
+                                span: 9..10,
+                            },
+                        ],
+                        span: 4..10,
+                    },
+                    span: 0..10,
+                },
+                Name {
+                    id: "c",
+                    span: 15..16,
+                },
+            ],
+            span: 0..16,
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_span() {
+        let source = "(a, f(x)[1:] ** -2)";
+        let (_, e) = expr(Input::new(source)).finish().unwrap();
+        let Expr::Tuple { elts, span } = &e else {
+            panic!("{:?}", e)
+        };
+        assert_eq!(&source[span.range()], source);
+        assert_eq!(&source[elts[0].span().range()], "a");
+        let Expr::BinOp { left, right, .. } = &elts[1] else {
+            panic!("{:?}", elts[1])
+        };
+        assert_eq!(&source[elts[1].span().range()], "f(x)[1:] ** -2");
+        assert_eq!(&source[left.span().range()], "f(x)[1:]");
+        assert_eq!(&source[right.span().range()], "-2");
+    }
+
+    #[test]
+    fn test_keywords_in_names() {
+        for id in ["info", "None_", "lambda_", "format", "is_", "notes", "iff"] {
+            let (rest, e) = expr(Input::new(id)).finish().unwrap();
+            assert_eq!(rest, "");
+            assert_eq!(
+                e,
+                Expr::Name {
+                    id: id.into(),
+                    span: Span::default(),
+                }
+            );
+        }
+        assert!(expr(Input::new("in")).finish().is_err());
+        assert!(expr(Input::new("lambda")).finish().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let (res, out) = expr(Input::new("lambda x, *, y=None: x")).finish().unwrap();
+        assert_eq!(res, "");
+        insta::assert_debug_snapshot!(out, @r###"
+        Lambda {
+            args: Arguments {
+                args: [
+                    Arg {
+                        name: "x",
+                        ty: None,
+                        default: None,
+                        type_comment: None,
+                        span: 7..8,
+                    },
+                ],
+                positional_only: [],
+                keyword_only: [
+                    Arg {
+                        name: "y",
+                        ty: None,
+                        default: Some(
+                            None {
+                                span: 15..19,
+                            },
                         ),
+                        type_comment: None,
+                        span: 13..19,
+                    },
+                ],
+                var_args: None,
+                kw_args: None,
+            },
+            body: Name {
+                id: "x",
+                span: 21..22,
+            },
+            span: 0..22,
+        }
+        "###);
+
+        let (res, out) = expr(Input::new("{k: v for k, v in items if v}"))
+            .finish()
+            .unwrap();
+        assert_eq!(res, "");
+        insta::assert_debug_snapshot!(out, @r###"
+        DictComp {
+            key: Name {
+                id: "k",
+                span: 1..2,
+            },
+            value: Name {
+                id: "v",
+                span: 4..5,
+            },
+            generators: [
+                Comprehension {
+                    target: Tuple {
+                        elts: [
+                            Name {
+                                id: "k",
+                                span: 10..11,
+                            },
+                            Name {
+                                id: "v",
+                                span: 13..14,
+                            },
+                        ],
+                        span: 10..14,
+                    },
+                    iter: Name {
+                        id: "items",
+                        span: 18..23,
                     },
-                    Constant {
-                        value: Float(
-                            9.0,
+                    ifs: [
+                        Name {
+                            id: "v",
+                            span: 27..28,
+                        },
+                    ],
+                    is_async: false,
+                },
+            ],
+            span: 0..29,
+        }
+        "###);
+
+        let (res, out) = expr(Input::new("xs[1.0:, *ys]")).finish().unwrap();
+        assert_eq!(res, "");
+        insta::assert_debug_snapshot!(out, @r###"
+        Subscript {
+            value: Name {
+                id: "xs",
+                span: 0..2,
+            },
+            slice: Tuple {
+                elts: [
+                    Slice {
+                        lower: Some(
+                            Constant {
+                                value: Float(
+                                    1.0,
+                                ),
+                                span: 3..6,
+                            },
                         ),
+                        upper: None,
+                        step: None,
+                        span: 3..7,
+                    },
+                    Starred {
+                        value: Name {
+                            id: "ys",
+                            span: 10..12,
+                        },
+                        span: 9..12,
                     },
                 ],
+                span: 3..12,
             },
+            span: 0..13,
         }
         "###);
     }
@@ -0,0 +1,461 @@
+//! Syntax tree traversal taking ownership, in the style of [syn::fold](https://docs.rs/syn/latest/syn/fold/)
+//!
+//! Each method of [Fold] defaults to the free function of the same name,
+//! which rebuilds the node from its folded children in the order they appear in the source.
+//! Override a method to rewrite the node, e.g. to replace annotations.
+//!
+//! Operators, identifiers and spans are leaves, and are kept as they are.
+
+use super::{
+    Alias, Arg, Arguments, ClassDef, Comprehension, Constant, Expr, FunctionDef, Keyword, Stmt,
+};
+
+/// Transformation of a syntax tree, see the [module documentation](self)
+pub trait Fold<'input> {
+    fn fold_stmts(&mut self, i: Vec<Stmt<'input>>) -> Vec<Stmt<'input>> {
+        fold_stmts(self, i)
+    }
+    fn fold_stmt(&mut self, i: Stmt<'input>) -> Stmt<'input> {
+        fold_stmt(self, i)
+    }
+    fn fold_function_def(&mut self, i: FunctionDef<'input>) -> FunctionDef<'input> {
+        fold_function_def(self, i)
+    }
+    fn fold_class_def(&mut self, i: ClassDef<'input>) -> ClassDef<'input> {
+        fold_class_def(self, i)
+    }
+    fn fold_alias(&mut self, i: Alias<'input>) -> Alias<'input> {
+        fold_alias(self, i)
+    }
+    fn fold_arguments(&mut self, i: Arguments<'input>) -> Arguments<'input> {
+        fold_arguments(self, i)
+    }
+    fn fold_arg(&mut self, i: Arg<'input>) -> Arg<'input> {
+        fold_arg(self, i)
+    }
+    fn fold_expr(&mut self, i: Expr<'input>) -> Expr<'input> {
+        fold_expr(self, i)
+    }
+    fn fold_constant(&mut self, i: Constant<'input>) -> Constant<'input> {
+        fold_constant(self, i)
+    }
+    fn fold_keyword(&mut self, i: Keyword<'input>) -> Keyword<'input> {
+        fold_keyword(self, i)
+    }
+    fn fold_comprehension(&mut self, i: Comprehension<'input>) -> Comprehension<'input> {
+        fold_comprehension(self, i)
+    }
+}
+
+fn fold_exprs<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    exprs: Vec<Expr<'input>>,
+) -> Vec<Expr<'input>> {
+    exprs.into_iter().map(|expr| f.fold_expr(expr)).collect()
+}
+
+fn fold_box<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    mut expr: Box<Expr<'input>>,
+) -> Box<Expr<'input>> {
+    // Reuse the allocation
+    *expr = f.fold_expr(*expr);
+    expr
+}
+
+fn fold_generators<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    generators: Vec<Comprehension<'input>>,
+) -> Vec<Comprehension<'input>> {
+    generators
+        .into_iter()
+        .map(|generator| f.fold_comprehension(generator))
+        .collect()
+}
+
+pub fn fold_stmts<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    stmts: Vec<Stmt<'input>>,
+) -> Vec<Stmt<'input>> {
+    stmts.into_iter().map(|stmt| f.fold_stmt(stmt)).collect()
+}
+
+pub fn fold_stmt<'input, F: Fold<'input> + ?Sized>(f: &mut F, stmt: Stmt<'input>) -> Stmt<'input> {
+    match stmt {
+        Stmt::FunctionDef(def) => Stmt::FunctionDef(Box::new(f.fold_function_def(*def))),
+        Stmt::AsyncFunctionDef(def) => Stmt::AsyncFunctionDef(Box::new(f.fold_function_def(*def))),
+        Stmt::ClassDef(def) => Stmt::ClassDef(f.fold_class_def(def)),
+        Stmt::Import { names } => Stmt::Import {
+            names: names.into_iter().map(|alias| f.fold_alias(alias)).collect(),
+        },
+        Stmt::ImportFrom {
+            module,
+            names,
+            level,
+        } => Stmt::ImportFrom {
+            module,
+            names: names.into_iter().map(|alias| f.fold_alias(alias)).collect(),
+            level,
+        },
+        Stmt::Assign { targets, value } => Stmt::Assign {
+            targets: fold_exprs(f, targets),
+            value: f.fold_expr(value),
+        },
+        Stmt::AnnAssign {
+            target,
+            annotation,
+            value,
+        } => Stmt::AnnAssign {
+            target: f.fold_expr(target),
+            annotation: f.fold_expr(annotation),
+            value: value.map(|value| f.fold_expr(value)),
+        },
+        Stmt::If { test, body, orelse } => Stmt::If {
+            test: f.fold_expr(test),
+            body: f.fold_stmts(body),
+            orelse: f.fold_stmts(orelse),
+        },
+        Stmt::Expr { value } => Stmt::Expr {
+            value: f.fold_expr(value),
+        },
+        Stmt::Other(source) => Stmt::Other(source),
+    }
+}
+
+pub fn fold_function_def<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    def: FunctionDef<'input>,
+) -> FunctionDef<'input> {
+    FunctionDef {
+        decorators: fold_exprs(f, def.decorators),
+        name: def.name,
+        args: f.fold_arguments(def.args),
+        returns: def.returns.map(|returns| f.fold_expr(returns)),
+        body: f.fold_stmts(def.body),
+        type_comment: def.type_comment,
+        span: def.span,
+    }
+}
+
+pub fn fold_class_def<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    def: ClassDef<'input>,
+) -> ClassDef<'input> {
+    ClassDef {
+        decorators: fold_exprs(f, def.decorators),
+        name: def.name,
+        bases: fold_exprs(f, def.bases),
+        keywords: def
+            .keywords
+            .into_iter()
+            .map(|keyword| f.fold_keyword(keyword))
+            .collect(),
+        body: f.fold_stmts(def.body),
+        span: def.span,
+    }
+}
+
+pub fn fold_alias<'input, F: Fold<'input> + ?Sized>(
+    _f: &mut F,
+    alias: Alias<'input>,
+) -> Alias<'input> {
+    alias
+}
+
+pub fn fold_arguments<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    args: Arguments<'input>,
+) -> Arguments<'input> {
+    let positional_only = args
+        .positional_only
+        .into_iter()
+        .map(|arg| f.fold_arg(arg))
+        .collect();
+    let args_ = args.args.into_iter().map(|arg| f.fold_arg(arg)).collect();
+    let var_args = args.var_args.map(|arg| f.fold_arg(arg));
+    let keyword_only = args
+        .keyword_only
+        .into_iter()
+        .map(|arg| f.fold_arg(arg))
+        .collect();
+    let kw_args = args.kw_args.map(|arg| f.fold_arg(arg));
+    Arguments {
+        args: args_,
+        positional_only,
+        keyword_only,
+        var_args,
+        kw_args,
+    }
+}
+
+pub fn fold_arg<'input, F: Fold<'input> + ?Sized>(f: &mut F, arg: Arg<'input>) -> Arg<'input> {
+    Arg {
+        name: arg.name,
+        ty: arg.ty.map(|ty| f.fold_expr(ty)),
+        default: arg.default.map(|default| f.fold_expr(default)),
+        type_comment: arg.type_comment,
+        span: arg.span,
+    }
+}
+
+pub fn fold_expr<'input, F: Fold<'input> + ?Sized>(f: &mut F, expr: Expr<'input>) -> Expr<'input> {
+    match expr {
+        Expr::Name { .. } | Expr::None { .. } | Expr::Ellipsis { .. } => expr,
+        Expr::Constant { value, span } => Expr::Constant {
+            value: f.fold_constant(value),
+            span,
+        },
+        Expr::Tuple { elts, span } => Expr::Tuple {
+            elts: fold_exprs(f, elts),
+            span,
+        },
+        Expr::Compare {
+            left,
+            ops,
+            comparators,
+            span,
+        } => Expr::Compare {
+            left: fold_box(f, left),
+            ops,
+            comparators: fold_exprs(f, comparators),
+            span,
+        },
+        Expr::Attribute { value, attr, span } => Expr::Attribute {
+            value: fold_box(f, value),
+            attr,
+            span,
+        },
+        Expr::Call {
+            func,
+            args,
+            keywords,
+            span,
+        } => Expr::Call {
+            func: fold_box(f, func),
+            args: fold_exprs(f, args),
+            keywords: keywords
+                .into_iter()
+                .map(|keyword| f.fold_keyword(keyword))
+                .collect(),
+            span,
+        },
+        Expr::Subscript { value, slice, span } => Expr::Subscript {
+            value: fold_box(f, value),
+            slice: fold_box(f, slice),
+            span,
+        },
+        Expr::BinOp {
+            left,
+            op,
+            right,
+            span,
+        } => Expr::BinOp {
+            left: fold_box(f, left),
+            op,
+            right: fold_box(f, right),
+            span,
+        },
+        Expr::List { elts, span } => Expr::List {
+            elts: fold_exprs(f, elts),
+            span,
+        },
+        Expr::BoolOp { op, values, span } => Expr::BoolOp {
+            op,
+            values: fold_exprs(f, values),
+            span,
+        },
+        Expr::NamedExpr {
+            target,
+            value,
+            span,
+        } => Expr::NamedExpr {
+            target: fold_box(f, target),
+            value: fold_box(f, value),
+            span,
+        },
+        Expr::UnaryOp { op, operand, span } => Expr::UnaryOp {
+            op,
+            operand: fold_box(f, operand),
+            span,
+        },
+        Expr::Lambda { args, body, span } => Expr::Lambda {
+            args: Box::new(f.fold_arguments(*args)),
+            body: fold_box(f, body),
+            span,
+        },
+        // `body if test else orelse`
+        Expr::IfExp {
+            test,
+            body,
+            orelse,
+            span,
+        } => {
+            let body = fold_box(f, body);
+            Expr::IfExp {
+                test: fold_box(f, test),
+                body,
+                orelse: fold_box(f, orelse),
+                span,
+            }
+        }
+        Expr::Dict { keys, values, span } => {
+            let (keys, values) = keys
+                .into_iter()
+                .zip(values)
+                .map(|(key, value)| (key.map(|key| f.fold_expr(key)), f.fold_expr(value)))
+                .unzip();
+            Expr::Dict { keys, values, span }
+        }
+        Expr::Set { elts, span } => Expr::Set {
+            elts: fold_exprs(f, elts),
+            span,
+        },
+        Expr::ListComp {
+            elt,
+            generators,
+            span,
+        } => Expr::ListComp {
+            elt: fold_box(f, elt),
+            generators: fold_generators(f, generators),
+            span,
+        },
+        Expr::SetComp {
+            elt,
+            generators,
+            span,
+        } => Expr::SetComp {
+            elt: fold_box(f, elt),
+            generators: fold_generators(f, generators),
+            span,
+        },
+        Expr::DictComp {
+            key,
+            value,
+            generators,
+            span,
+        } => Expr::DictComp {
+            key: fold_box(f, key),
+            value: fold_box(f, value),
+            generators: fold_generators(f, generators),
+            span,
+        },
+        Expr::GeneratorExp {
+            elt,
+            generators,
+            span,
+        } => Expr::GeneratorExp {
+            elt: fold_box(f, elt),
+            generators: fold_generators(f, generators),
+            span,
+        },
+        Expr::Await { value, span } => Expr::Await {
+            value: fold_box(f, value),
+            span,
+        },
+        Expr::Yield { value, span } => Expr::Yield {
+            value: value.map(|value| fold_box(f, value)),
+            span,
+        },
+        Expr::YieldFrom { value, span } => Expr::YieldFrom {
+            value: fold_box(f, value),
+            span,
+        },
+        Expr::Starred { value, span } => Expr::Starred {
+            value: fold_box(f, value),
+            span,
+        },
+        Expr::JoinedStr { values, span } => Expr::JoinedStr {
+            values: fold_exprs(f, values),
+            span,
+        },
+        Expr::FormattedValue {
+            value,
+            conversion,
+            format_spec,
+            span,
+        } => Expr::FormattedValue {
+            value: fold_box(f, value),
+            conversion,
+            format_spec: format_spec.map(|spec| fold_box(f, spec)),
+            span,
+        },
+        Expr::Slice {
+            lower,
+            upper,
+            step,
+            span,
+        } => Expr::Slice {
+            lower: lower.map(|lower| fold_box(f, lower)),
+            upper: upper.map(|upper| fold_box(f, upper)),
+            step: step.map(|step| fold_box(f, step)),
+            span,
+        },
+    }
+}
+
+pub fn fold_constant<'input, F: Fold<'input> + ?Sized>(
+    _f: &mut F,
+    constant: Constant<'input>,
+) -> Constant<'input> {
+    constant
+}
+
+pub fn fold_keyword<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    keyword: Keyword<'input>,
+) -> Keyword<'input> {
+    Keyword {
+        arg: keyword.arg,
+        value: f.fold_expr(keyword.value),
+    }
+}
+
+pub fn fold_comprehension<'input, F: Fold<'input> + ?Sized>(
+    f: &mut F,
+    comprehension: Comprehension<'input>,
+) -> Comprehension<'input> {
+    Comprehension {
+        target: f.fold_expr(comprehension.target),
+        iter: f.fold_expr(comprehension.iter),
+        ifs: fold_exprs(f, comprehension.ifs),
+        is_async: comprehension.is_async,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::{module, test::CheckParsed, Input, Operator};
+
+    /// Rewrite `Optional[T]` annotations into `T | None`
+    struct Optional;
+
+    impl<'input> Fold<'input> for Optional {
+        fn fold_expr(&mut self, expr: Expr<'input>) -> Expr<'input> {
+            match fold_expr(self, expr) {
+                Expr::Subscript { value, slice, span } if matches!(&*value, Expr::Name { id, .. } if id == "Optional") => {
+                    Expr::BinOp {
+                        left: slice,
+                        op: Operator::BitOr,
+                        right: Box::new(Expr::None { span }),
+                        span,
+                    }
+                }
+                expr => expr,
+            }
+        }
+    }
+
+    #[test]
+    fn rewrite_annotations() {
+        let stmts = module(Input::new(
+            "def f(x: Optional[int], y: list[Optional[str]] = []) -> Optional[Optional[int]]: ...\n",
+        ))
+        .check_parsed();
+        let expected = module(Input::new(
+            "def f(x: int | None, y: list[str | None] = []) -> int | None | None: ...\n",
+        ))
+        .check_parsed();
+        assert_eq!(Optional.fold_stmts(stmts), expected);
+    }
+}
@@ -1,47 +1,54 @@
-use super::{builtin::*, expr::*, ParseResult};
+use super::{builtin::*, expr::*, stmt::*, Input, ParseResult, Span};
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::*,
-    combinator::opt,
-    multi::{many0, separated_list0},
-    sequence::{delimited, tuple},
-    Parser,
+    combinator::{all_consuming, cut, opt},
+    multi::separated_list0,
+    sequence::{preceded, tuple},
+    Parser, Slice,
 };
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
-pub enum Type<'input> {
-    Name(&'input str),
-    None,
-}
-
-pub fn type_(input: &str) -> ParseResult<Type> {
-    // FIXME: More possible types e.g. `Callable`
-    let (input, name) = identifier(input)?;
-    Ok((input, Type::Name(name)))
+/// Annotation of arguments and return values, e.g. `int`, `list[str]` or `int | None`
+pub fn type_(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    expr(input)
 }
 
 /// `arg = (identifier arg, expr? annotation, string? type_comment)`
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
 pub struct Arg<'input> {
-    pub name: &'input str,
-    pub ty: Option<Type<'input>>,
+    pub name: Cow<'input, str>,
+    pub ty: Option<Expr<'input>>,
     pub default: Option<Expr<'input>>,
+    /// `# type: int` following the argument in a multi-line definition
+    pub type_comment: Option<Cow<'input, str>>,
+    pub span: Span,
 }
 
-pub fn arg(input: &str) -> ParseResult<Arg> {
-    let (input, (name, ty, default)) = tuple((
+pub fn arg(input: Input<'_>) -> ParseResult<'_, Arg<'_>> {
+    let (rest, (name, ty, default)) = tuple((
         identifier,
-        opt(tuple((multispace0, char(':'), multispace0, type_)).map(|(_sp1, _colon, _sp2, ty)| ty)),
-        opt(tuple((multispace0, char('='), multispace0, expr))
-            .map(|(_sp1, _colon, _sp2, default)| default)),
+        opt(tuple((ws, char(':'), ws, type_)).map(|(_sp1, _colon, _sp2, ty)| ty)),
+        opt(tuple((ws, char('='), ws, expr)).map(|(_sp1, _colon, _sp2, default)| default)),
     ))
     .parse(input)?;
-    Ok((input, Arg { name, ty, default }))
+    Ok((
+        rest,
+        Arg {
+            name,
+            ty,
+            default,
+            type_comment: None,
+            span: input.span_to(&rest),
+        },
+    ))
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
 pub enum ArgLike<'input> {
     /// Usual argument
     Arg(Arg<'input>),
@@ -55,29 +62,56 @@ pub enum ArgLike<'input> {
     KeywordSep,
 }
 
-pub fn arg_like(input: &str) -> ParseResult<ArgLike> {
+pub fn arg_like(input: Input<'_>) -> ParseResult<'_, ArgLike<'_>> {
     alt((
         char('/').map(|_| ArgLike::PositionalSep),
-        tuple((char('*'), multispace0, arg)).map(|(_star, _sp, arg)| ArgLike::VarArg(arg)),
-        tuple((tag("**"), multispace0, arg)).map(|(_star, _sp, arg)| ArgLike::KwArg(arg)),
+        tuple((char('*'), ws, arg)).map(|(_star, _sp, arg)| ArgLike::VarArg(arg)),
+        tuple((tag("**"), ws, arg)).map(|(_star, _sp, arg)| ArgLike::KwArg(arg)),
         char('*').map(|_| ArgLike::KeywordSep),
         arg.map(ArgLike::Arg),
     ))
     .parse(input)
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Default)]
+#[derive(Debug, PartialEq, PartialOrd, Clone, Default, Serialize, Deserialize)]
 pub struct Arguments<'input> {
     /// Usual arguments
-    args: Vec<Arg<'input>>,
+    pub(crate) args: Vec<Arg<'input>>,
     /// Args before `/`
-    positional_only: Vec<Arg<'input>>,
+    pub(crate) positional_only: Vec<Arg<'input>>,
     /// Args after `*`
-    keyword_only: Vec<Arg<'input>>,
+    pub(crate) keyword_only: Vec<Arg<'input>>,
     /// `*arg` will be stored without `*`
-    var_args: Option<Arg<'input>>,
+    pub(crate) var_args: Option<Arg<'input>>,
     /// `**kwargs` will be stored without `**`
-    kw_args: Option<Arg<'input>>,
+    pub(crate) kw_args: Option<Arg<'input>>,
+}
+
+impl<'input> Arguments<'input> {
+    /// Usual arguments, which can be passed both positionally and by keyword
+    pub fn args(&self) -> &[Arg<'input>] {
+        &self.args
+    }
+
+    /// Arguments before `/`
+    pub fn positional_only(&self) -> &[Arg<'input>] {
+        &self.positional_only
+    }
+
+    /// Arguments after `*` or `*args`
+    pub fn keyword_only(&self) -> &[Arg<'input>] {
+        &self.keyword_only
+    }
+
+    /// `*args` without `*`
+    pub fn var_args(&self) -> Option<&Arg<'input>> {
+        self.var_args.as_ref()
+    }
+
+    /// `**kwargs` without `**`
+    pub fn kw_args(&self) -> Option<&Arg<'input>> {
+        self.kw_args.as_ref()
+    }
 }
 
 impl<'input> From<Vec<ArgLike<'input>>> for Arguments<'input> {
@@ -90,49 +124,222 @@ impl<'input> From<Vec<ArgLike<'input>>> for Arguments<'input> {
                 ArgLike::PositionalSep => arguments.positional_only.append(cursor),
                 ArgLike::KeywordSep => cursor = &mut arguments.keyword_only,
                 ArgLike::KwArg(arg) => arguments.kw_args = Some(arg),
-                ArgLike::VarArg(arg) => arguments.var_args = Some(arg),
+                ArgLike::VarArg(arg) => {
+                    arguments.var_args = Some(arg);
+                    cursor = &mut arguments.keyword_only;
+                }
             }
         }
         arguments
     }
 }
 
-pub fn arguments(input: &str) -> ParseResult<Arguments> {
-    let (input, args) =
-        separated_list0(tuple((multispace0, char(','), multispace0)), arg_like).parse(input)?;
+pub fn arguments(input: Input<'_>) -> ParseResult<'_, Arguments<'_>> {
+    let (input, args) = separated_list0(tuple((ws, char(','), ws)), arg_like).parse(input)?;
     Ok((input, args.into()))
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
-pub struct FunctionDef<'input> {
-    name: &'input str,
-    args: Arguments<'input>,
-    type_: Type<'input>,
-    decorators: Vec<Expr<'input>>,
+/// `(...)` of the function definition, which may span multiple lines and end with a trailing comma
+///
+/// A type comment after an argument and its comma, e.g. `a,  # type: int`, is attached to the argument.
+fn parenthesized_arguments(input: Input<'_>) -> ParseResult<'_, Arguments<'_>> {
+    let (mut input, _open) = tuple((char('('), ws)).parse(input)?;
+    let mut args = Vec::new();
+    loop {
+        let (rest, mut arg) = match arg_like(input) {
+            Ok(parsed) => parsed,
+            Err(nom::Err::Error(_)) => break,
+            Err(e) => return Err(e),
+        };
+        let (rest, (_sp, before_comma)) = tuple((space0, opt(type_comment))).parse(rest)?;
+        let (rest, comma) = opt(preceded(ws, char(','))).parse(rest)?;
+        let (rest, (_sp, after_comma)) = tuple((space0, opt(type_comment))).parse(rest)?;
+        if let ArgLike::Arg(arg) | ArgLike::VarArg(arg) | ArgLike::KwArg(arg) = &mut arg {
+            arg.type_comment = before_comma.or(after_comma);
+        }
+        args.push(arg);
+        (input, _) = ws(rest)?;
+        if comma.is_none() {
+            break;
+        }
+    }
+    let (input, _close) = char(')').parse(input)?;
+    Ok((input, args.into()))
 }
 
-pub fn function_def(input: &str) -> ParseResult<FunctionDef> {
-    let (input, decorators) = many0(
-        tuple((char('@'), multispace0, expr, multispace0)).map(|(_at, _sp1, expr, _sp2)| expr),
-    )
+/// Comment starting with `type:` except `# type: ignore`, whose content is returned
+pub fn type_comment(input: Input<'_>) -> ParseResult<'_, Cow<'_, str>> {
+    let (rest, (_hash, _sp1, _type, _sp2, _colon, _sp3, comment)) = tuple((
+        char('#'),
+        space0,
+        tag("type"),
+        space0,
+        char(':'),
+        space0,
+        not_line_ending,
+    ))
     .parse(input)?;
-    let (input, _def) = tag("def").parse(input)?;
-    let (input, _space) = multispace1(input)?;
-    let (input, name) = identifier(input)?;
-    let (input, args) = delimited(char('('), arguments, char(')')).parse(input)?;
-    let (input, ty) =
-        opt(tuple((multispace0, tag("->"), multispace0, type_)).map(|(_sp1, _arrow, _sp2, ty)| ty))
+    let comment = comment.fragment().trim_end();
+    let ignore = comment
+        .strip_prefix("ignore")
+        .is_some_and(|rest| !rest.starts_with(|c: char| c == '_' || c.is_alphanumeric()));
+    if ignore {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    Ok((rest, Cow::Borrowed(comment)))
+}
+
+/// Type comment of the function following `:` in the same line or in the next line, without consuming it
+fn func_type_comment(input: Input<'_>) -> Option<Cow<'_, str>> {
+    if let Ok((_, comment)) = preceded(space0::<_, nom::error::Error<_>>, type_comment)(input) {
+        return Some(comment);
+    }
+    let (next, _) = tuple((
+        space0::<_, nom::error::Error<_>>,
+        opt(tuple((char('#'), not_line_ending))),
+        line_ending,
+        space0,
+    ))
+    .parse(input)
+    .ok()?;
+    type_comment(next).ok().map(|(_, comment)| comment)
+}
+
+/// `FunctionType(expr* argtypes, expr returns)`, i.e. the signature in a type comment like `(int, str) -> bool`
+///
+/// `*` and `**` of variable arguments are dropped as `ast.parse(..., mode="func_type")` does,
+/// and `(...)` is kept as a single [Expr::Ellipsis].
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+pub struct FunctionType<'input> {
+    pub argtypes: Vec<Expr<'input>>,
+    pub returns: Expr<'input>,
+}
+
+pub fn func_type(input: Input<'_>) -> ParseResult<'_, FunctionType<'_>> {
+    let argtype = preceded(tuple((opt(alt((tag("**"), tag("*")))), ws)), expr);
+    let (input, (_open, _sp1, argtypes, _trailing, _sp2, _close)) = tuple((
+        char('('),
+        ws,
+        separated_list0(tuple((ws, char(','), ws)), argtype),
+        opt(tuple((ws, char(',')))),
+        ws,
+        char(')'),
+    ))
+    .parse(input)?;
+    let (input, (_sp, _arrow, _sp2, returns)) = tuple((ws, tag("->"), ws, expr)).parse(input)?;
+    Ok((input, FunctionType { argtypes, returns }))
+}
+
+/// `@decorator` lines preceding `def` or `class`
+pub fn decorators(mut input: Input<'_>) -> ParseResult<'_, Vec<Expr<'_>>> {
+    let mut decorators = Vec::new();
+    // Each decorator is read in its own logical line, not to take `@a\n@b` as `a @ b`
+    while input.starts_with('@') {
+        let (line, rest) = logical_line(input.slice(1..));
+        let (_, decorator) = all_consuming(preceded(ws, named_expr)).parse(line)?;
+        decorators.push(decorator);
+        input = rest.slice(rest.len() - rest.trim_start().len()..);
+    }
+    Ok((input, decorators))
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+pub struct FunctionDef<'input> {
+    pub(crate) name: Cow<'input, str>,
+    pub(crate) args: Arguments<'input>,
+    pub(crate) returns: Option<Expr<'input>>,
+    pub(crate) decorators: Vec<Expr<'input>>,
+    pub(crate) body: Vec<Stmt<'input>>,
+    /// `# type: (int, str) -> bool` after `:`, which can be parsed by [func_type]
+    pub(crate) type_comment: Option<Cow<'input, str>>,
+    /// From `def` or `async` to the end of the body, i.e. decorators are not included
+    pub(crate) span: Span,
+}
+
+impl<'input> FunctionDef<'input> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn args(&self) -> &Arguments<'input> {
+        &self.args
+    }
+
+    /// Return type annotation
+    pub fn returns(&self) -> Option<&Expr<'input>> {
+        self.returns.as_ref()
+    }
+
+    pub fn decorators(&self) -> &[Expr<'input>] {
+        &self.decorators
+    }
+
+    pub fn body(&self) -> &[Stmt<'input>] {
+        &self.body
+    }
+
+    /// Signature given as `# type: (int, str) -> bool`, see [func_type]
+    pub fn type_comment(&self) -> Option<&str> {
+        self.type_comment.as_deref()
+    }
+
+    /// From `def` or `async` to the end of the body, or the default for ones converted by [crate::ast]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+pub fn function_def(input: Input<'_>) -> ParseResult<'_, FunctionDef<'_>> {
+    let (start, decorators) = decorators(input)?;
+    let (input, _def) = tuple((tag("def"), ws1)).parse(start)?;
+    function_def_body(start, input, decorators)
+}
+
+/// `async def` with its decorators
+pub fn async_function_def(input: Input<'_>) -> ParseResult<'_, FunctionDef<'_>> {
+    let (start, decorators) = decorators(input)?;
+    let (input, _async_def) = tuple((tag("async"), ws1, tag("def"), ws1)).parse(start)?;
+    function_def_body(start, input, decorators)
+}
+
+/// Rest of `def` after the keyword, i.e. name, arguments, return type and body
+///
+/// `start` is the input from the keyword, where the span of the definition starts.
+fn function_def_body<'input>(
+    start: Input<'input>,
+    input: Input<'input>,
+    decorators: Vec<Expr<'input>>,
+) -> ParseResult<'input, FunctionDef<'input>> {
+    // Errors after the keyword are not backtracked, to be reported where they are found
+    let (input, name) = cut(identifier)(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, args) = cut(parenthesized_arguments)(input)?;
+    let (input, returns) =
+        opt(tuple((ws, tag("->"), ws, cut(type_))).map(|(_sp1, _arrow, _sp2, ty)| ty))
             .parse(input)?;
-    let (input, _colon) = char(':').parse(input)?;
-    let (input, _sp) = multispace0(input)?;
-    let (input, _expr) = expr(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, _colon) = cut(char(':')).parse(input)?;
+    let type_comment = func_type_comment(input);
+    let (input, body) = cut(suite)(input)?;
+    // The body may be followed by blank lines
+    let consumed = &start[..start.len() - input.len()];
+    let span = Span {
+        start: start.offset(),
+        end: start.offset() + consumed.trim_end().len(),
+    };
     Ok((
         input,
         FunctionDef {
             name,
             args,
-            type_: ty.unwrap_or(Type::None),
+            returns,
             decorators,
+            body,
+            type_comment,
+            span,
         },
     ))
 }
@@ -143,51 +350,65 @@ mod test {
 
     #[test]
     fn parse_arg() {
-        insta::assert_debug_snapshot!(arg("a").check_parsed(),
+        insta::assert_debug_snapshot!(arg(Input::new("a")).check_parsed(),
             @r###"
             Arg {
                 name: "a",
                 ty: None,
                 default: None,
+                type_comment: None,
+                span: 0..1,
             }
             "###
         );
-        insta::assert_debug_snapshot!(arg("a: T").check_parsed(),
+        insta::assert_debug_snapshot!(arg(Input::new("a: T")).check_parsed(),
             @r###"
             Arg {
                 name: "a",
                 ty: Some(
-                    Name(
-                        "T",
-                    ),
+                    Name {
+                        id: "T",
+                        span: 3..4,
+                    },
                 ),
                 default: None,
+                type_comment: None,
+                span: 0..4,
             }
             "###
         );
-        insta::assert_debug_snapshot!(arg("a: T = None").check_parsed(),
+        insta::assert_debug_snapshot!(arg(Input::new("a: T = None")).check_parsed(),
             @r###"
             Arg {
                 name: "a",
                 ty: Some(
-                    Name(
-                        "T",
-                    ),
+                    Name {
+                        id: "T",
+                        span: 3..4,
+                    },
                 ),
                 default: Some(
-                    None,
+                    None {
+                        span: 7..11,
+                    },
                 ),
+                type_comment: None,
+                span: 0..11,
             }
             "###
         );
-        insta::assert_debug_snapshot!(arg("a = None").check_parsed(),
+        insta::assert_debug_snapshot!(arg(Input::new("a = None")).check_parsed(),
             @r###"
             Arg {
                 name: "a",
                 ty: None,
                 default: Some(
-                    None,
+                    None {
+                        span: 4..8,
+                    },
                 ),
+                type_comment: None,
+                span: 0..8,
             }
             "###
         );
@@ -197,10 +418,10 @@ mod test {
     fn parse_function_def() {
         insta::assert_debug_snapshot!(
             function_def(
-                r#"
+                Input::new(r#"
                 def f(a, b):
                     ...
-                "#.trim()
+                "#.trim())
             ).check_parsed(),
             @r###"
             FunctionDef {
@@ -211,11 +432,15 @@ mod test {
                             name: "a",
                             ty: None,
                             default: None,
+                            type_comment: None,
+                            span: 6..7,
                         },
                         Arg {
                             name: "b",
                             ty: None,
                             default: None,
+                            type_comment: None,
+                            span: 9..10,
                         },
                     ],
                     positional_only: [],
@@ -223,8 +448,17 @@ mod test {
                     var_args: None,
                     kw_args: None,
                 },
-                type_: None,
+                returns: None,
                 decorators: [],
+                body: [
+                    Expr {
+                        value: Ellipsis {
+                            span: 33..36,
+                        },
+                    },
+                ],
+                type_comment: None,
+                span: 0..36,
             }
             "###
         );
@@ -232,11 +466,11 @@ mod test {
         // type hint
         insta::assert_debug_snapshot!(
             function_def(
-                r#"
+                Input::new(r#"
                 def g(x: int) -> str:
                     ...
                 "#
-                .trim()
+                .trim())
             ).check_parsed(),
             @r###"
             FunctionDef {
@@ -246,11 +480,14 @@ mod test {
                         Arg {
                             name: "x",
                             ty: Some(
-                                Name(
-                                    "int",
-                                ),
+                                Name {
+                                    id: "int",
+                                    span: 9..12,
+                                },
                             ),
                             default: None,
+                            type_comment: None,
+                            span: 6..12,
                         },
                     ],
                     positional_only: [],
@@ -258,10 +495,22 @@ mod test {
                     var_args: None,
                     kw_args: None,
                 },
-                type_: Name(
-                    "str",
+                returns: Some(
+                    Name {
+                        id: "str",
+                        span: 17..20,
+                    },
                 ),
                 decorators: [],
+                body: [
+                    Expr {
+                        value: Ellipsis {
+                            span: 42..45,
+                        },
+                    },
+                ],
+                type_comment: None,
+                span: 0..45,
             }
             "###
         );
@@ -269,11 +518,11 @@ mod test {
         // *arg
         insta::assert_debug_snapshot!(
             function_def(
-                r#"
+                Input::new(r#"
                 def g(*args) -> str:
                     ...
                 "#
-                .trim()
+                .trim())
             ).check_parsed(),
             @r###"
             FunctionDef {
@@ -287,24 +536,38 @@ mod test {
                             name: "args",
                             ty: None,
                             default: None,
+                            type_comment: None,
+                            span: 7..11,
                         },
                     ),
                     kw_args: None,
                 },
-                type_: Name(
-                    "str",
+                returns: Some(
+                    Name {
+                        id: "str",
+                        span: 16..19,
+                    },
                 ),
                 decorators: [],
+                body: [
+                    Expr {
+                        value: Ellipsis {
+                            span: 41..44,
+                        },
+                    },
+                ],
+                type_comment: None,
+                span: 0..44,
             }
             "###
         );
         // **keywords
         insta::assert_debug_snapshot!(
         function_def(
-            r#"
+            Input::new(r#"
             def g(**keywords) -> str:
                 ...
-            "#.trim()
+            "#.trim())
         ).check_parsed(), @r###"
         FunctionDef {
             name: "g",
@@ -318,23 +581,37 @@ mod test {
                         name: "keywords",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 8..16,
                     },
                 ),
             },
-            type_: Name(
-                "str",
+            returns: Some(
+                Name {
+                    id: "str",
+                    span: 21..24,
+                },
             ),
             decorators: [],
+            body: [
+                Expr {
+                    value: Ellipsis {
+                        span: 42..45,
+                    },
+                },
+            ],
+            type_comment: None,
+            span: 0..45,
         }
         "###
         );
 
         // Positional only
         insta::assert_debug_snapshot!(function_def(
-            r#"
+            Input::new(r#"
             def f(x, /, a=1):
                 ...
-            "#.trim()
+            "#.trim())
         ).check_parsed(), @r###"
         FunctionDef {
             name: "f",
@@ -345,11 +622,14 @@ mod test {
                         ty: None,
                         default: Some(
                             Constant {
-                                value: Float(
-                                    1.0,
+                                value: Int(
+                                    1,
                                 ),
+                                span: 14..15,
                             },
                         ),
+                        type_comment: None,
+                        span: 12..15,
                     },
                 ],
                 positional_only: [
@@ -357,24 +637,35 @@ mod test {
                         name: "x",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 6..7,
                     },
                 ],
                 keyword_only: [],
                 var_args: None,
                 kw_args: None,
             },
-            type_: None,
+            returns: None,
             decorators: [],
+            body: [
+                Expr {
+                    value: Ellipsis {
+                        span: 34..37,
+                    },
+                },
+            ],
+            type_comment: None,
+            span: 0..37,
         }
         "###
         );
 
         // Keyword only
         insta::assert_debug_snapshot!(function_def(
-            r#"
+            Input::new(r#"
             def f(x, *, a=1):
                 ...
-            "#.trim()
+            "#.trim())
         ).check_parsed(), @r###"
         FunctionDef {
             name: "f",
@@ -384,6 +675,8 @@ mod test {
                         name: "x",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 6..7,
                     },
                 ],
                 positional_only: [],
@@ -393,29 +686,41 @@ mod test {
                         ty: None,
                         default: Some(
                             Constant {
-                                value: Float(
-                                    1.0,
+                                value: Int(
+                                    1,
                                 ),
+                                span: 14..15,
                             },
                         ),
+                        type_comment: None,
+                        span: 12..15,
                     },
                 ],
                 var_args: None,
                 kw_args: None,
             },
-            type_: None,
+            returns: None,
             decorators: [],
+            body: [
+                Expr {
+                    value: Ellipsis {
+                        span: 34..37,
+                    },
+                },
+            ],
+            type_comment: None,
+            span: 0..37,
         }
         "###
         );
 
         // Combined
         insta::assert_debug_snapshot!(function_def(
-            r#"
+            Input::new(r#"
             def f(x, /, y, *, a=1):
                 ...
             "#
-            .trim()
+            .trim())
         ).check_parsed(), @r###"
         FunctionDef {
             name: "f",
@@ -425,6 +730,8 @@ mod test {
                         name: "y",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 12..13,
                     },
                 ],
                 positional_only: [
@@ -432,6 +739,8 @@ mod test {
                         name: "x",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 6..7,
                     },
                 ],
                 keyword_only: [
@@ -440,29 +749,41 @@ mod test {
                         ty: None,
                         default: Some(
                             Constant {
-                                value: Float(
-                                    1.0,
+                                value: Int(
+                                    1,
                                 ),
+                                span: 20..21,
                             },
                         ),
+                        type_comment: None,
+                        span: 18..21,
                     },
                 ],
                 var_args: None,
                 kw_args: None,
             },
-            type_: None,
+            returns: None,
             decorators: [],
+            body: [
+                Expr {
+                    value: Ellipsis {
+                        span: 40..43,
+                    },
+                },
+            ],
+            type_comment: None,
+            span: 0..43,
         }
         "###
         );
 
         // decorators
         insta::assert_debug_snapshot!(function_def(
-            r#"
+            Input::new(r#"
             @staticmethod
             def f(a, b):
                 ...
-            "#.trim()
+            "#.trim())
         ).check_parsed(), @r###"
         FunctionDef {
             name: "f",
@@ -472,11 +793,15 @@ mod test {
                         name: "a",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 32..33,
                     },
                     Arg {
                         name: "b",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 35..36,
                     },
                 ],
                 positional_only: [],
@@ -484,23 +809,33 @@ mod test {
                 var_args: None,
                 kw_args: None,
             },
-            type_: None,
+            returns: None,
             decorators: [
                 Name {
                     id: "staticmethod",
+                    span: 1..13,
+                },
+            ],
+            body: [
+                Expr {
+                    value: Ellipsis {
+                        span: 55..58,
+                    },
                 },
             ],
+            type_comment: None,
+            span: 26..58,
         }
         "###
         );
 
         // decorators with arguments
         insta::assert_debug_snapshot!(function_def(
-            r#"
+            Input::new(r#"
             @deco(1, "test")
             def f(a, b):
                 ...
-            "#.trim()
+            "#.trim())
         ).check_parsed(), @r###"
         FunctionDef {
             name: "f",
@@ -510,11 +845,15 @@ mod test {
                         name: "a",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 35..36,
                     },
                     Arg {
                         name: "b",
                         ty: None,
                         default: None,
+                        type_comment: None,
+                        span: 38..39,
                     },
                 ],
                 positional_only: [],
@@ -522,29 +861,134 @@ mod test {
                 var_args: None,
                 kw_args: None,
             },
-            type_: None,
+            returns: None,
             decorators: [
                 Call {
                     func: Name {
                         id: "deco",
+                        span: 1..5,
                     },
                     args: [
                         Constant {
-                            value: Float(
-                                1.0,
+                            value: Int(
+                                1,
                             ),
+                            span: 6..7,
                         },
                         Constant {
                             value: String(
                                 "test",
                             ),
+                            span: 9..15,
                         },
                     ],
                     keywords: [],
+                    span: 1..16,
                 },
             ],
+            body: [
+                Expr {
+                    value: Ellipsis {
+                        span: 58..61,
+                    },
+                },
+            ],
+            type_comment: None,
+            span: 29..61,
         }
         "###
         );
     }
+
+    #[test]
+    fn parse_type_comments() {
+        let (_, f) = function_def(Input::new(
+            "def f(a,  # type: int\n      *args,  # type: str\n      b  # type: List[int]\n      ):\n    # type: (...) -> None\n    pass\n",
+        ))
+        .unwrap();
+        let comments: Vec<_> = f
+            .args
+            .args
+            .iter()
+            .chain(&f.args.var_args)
+            .chain(&f.args.keyword_only)
+            .map(|arg| arg.type_comment.as_deref())
+            .collect();
+        assert_eq!(comments, [Some("int"), Some("str"), Some("List[int]")]);
+        assert_eq!(f.type_comment(), Some("(...) -> None"));
+
+        let (_, f) =
+            function_def(Input::new("def f(a):  # type: (int) -> bool\n    ...\n")).unwrap();
+        assert_eq!(f.type_comment(), Some("(int) -> bool"));
+        let (_, f) = function_def(Input::new("def f(a):  # type: ignore\n    ...\n")).unwrap();
+        assert_eq!(f.type_comment(), None);
+    }
+
+    #[test]
+    fn parse_func_type() {
+        insta::assert_debug_snapshot!(func_type(Input::new("(int, *str, **Any) -> List[T]")).check_parsed(), @r###"
+        FunctionType {
+            argtypes: [
+                Name {
+                    id: "int",
+                    span: 1..4,
+                },
+                Name {
+                    id: "str",
+                    span: 7..10,
+                },
+                Name {
+                    id: "Any",
+                    span: 14..17,
+                },
+            ],
+            returns: Subscript {
+                value: Name {
+                    id: "List",
+                    span: 22..26,
+                },
+                slice: Name {
+                    id: "T",
+                    span: 27..28,
+                },
+                span: 22..29,
+            },
+        }
+        "###);
+    }
+
+    #[test]
+    fn parse_decorators() {
+        insta::assert_debug_snapshot!(decorators(Input::new("@a\n@b(\n    1.0,\n)  # comment\ndef")).unwrap(), @r###"
+        (
+            Input {
+                offset: 29,
+                line: 5,
+                fragment: "def",
+            },
+            [
+                Name {
+                    id: "a",
+                    span: 1..2,
+                },
+                Call {
+                    func: Name {
+                        id: "b",
+                        span: 4..5,
+                    },
+                    args: [
+                        Constant {
+                            value: Float(
+                                1.0,
+                            ),
+                            span: 11..14,
+                        },
+                    ],
+                    keywords: [],
+                    span: 4..17,
+                },
+            ],
+        )
+        "###);
+    }
 }
@@ -0,0 +1,316 @@
+//! Input of parsers which keeps its position in the whole source, and spans of nodes
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    ops::{Deref, Range, RangeFrom, RangeFull, RangeTo},
+    str::{CharIndices, Chars},
+};
+
+use nom::{
+    error::{Error, ErrorKind, ParseError},
+    AsBytes, Compare, CompareResult, Err, FindSubstring, IResult, InputIter, InputLength,
+    InputTake, InputTakeAtPosition, Needed, Offset, Parser, Slice,
+};
+
+/// Part of the source remaining to be parsed
+///
+/// This dereferences to the remaining part as `&str`,
+/// and [Slice] keeps track of the byte offset and the line number in the whole source.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Input<'input> {
+    source: &'input str,
+    fragment: &'input str,
+    offset: usize,
+    line: usize,
+}
+
+/// Line and column, both starting from 1, where the column counts characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'input> Input<'input> {
+    pub fn new(source: &'input str) -> Self {
+        Input {
+            source,
+            fragment: source,
+            offset: 0,
+            line: 1,
+        }
+    }
+
+    /// Remaining part of the source
+    pub fn fragment(&self) -> &'input str {
+        self.fragment
+    }
+
+    /// The whole source given to [Input::new]
+    pub fn source(&self) -> &'input str {
+        self.source
+    }
+
+    /// Byte offset from the beginning of the source
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn location(&self) -> Location {
+        let line_start = self.source[..self.offset]
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+        Location {
+            line: self.line,
+            column: self.source[line_start..self.offset].chars().count() + 1,
+        }
+    }
+
+    /// Span from this input to `rest` remaining after parsing
+    pub fn span_to(&self, rest: &Input) -> Span {
+        Span {
+            start: self.offset,
+            end: rest.offset,
+        }
+    }
+}
+
+impl fmt::Debug for Input<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Input")
+            .field("offset", &self.offset)
+            .field("line", &self.line)
+            .field("fragment", &self.fragment)
+            .finish()
+    }
+}
+
+impl<'input> Deref for Input<'input> {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.fragment
+    }
+}
+
+impl PartialEq<&str> for Input<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.fragment == *other
+    }
+}
+
+macro_rules! impl_slice {
+    ($($range:ty),*) => {$(
+        impl<'input> Slice<$range> for Input<'input> {
+            fn slice(&self, range: $range) -> Self {
+                let fragment = &self.fragment[range];
+                let consumed = &self.fragment[..fragment.as_ptr() as usize - self.fragment.as_ptr() as usize];
+                Input {
+                    source: self.source,
+                    fragment,
+                    offset: self.offset + consumed.len(),
+                    line: self.line + consumed.matches('\n').count(),
+                }
+            }
+        }
+    )*};
+}
+impl_slice!(Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull);
+
+impl InputLength for Input<'_> {
+    fn input_len(&self) -> usize {
+        self.fragment.len()
+    }
+}
+
+impl InputTake for Input<'_> {
+    fn take(&self, count: usize) -> Self {
+        self.slice(..count)
+    }
+
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        (self.slice(count..), self.slice(..count))
+    }
+}
+
+impl<'input> InputIter for Input<'input> {
+    type Item = char;
+    type Iter = CharIndices<'input>;
+    type IterElem = Chars<'input>;
+
+    fn iter_indices(&self) -> Self::Iter {
+        self.fragment.char_indices()
+    }
+
+    fn iter_elements(&self) -> Self::IterElem {
+        self.fragment.chars()
+    }
+
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        self.fragment.position(predicate)
+    }
+
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        self.fragment.slice_index(count)
+    }
+}
+
+impl InputTakeAtPosition for Input<'_> {
+    type Item = char;
+
+    fn split_at_position<P, E: ParseError<Self>>(&self, predicate: P) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        match self.fragment.find(predicate) {
+            Some(i) => Ok(self.take_split(i)),
+            None => Err(Err::Incomplete(Needed::new(1))),
+        }
+    }
+
+    fn split_at_position1<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+        kind: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        match self.fragment.find(predicate) {
+            Some(0) => Err(Err::Error(E::from_error_kind(*self, kind))),
+            Some(i) => Ok(self.take_split(i)),
+            None => Err(Err::Incomplete(Needed::new(1))),
+        }
+    }
+
+    fn split_at_position_complete<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        let i = self.fragment.find(predicate).unwrap_or(self.fragment.len());
+        Ok(self.take_split(i))
+    }
+
+    fn split_at_position1_complete<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+        kind: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        match self.fragment.find(predicate).unwrap_or(self.fragment.len()) {
+            0 => Err(Err::Error(E::from_error_kind(*self, kind))),
+            i => Ok(self.take_split(i)),
+        }
+    }
+}
+
+impl<'a> Compare<&'a str> for Input<'_> {
+    fn compare(&self, t: &'a str) -> CompareResult {
+        self.fragment.compare(t)
+    }
+
+    fn compare_no_case(&self, t: &'a str) -> CompareResult {
+        self.fragment.compare_no_case(t)
+    }
+}
+
+impl<'a> FindSubstring<&'a str> for Input<'_> {
+    fn find_substring(&self, substr: &'a str) -> Option<usize> {
+        self.fragment.find(substr)
+    }
+}
+
+impl Offset for Input<'_> {
+    fn offset(&self, second: &Self) -> usize {
+        second.offset - self.offset
+    }
+}
+
+impl AsBytes for Input<'_> {
+    fn as_bytes(&self) -> &[u8] {
+        self.fragment.as_bytes()
+    }
+}
+
+/// Range of bytes in the source where a node is parsed from
+///
+/// Spans are ignored when nodes are compared, so that the same code parsed from different places,
+/// or converted from Python's `ast` module by [crate::ast] without spans, compare equal.
+#[derive(Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Smallest span covering both
+    pub fn to(&self, other: Span) -> Span {
+        Span {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+}
+
+impl fmt::Debug for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for Span {
+    fn partial_cmp(&self, _other: &Self) -> Option<std::cmp::Ordering> {
+        Some(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Map the output of `parser` together with the span of the consumed input
+pub fn spanned<'input, O, T>(
+    mut parser: impl Parser<Input<'input>, O, Error<Input<'input>>>,
+    mut f: impl FnMut(O, Span) -> T,
+) -> impl FnMut(Input<'input>) -> IResult<Input<'input>, T> {
+    move |input| {
+        let (rest, out) = parser.parse(input)?;
+        Ok((rest, f(out, input.span_to(&rest))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{bytes::complete::tag, character::complete::multispace1};
+
+    #[test]
+    fn track_location() {
+        let input = Input::new("ab\ncdé f");
+        let (rest, _) = tag::<_, _, nom::error::Error<_>>("ab\ncd")
+            .parse(input)
+            .unwrap();
+        assert_eq!(rest, "é f");
+        assert_eq!(rest.offset(), 5);
+        assert_eq!(rest.location(), Location { line: 2, column: 3 });
+
+        let rest = rest.slice("é".len()..);
+        let (rest, _) = multispace1::<_, nom::error::Error<_>>(rest).unwrap();
+        assert_eq!(rest.location(), Location { line: 2, column: 5 });
+        assert_eq!(input.span_to(&rest).range(), 0..8);
+    }
+}
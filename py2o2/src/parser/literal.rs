@@ -0,0 +1,627 @@
+//! Numeric, string and bytes literals
+//!
+//! See <https://docs.python.org/3/reference/lexical_analysis.html#literals>
+
+use super::{expr::*, Input, ParseResult, Span};
+use std::borrow::Cow;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, one_of, satisfy},
+    combinator::{opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many0, many0_count},
+    sequence::{pair, preceded, tuple},
+    Parser, Slice,
+};
+
+fn error(input: Input<'_>, kind: ErrorKind) -> nom::Err<Error<Input<'_>>> {
+    nom::Err::Error(Error::new(input, kind))
+}
+
+/// Digits of `radix` which may be separated by single underscores, e.g. `1_000`
+fn digits<'input>(radix: u32) -> impl Fn(Input<'input>) -> ParseResult<'input, Input<'input>> {
+    move |input| {
+        let digit = |input| satisfy(|c: char| c.is_digit(radix)).parse(input);
+        recognize(pair(digit, many0_count(pair(opt(char('_')), digit)))).parse(input)
+    }
+}
+
+/// Integer, floating point or imaginary literal, e.g. `0x_ff`, `1e-3` or `2j`
+///
+/// Integers which do not fit in `i64` become [Constant::BigInt].
+pub fn number(input: Input<'_>) -> ParseResult<'_, Constant<'_>> {
+    alt((radix_integer, decimal)).parse(input)
+}
+
+/// `0b`, `0o` or `0x` integer
+fn radix_integer(input: Input<'_>) -> ParseResult<'_, Constant<'_>> {
+    let (rest, (_zero, base)) = pair(char('0'), one_of("bBoOxX")).parse(input)?;
+    let radix = match base {
+        'b' | 'B' => 2,
+        'o' | 'O' => 8,
+        _ => 16,
+    };
+    let (rest, digits) = preceded(opt(char('_')), digits(radix)).parse(rest)?;
+    Ok((rest, integer(&digits.replace('_', ""), radix)))
+}
+
+/// Integer of `digits` in `radix` without underscores
+fn integer(digits: &str, radix: u32) -> Constant<'static> {
+    if let Ok(value) = i64::from_str_radix(digits, radix) {
+        return Constant::Int(value);
+    }
+    // Little-endian limbs of 10^9 converted digit by digit
+    const LIMB: u64 = 1_000_000_000;
+    let mut limbs: Vec<u64> = vec![0];
+    for digit in digits.chars().filter_map(|c| c.to_digit(radix)) {
+        let mut carry = u64::from(digit);
+        for limb in &mut limbs {
+            let value = *limb * u64::from(radix) + carry;
+            *limb = value % LIMB;
+            carry = value / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+    }
+    let mut decimal = limbs.pop().unwrap_or_default().to_string();
+    for limb in limbs.iter().rev() {
+        decimal += &format!("{:09}", limb);
+    }
+    Constant::BigInt(decimal)
+}
+
+/// Decimal integer, floating point or imaginary number
+fn decimal(input: Input<'_>) -> ParseResult<'_, Constant<'_>> {
+    let exponent =
+        |input| recognize(tuple((one_of("eE"), opt(one_of("+-")), digits(10)))).parse(input);
+    let (rest, token) = alt((
+        recognize(tuple((
+            digits(10),
+            opt(pair(char('.'), opt(digits(10)))),
+            opt(exponent),
+        ))),
+        recognize(tuple((char('.'), digits(10), opt(exponent)))),
+    ))
+    .parse(input)?;
+    let (rest, imaginary) = opt(one_of("jJ")).parse(rest)?;
+
+    let token = token.replace('_', "");
+    let float = || token.parse().map_err(|_| error(input, ErrorKind::Float));
+    let value = if imaginary.is_some() {
+        Constant::Complex {
+            real: 0.0,
+            imag: float()?,
+        }
+    } else if token.contains(['.', 'e', 'E']) {
+        Constant::Float(float()?)
+    } else {
+        integer(&token, 10)
+    };
+    Ok((rest, value))
+}
+
+/// String literal without concatenation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringPart<'input> {
+    /// `r` prefix, i.e. escape sequences are kept as they are
+    pub raw: bool,
+    /// `b` prefix
+    pub bytes: bool,
+    /// `f` prefix
+    pub formatted: bool,
+    /// Source between the quotes
+    pub body: Input<'input>,
+}
+
+/// String literal with its prefix, e.g. `rb'\d'` or `"""doc"""`
+pub fn string_part(input: Input<'_>) -> ParseResult<'_, StringPart<'_>> {
+    let (rest, prefix) = take_while_m_n(0, 2, |c| "rRbBuUfF".contains(c))(input)?;
+    let prefix = prefix.to_ascii_lowercase();
+    if !matches!(
+        prefix.as_str(),
+        "" | "r" | "u" | "b" | "f" | "br" | "rb" | "fr" | "rf"
+    ) {
+        return Err(error(input, ErrorKind::Tag));
+    }
+
+    let (rest, quote) = alt((tag("\"\"\""), tag("'''"), tag("\""), tag("'"))).parse(rest)?;
+    let mut chars = rest.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if rest[i..].starts_with(quote.fragment()) {
+            let part = StringPart {
+                raw: prefix.contains('r'),
+                bytes: prefix.contains('b'),
+                formatted: prefix.contains('f'),
+                body: rest.slice(..i),
+            };
+            return Ok((rest.slice(i + quote.len()..), part));
+        }
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '\n' if quote.len() == 1 => break,
+            _ => {}
+        }
+    }
+    Err(error(input, ErrorKind::Char))
+}
+
+/// String, bytes or f-string literals concatenated implicitly, e.g. `"a" 'b'`
+///
+/// Bytes become [Constant::Bytes], and strings become [Constant::String]
+/// or [Expr::JoinedStr] if any of them is an f-string.
+pub fn strings(input: Input<'_>) -> ParseResult<'_, Expr<'_>> {
+    let (rest, first) = string_part(input)?;
+    let (rest, others) = many0(preceded(super::ws, string_part)).parse(rest)?;
+    let parts: Vec<StringPart> = std::iter::once(first).chain(others).collect();
+    let fail = || error(input, ErrorKind::Verify);
+
+    if parts.iter().any(|part| part.bytes) {
+        if !parts.iter().all(|part| part.bytes) {
+            return Err(fail());
+        }
+        let mut value = Vec::new();
+        for part in &parts {
+            // Non-ASCII characters are only allowed as escape sequences
+            if !part.body.is_ascii() {
+                return Err(fail());
+            }
+            let text = if part.raw {
+                Cow::Borrowed(part.body.fragment())
+            } else {
+                Cow::Owned(unescape(&part.body, true).ok_or_else(fail)?)
+            };
+            for c in text.chars() {
+                value.push(u8::try_from(c).map_err(|_| fail())?);
+            }
+        }
+        return Ok((
+            rest,
+            Expr::Constant {
+                value: Constant::Bytes(value),
+                span: input.span_to(&rest),
+            },
+        ));
+    }
+
+    if parts.iter().all(|part| !part.formatted) {
+        let value = match &parts[..] {
+            [part] if part.raw || !part.body.contains('\\') => Cow::Borrowed(part.body.fragment()),
+            _ => {
+                let mut value = String::new();
+                for part in &parts {
+                    value += &literal(part).ok_or_else(fail)?;
+                }
+                Cow::Owned(value)
+            }
+        };
+        return Ok((
+            rest,
+            Expr::Constant {
+                value: Constant::String(value),
+                span: input.span_to(&rest),
+            },
+        ));
+    }
+
+    let mut values = JoinedValues::default();
+    for part in &parts {
+        if part.formatted {
+            let (body, ()) = f_string(part.body, part.raw, false, &mut values)?;
+            if !body.is_empty() {
+                return Err(fail());
+            }
+        } else {
+            values.push_str(&literal(part).ok_or_else(fail)?);
+        }
+    }
+    Ok((rest, values.into_joined_str(input.span_to(&rest))))
+}
+
+/// Value of a string literal other than f-string
+fn literal<'input>(part: &StringPart<'input>) -> Option<Cow<'input, str>> {
+    if part.raw {
+        Some(Cow::Borrowed(part.body.fragment()))
+    } else {
+        unescape(&part.body, false).map(Cow::Owned)
+    }
+}
+
+/// Values of [Expr::JoinedStr] where adjacent literals are merged
+///
+/// As in CPython 3.11, the merged literals have the span of the whole [Expr::JoinedStr]
+/// since they may be built from several parts.
+#[derive(Default)]
+struct JoinedValues<'input> {
+    values: Vec<Expr<'input>>,
+    text: String,
+}
+
+impl<'input> JoinedValues<'input> {
+    fn push_str(&mut self, s: &str) {
+        self.text += s;
+    }
+
+    fn push_value(&mut self, value: Expr<'input>) {
+        self.flush();
+        self.values.push(value);
+    }
+
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+            self.values.push(Expr::Constant {
+                value: Constant::String(Cow::Owned(text)),
+                span: Span::default(),
+            });
+        }
+    }
+
+    fn into_joined_str(mut self, span: Span) -> Expr<'input> {
+        self.flush();
+        for value in &mut self.values {
+            if let Expr::Constant { span: literal, .. } = value {
+                *literal = span;
+            }
+        }
+        Expr::JoinedStr {
+            values: self.values,
+            span,
+        }
+    }
+}
+
+/// Literals and replacement fields of an f-string body
+///
+/// In a format spec, i.e. `spec` is true, this stops at the `}` closing the replacement field.
+fn f_string<'input>(
+    mut input: Input<'input>,
+    raw: bool,
+    spec: bool,
+    values: &mut JoinedValues<'input>,
+) -> ParseResult<'input, ()> {
+    loop {
+        let end = input.find(['{', '}']).unwrap_or(input.len());
+        let text = &input[..end];
+        if raw {
+            values.push_str(text);
+        } else {
+            values
+                .push_str(&unescape(text, false).ok_or_else(|| error(input, ErrorKind::Escaped))?);
+        }
+        input = input.slice(end..);
+
+        if input.starts_with("{{") {
+            values.push_str("{");
+            input = input.slice(2..);
+        } else if input.starts_with('}') && spec {
+            return Ok((input, ()));
+        } else if input.starts_with("}}") {
+            values.push_str("}");
+            input = input.slice(2..);
+        } else if input.starts_with('{') {
+            let (rest, ()) = replacement_field(input, raw, values)?;
+            input = rest;
+        } else if input.is_empty() {
+            return Ok((input, ()));
+        } else {
+            return Err(error(input, ErrorKind::Char));
+        }
+    }
+}
+
+/// `{expression[=][!conversion][:format_spec]}` in an f-string
+fn replacement_field<'input>(
+    input: Input<'input>,
+    raw: bool,
+    values: &mut JoinedValues<'input>,
+) -> ParseResult<'input, ()> {
+    let (rest, _open) = char('{').parse(input)?;
+    let (rest, value) = preceded(super::ws, alt((yield_expr, star_expressions))).parse(rest)?;
+    let (rest, _sp) = super::ws(rest)?;
+
+    // Self-documenting expression, e.g. `{x = }` is rendered as `x = 1`
+    let (rest, debug) = opt(pair(char('='), super::ws)).parse(rest)?;
+    if debug.is_some() {
+        values.push_str(&input[1..input.len() - rest.len()]);
+    }
+
+    let (rest, conversion) = opt(preceded(char('!'), one_of("sra"))).parse(rest)?;
+    let (rest, format_spec) = match char::<_, Error<Input>>(':').parse(rest) {
+        Ok((start, _colon)) => {
+            let mut spec = JoinedValues::default();
+            let (rest, ()) = f_string(start, raw, true, &mut spec)?;
+            (
+                rest,
+                Some(Box::new(spec.into_joined_str(start.span_to(&rest)))),
+            )
+        }
+        Err(_) => (rest, None),
+    };
+    let (rest, _close) = char('}').parse(rest)?;
+
+    let conversion = match conversion {
+        None if debug.is_some() && format_spec.is_none() => Some('r'),
+        conversion => conversion,
+    };
+    values.push_value(Expr::FormattedValue {
+        value: Box::new(value),
+        conversion,
+        format_spec,
+        span: input.span_to(&rest),
+    });
+    Ok((rest, ()))
+}
+
+/// Decode escape sequences, e.g. `\n` or `\x41`
+///
+/// In bytes, `\u`, `\U` and `\N` are not escape sequences,
+/// and characters are in `0..=255`.
+fn unescape(body: &str, bytes: bool) -> Option<String> {
+    let mut out = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        let Some(c) = chars.next() else {
+            out.push('\\');
+            break;
+        };
+        let mut code = |len: usize, radix: u32| -> Option<char> {
+            let digits: String = (0..len).map_while(|_| chars.next()).collect();
+            if digits.len() != len {
+                return None;
+            }
+            char::from_u32(u32::from_str_radix(&digits, radix).ok()?)
+        };
+        match c {
+            '\n' => {}
+            '\r' => {
+                chars.next_if_eq(&'\n');
+            }
+            '\\' | '\'' | '"' => out.push(c),
+            'a' => out.push('\x07'),
+            'b' => out.push('\x08'),
+            'f' => out.push('\x0c'),
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            'v' => out.push('\x0b'),
+            'x' => out.push(code(2, 16)?),
+            'u' if !bytes => out.push(code(4, 16)?),
+            'U' if !bytes => out.push(code(8, 16)?),
+            // `\N{EM DASH}`
+            'N' if !bytes => {
+                if chars.next() != Some('{') {
+                    return None;
+                }
+                let mut name = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        c => name.push(c),
+                    }
+                }
+                out.push(unicode_names2::character(&name)?);
+            }
+            '0'..='7' => {
+                let mut value = c.to_digit(8)?;
+                for _ in 0..2 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                if bytes && value > 0xff {
+                    return None;
+                }
+                out.push(char::from_u32(value)?);
+            }
+            _ => {
+                out.push('\\');
+                out.push(c);
+            }
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::{combinator::all_consuming, Finish};
+
+    fn constant(input: &str) -> Constant<'_> {
+        match all_consuming(strings)
+            .parse(Input::new(input))
+            .finish()
+            .unwrap()
+            .1
+        {
+            Expr::Constant { value, .. } => value,
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_number() {
+        let number = |input| {
+            all_consuming(number)
+                .parse(Input::new(input))
+                .finish()
+                .unwrap()
+                .1
+        };
+        assert_eq!(number("42"), Constant::Int(42));
+        assert_eq!(number("1_000"), Constant::Int(1000));
+        assert_eq!(number("0"), Constant::Int(0));
+        assert_eq!(number("0xff"), Constant::Int(255));
+        assert_eq!(number("0o_17"), Constant::Int(15));
+        assert_eq!(number("0B101"), Constant::Int(5));
+        assert_eq!(number("1."), Constant::Float(1.0));
+        assert_eq!(number(".5"), Constant::Float(0.5));
+        assert_eq!(number("1e3"), Constant::Float(1000.0));
+        assert_eq!(number("1_0.2_5E-1"), Constant::Float(1.025));
+        assert_eq!(
+            number("2j"),
+            Constant::Complex {
+                real: 0.0,
+                imag: 2.0
+            }
+        );
+        assert_eq!(
+            number("1.5e1J"),
+            Constant::Complex {
+                real: 0.0,
+                imag: 15.0
+            }
+        );
+
+        assert!(all_consuming(super::number)
+            .parse(Input::new("1__0"))
+            .is_err());
+        assert!(all_consuming(super::number)
+            .parse(Input::new("1_"))
+            .is_err());
+        assert_eq!(
+            number("99999999999999999999"),
+            Constant::BigInt("99999999999999999999".into())
+        );
+        assert_eq!(
+            number("0xFFFF_FFFF_FFFF_FFFF"),
+            Constant::BigInt("18446744073709551615".into())
+        );
+        assert_eq!(
+            number("0o1000000000000000000000"),
+            Constant::BigInt("9223372036854775808".into())
+        );
+    }
+
+    #[test]
+    fn parse_string() {
+        assert_eq!(
+            constant(r#""""document""""#),
+            Constant::String("document".into())
+        );
+        assert_eq!(constant("'single'"), Constant::String("single".into()));
+        assert_eq!(
+            constant(
+                r#""a\tb\x41\101é\
+c""#
+            ),
+            Constant::String("a\tbAAéc".into())
+        );
+        assert_eq!(constant(r"r'\d+'"), Constant::String(r"\d+".into()));
+        assert_eq!(constant(r#"'it\'s'"#), Constant::String("it's".into()));
+        assert_eq!(
+            constant("'a' \"b\"\n    '''c'''"),
+            Constant::String("abc".into())
+        );
+        assert_eq!(
+            constant(r#"b"\x80" rB'\n'"#),
+            Constant::Bytes(vec![0x80, b'\\', b'n'])
+        );
+
+        assert!(strings(Input::new("'unterminated")).is_err());
+        assert!(strings(Input::new("'a\nb'")).is_err());
+        assert!(strings(Input::new("b'a' 'b'")).is_err());
+        assert!(strings(Input::new("ub''")).is_err());
+        assert_eq!(
+            constant(r"'\N{EM DASH}\N{latin small letter a}'"),
+            Constant::String("—a".into())
+        );
+        assert_eq!(
+            constant(r"b'\N{DASH}'"),
+            Constant::Bytes(br"\N{DASH}".to_vec())
+        );
+        assert!(strings(Input::new(r"'\N{NO SUCH NAME}'")).is_err());
+        assert!(strings(Input::new(r"'\N{EM DASH'")).is_err());
+        assert!(strings(Input::new("b'é'")).is_err());
+        assert!(strings(Input::new("rb'é'")).is_err());
+    }
+
+    #[test]
+    fn parse_f_string() {
+        let (rest, joined) = strings(Input::new(r#""a{{" f"{x!r:>{width}} {y = }" '}'"#))
+            .finish()
+            .unwrap();
+        assert_eq!(rest, "");
+        insta::assert_debug_snapshot!(joined, @r###"
+        JoinedStr {
+            values: [
+                Constant {
+                    value: String(
+                        "a{{",
+                    ),
+                    span: 0..34,
+                },
+                FormattedValue {
+                    value: Name {
+                        id: "x",
+                        span: 9..10,
+                    },
+                    conversion: Some(
+                        'r',
+                    ),
+                    format_spec: Some(
+                        JoinedStr {
+                            values: [
+                                Constant {
+                                    value: String(
+                                        ">",
+                                    ),
+                                    span: 13..21,
+                                },
+                                FormattedValue {
+                                    value: Name {
+                                        id: "width",
+                                        span: 15..20,
+                                    },
+                                    conversion: None,
+                                    format_spec: None,
+                                    span: 14..21,
+                                },
+                            ],
+                            span: 13..21,
+                        },
+                    ),
+                    span: 8..22,
+                },
+                Constant {
+                    value: String(
+                        " y = ",
+                    ),
+                    span: 0..34,
+                },
+                FormattedValue {
+                    value: Name {
+                        id: "y",
+                        span: 24..25,
+                    },
+                    conversion: Some(
+                        'r',
+                    ),
+                    format_spec: None,
+                    span: 23..29,
+                },
+                Constant {
+                    value: String(
+                        "}",
+                    ),
+                    span: 0..34,
+                },
+            ],
+            span: 0..34,
+        }
+        "###);
+    }
+}
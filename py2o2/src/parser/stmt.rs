@@ -0,0 +1,753 @@
+use super::{builtin::*, class_def::*, expr::*, function_def::*, Input, ParseResult};
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::*,
+    combinator::{all_consuming, cut, eof, not, opt, recognize},
+    multi::{many0, many1, separated_list1},
+    sequence::{delimited, preceded, terminated, tuple},
+    Parser, Slice,
+};
+
+/// Statement in a module or a block
+///
+/// ```text
+/// stmt = FunctionDef(...) | ClassDef(...) | Import(alias* names) | ...
+/// ```
+///
+/// Statements which do not affect the interface of the module,
+/// e.g. `return` or `for` loops, are kept as [Stmt::Other] with its source.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+pub enum Stmt<'input> {
+    FunctionDef(Box<FunctionDef<'input>>),
+    AsyncFunctionDef(Box<FunctionDef<'input>>),
+    ClassDef(ClassDef<'input>),
+    /// `Import(alias* names)`
+    Import {
+        names: Vec<Alias<'input>>,
+    },
+    /// `ImportFrom(identifier? module, alias* names, int? level)`
+    ImportFrom {
+        module: Option<Cow<'input, str>>,
+        names: Vec<Alias<'input>>,
+        level: usize,
+    },
+    /// `Assign(expr* targets, expr value, string? type_comment)`
+    Assign {
+        targets: Vec<Expr<'input>>,
+        value: Expr<'input>,
+    },
+    /// `AnnAssign(expr target, expr annotation, expr? value, int simple)`
+    AnnAssign {
+        target: Expr<'input>,
+        annotation: Expr<'input>,
+        value: Option<Expr<'input>>,
+    },
+    /// `If(expr test, stmt* body, stmt* orelse)`, where `elif` is nested in `orelse`
+    If {
+        test: Expr<'input>,
+        body: Vec<Stmt<'input>>,
+        orelse: Vec<Stmt<'input>>,
+    },
+    /// `Expr(expr value)`
+    Expr {
+        value: Expr<'input>,
+    },
+    Other(Cow<'input, str>),
+}
+
+/// `alias = (identifier name, identifier? asname)`
+#[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+pub struct Alias<'input> {
+    /// Dotted name, e.g. `collections.abc`
+    pub name: Cow<'input, str>,
+    pub asname: Option<Cow<'input, str>>,
+}
+
+/// Dotted name, e.g. `collections.abc`
+pub fn dotted_name(input: Input<'_>) -> ParseResult<'_, Cow<'_, str>> {
+    recognize(separated_list1(char('.'), identifier))
+        .map(|name: Input| normalize_identifier(name.fragment()))
+        .parse(input)
+}
+
+pub fn alias(input: Input<'_>) -> ParseResult<'_, Alias<'_>> {
+    let (input, name) = alt((
+        dotted_name,
+        tag("*").map(|star: Input| star.fragment().into()),
+    ))
+    .parse(input)?;
+    let (input, asname) =
+        opt(tuple((ws1, tag("as"), ws1, identifier)).map(|(_sp1, _as, _sp2, asname)| asname))
+            .parse(input)?;
+    Ok((input, Alias { name, asname }))
+}
+
+fn aliases(input: Input<'_>) -> ParseResult<'_, Vec<Alias<'_>>> {
+    separated_list1(tuple((ws, char(','), ws)), alias).parse(input)
+}
+
+pub fn import(input: Input<'_>) -> ParseResult<'_, Stmt<'_>> {
+    let (input, _import) = tag("import").parse(input)?;
+    let (input, _sp) = ws1(input)?;
+    let (input, names) = aliases(input)?;
+    Ok((input, Stmt::Import { names }))
+}
+
+pub fn import_from(input: Input<'_>) -> ParseResult<'_, Stmt<'_>> {
+    let (input, _from) = tag("from").parse(input)?;
+    let (input, _sp) = ws1(input)?;
+    let (input, dots) = many0(char('.')).parse(input)?;
+    let (input, module) = opt(dotted_name).parse(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, _import) = tag("import").parse(input)?;
+    let (input, _sp) = ws(input)?;
+    let (input, names) = alt((
+        delimited(
+            tuple((char('('), ws)),
+            aliases,
+            tuple((ws, opt(char(',')), ws, char(')'))),
+        ),
+        aliases,
+    ))
+    .parse(input)?;
+    Ok((
+        input,
+        Stmt::ImportFrom {
+            module,
+            names,
+            level: dots.len(),
+        },
+    ))
+}
+
+pub fn assign(input: Input<'_>) -> ParseResult<'_, Stmt<'_>> {
+    // `=` but not `==` of a comparison in the value, e.g. `x = a == b`
+    let eq = terminated(char('='), not(char('=')));
+    let (input, targets) =
+        many1(tuple((star_targets, ws, eq, ws)).map(|(target, _sp1, _eq, _sp2)| target))
+            .parse(input)?;
+    let (input, value) = alt((yield_expr, star_expressions)).parse(input)?;
+    Ok((input, Stmt::Assign { targets, value }))
+}
+
+pub fn ann_assign(input: Input<'_>) -> ParseResult<'_, Stmt<'_>> {
+    let (input, target) = expr(input)?;
+    let (input, _colon) = tuple((ws, char(':'), ws)).parse(input)?;
+    let (input, annotation) = type_(input)?;
+    let (input, value) = opt(
+        tuple((ws, char('='), ws, alt((yield_expr, star_expressions))))
+            .map(|(_sp1, _eq, _sp2, value)| value),
+    )
+    .parse(input)?;
+    Ok((
+        input,
+        Stmt::AnnAssign {
+            target,
+            annotation,
+            value,
+        },
+    ))
+}
+
+/// Statement fitting in a logical line, e.g. `import sys` or `x = 1`
+pub fn simple_stmt(input: Input<'_>) -> ParseResult<'_, Stmt<'_>> {
+    alt((
+        import,
+        import_from,
+        assign,
+        ann_assign,
+        alt((yield_expr, star_expressions)).map(|value| Stmt::Expr { value }),
+    ))
+    .parse(input)
+}
+
+/// Split the first logical line, i.e. lines joined by brackets, strings or backslashes
+///
+/// Returns the line without a trailing comment, and the input after the newline.
+pub(crate) fn logical_line(input: Input<'_>) -> (Input<'_>, Input<'_>) {
+    let bytes = input.as_bytes();
+    let mut depth = 0usize;
+    let mut comment = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                comment.get_or_insert(i);
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            quote @ (b'\'' | b'"') => {
+                let triple = bytes[i..].starts_with(&[quote; 3]);
+                i += if triple { 3 } else { 1 };
+                while i < bytes.len() {
+                    if bytes[i] == b'\\' {
+                        i += 2;
+                        continue;
+                    }
+                    if triple && bytes[i..].starts_with(&[quote; 3]) {
+                        i += 3;
+                        break;
+                    }
+                    if !triple && (bytes[i] == quote || bytes[i] == b'\n') {
+                        i += 1;
+                        break;
+                    }
+                    i += 1;
+                }
+                continue;
+            }
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            b'\\' => i += 1,
+            b'\n' if depth == 0 => {
+                let line = input.slice(..comment.unwrap_or(i));
+                return (trim_end(line), input.slice(i + 1..));
+            }
+            b'\n' => comment = None,
+            _ => {}
+        }
+        i += 1;
+    }
+    let i = i.min(input.len());
+    (
+        trim_end(input.slice(..comment.unwrap_or(i))),
+        input.slice(i..),
+    )
+}
+
+fn trim_end(input: Input<'_>) -> Input<'_> {
+    input.slice(..input.trim_end().len())
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+/// Skip empty lines and lines only with a comment
+fn skip_blank_lines(mut input: Input<'_>) -> Input<'_> {
+    loop {
+        let end = input.find('\n').map_or(input.len(), |newline| newline + 1);
+        let (line, rest) = (input[..end].trim(), input.slice(end..));
+        if !(line.is_empty() || line.starts_with('#')) || input.is_empty() {
+            return input;
+        }
+        input = rest;
+    }
+}
+
+/// Statements in the first logical line, i.e. a definition or simple statements separated by `;`
+pub fn statements(input: Input<'_>) -> ParseResult<'_, Vec<Stmt<'_>>> {
+    let keyword = |kw: &str| {
+        input
+            .strip_prefix(kw)
+            .is_some_and(|rest| rest.starts_with([' ', '\t']))
+    };
+    // `async with` and `async for` are left as other statements
+    let async_def = keyword("async") && input["async".len()..].trim_start().starts_with("def");
+    if input.starts_with('@') || keyword("def") || async_def || keyword("class") {
+        return alt((
+            function_def.map(|f| Stmt::FunctionDef(Box::new(f))),
+            async_function_def.map(|f| Stmt::AsyncFunctionDef(Box::new(f))),
+            class_def.map(Stmt::ClassDef),
+        ))
+        .map(|stmt| vec![stmt])
+        .parse(input);
+    }
+    let (line, rest) = logical_line(input);
+    Ok((rest, simple_stmts(line)))
+}
+
+/// Simple statements separated by `;`, e.g. `x = 1; y = 2;`
+///
+/// The rest of the line from the first unsupported statement is kept as a single [Stmt::Other].
+fn simple_stmts(mut line: Input<'_>) -> Vec<Stmt<'_>> {
+    let mut stmts = Vec::new();
+    while !line.is_empty() {
+        let end = alt((eof, terminated(tag(";"), space0)));
+        match tuple((simple_stmt, space0, end)).parse(line) {
+            Ok((rest, (stmt, _, _))) => {
+                stmts.push(stmt);
+                line = rest;
+            }
+            Err(_) => {
+                stmts.push(Stmt::Other(line.fragment().into()));
+                break;
+            }
+        }
+    }
+    stmts
+}
+
+/// `if` statement with `elif` and `else` clauses at the indentation `indent`
+///
+/// `input` starts from `if` or `elif` after the indentation.
+fn if_stmt(input: Input<'_>, indent: usize) -> ParseResult<'_, Stmt<'_>> {
+    let (input, _if) = alt((reserved("if"), reserved("elif"))).parse(input)?;
+    let (input, test) = cut(preceded(ws, named_expr)).parse(input)?;
+    let (input, _colon) = cut(tuple((ws, char(':')))).parse(input)?;
+    let (input, body) = cut(suite)(input)?;
+
+    let next = skip_blank_lines(input);
+    if indentation(&next) != indent {
+        return Ok((
+            input,
+            Stmt::If {
+                test,
+                body,
+                orelse: Vec::new(),
+            },
+        ));
+    }
+    let clause = next.slice(indent..);
+    let (input, orelse) = if reserved("elif")(clause).is_ok() {
+        let (input, elif) = if_stmt(clause, indent)?;
+        (input, vec![elif])
+    } else if let Ok((clause, _else)) = tuple((reserved("else"), ws, char(':'))).parse(clause) {
+        suite(clause)?
+    } else {
+        (input, Vec::new())
+    };
+    Ok((input, Stmt::If { test, body, orelse }))
+}
+
+/// Statements sharing the indentation of the first non-blank line
+///
+/// Parsing stops at a line less indented than the first one.
+/// The bodies of compound statements other than `def`, `class` and `if`,
+/// e.g. `for` or `with`, are skipped.
+pub fn block(input: Input<'_>) -> ParseResult<'_, Vec<Stmt<'_>>> {
+    let mut input = skip_blank_lines(input);
+    let indent = indentation(&input);
+    let mut stmts = Vec::new();
+    while !input.is_empty() && indentation(&input) == indent {
+        let line = input.slice(indent..);
+        let (rest, line_stmts) = if reserved("if")(line).is_ok() {
+            let (rest, stmt) = if_stmt(line, indent)?;
+            (rest, vec![stmt])
+        } else {
+            statements(line)?
+        };
+        input = skip_blank_lines(rest);
+        if indentation(&input) > indent {
+            // Only the header of a compound statement, e.g. `for x in xs:`, is followed by its body
+            if !matches!(line_stmts.last(), Some(Stmt::Other(header)) if header.ends_with(':')) {
+                // The last line of a parsed body is indented deeper than this block
+                let consumed = &line[..line.len() - rest.len()];
+                let last_line = consumed.trim_end().rsplit('\n').next().unwrap_or_default();
+                let kind = if indentation(last_line) > indent {
+                    nom::error::ErrorKind::Eof
+                } else {
+                    nom::error::ErrorKind::Verify
+                };
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input.slice(indentation(&input)..),
+                    kind,
+                )));
+            }
+            while !input.is_empty() && indentation(&input) > indent {
+                input = skip_blank_lines(logical_line(input).1);
+            }
+        }
+        stmts.extend(line_stmts);
+    }
+    Ok((input, stmts))
+}
+
+/// Body following `:` of `def` or `class`, either in the same line or as an indented block
+pub fn suite(input: Input<'_>) -> ParseResult<'_, Vec<Stmt<'_>>> {
+    let (input, _sp) = space0(input)?;
+    let (input, _comment) = opt(tuple((char('#'), not_line_ending))).parse(input)?;
+    if let Ok((input, _newline)) = line_ending::<_, nom::error::Error<_>>(input) {
+        return block(input);
+    }
+    statements(input)
+}
+
+/// Statements of the whole module
+pub fn module(input: Input<'_>) -> ParseResult<'_, Vec<Stmt<'_>>> {
+    all_consuming(block).parse(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::test::CheckParsed, *};
+
+    #[test]
+    fn parse_import() {
+        insta::assert_debug_snapshot!(simple_stmt(Input::new("import collections.abc as abc, sys")).check_parsed(), @r###"
+        Import {
+            names: [
+                Alias {
+                    name: "collections.abc",
+                    asname: Some(
+                        "abc",
+                    ),
+                },
+                Alias {
+                    name: "sys",
+                    asname: None,
+                },
+            ],
+        }
+        "###);
+        insta::assert_debug_snapshot!(simple_stmt(Input::new("from ..typing import (Any,\n    NoReturn as Never,\n)")).check_parsed(), @r###"
+        ImportFrom {
+            module: Some(
+                "typing",
+            ),
+            names: [
+                Alias {
+                    name: "Any",
+                    asname: None,
+                },
+                Alias {
+                    name: "NoReturn",
+                    asname: Some(
+                        "Never",
+                    ),
+                },
+            ],
+            level: 2,
+        }
+        "###);
+    }
+
+    #[test]
+    fn parse_assign() {
+        insta::assert_debug_snapshot!(simple_stmt(Input::new(r#"UserId = NewType("UserId", int)"#)).check_parsed(), @r###"
+        Assign {
+            targets: [
+                Name {
+                    id: "UserId",
+                    span: 0..6,
+                },
+            ],
+            value: Call {
+                func: Name {
+                    id: "NewType",
+                    span: 9..16,
+                },
+                args: [
+                    Constant {
+                        value: String(
+                            "UserId",
+                        ),
+                        span: 17..25,
+                    },
+                    Name {
+                        id: "int",
+                        span: 27..30,
+                    },
+                ],
+                keywords: [],
+                span: 9..31,
+            },
+        }
+        "###);
+        insta::assert_debug_snapshot!(simple_stmt(Input::new("Vector: TypeAlias = list[float]")).check_parsed(), @r###"
+        AnnAssign {
+            target: Name {
+                id: "Vector",
+                span: 0..6,
+            },
+            annotation: Name {
+                id: "TypeAlias",
+                span: 8..17,
+            },
+            value: Some(
+                Subscript {
+                    value: Name {
+                        id: "list",
+                        span: 20..24,
+                    },
+                    slice: Name {
+                        id: "float",
+                        span: 25..30,
+                    },
+                    span: 20..31,
+                },
+            ),
+        }
+        "###);
+    }
+
+    #[test]
+    fn parse_module() {
+        insta::assert_debug_snapshot!(module(
+            Input::new(r#"
+# comment
+import typing
+
+
+@typing.overload
+def f(x: int) -> int: ...
+def f(
+    x,
+):
+    """Docstring
+with unindented line
+    """
+    for i in range(3):
+        print(i)
+
+    return x
+
+
+class C(Base):
+    def g(self): pass
+"#)
+        ).check_parsed(), @r###"
+        [
+            Import {
+                names: [
+                    Alias {
+                        name: "typing",
+                        asname: None,
+                    },
+                ],
+            },
+            FunctionDef(
+                FunctionDef {
+                    name: "f",
+                    args: Arguments {
+                        args: [
+                            Arg {
+                                name: "x",
+                                ty: Some(
+                                    Name {
+                                        id: "int",
+                                        span: 53..56,
+                                    },
+                                ),
+                                default: None,
+                                type_comment: None,
+                                span: 50..56,
+                            },
+                        ],
+                        positional_only: [],
+                        keyword_only: [],
+                        var_args: None,
+                        kw_args: None,
+                    },
+                    returns: Some(
+                        Name {
+                            id: "int",
+                            span: 61..64,
+                        },
+                    ),
+                    decorators: [
+                        Attribute {
+                            value: Name {
+                                id: "typing",
+                                span: 28..34,
+                            },
+                            attr: "overload",
+                            span: 28..43,
+                        },
+                    ],
+                    body: [
+                        Expr {
+                            value: Ellipsis {
+                                span: 66..69,
+                            },
+                        },
+                    ],
+                    type_comment: None,
+                    span: 44..69,
+                },
+            ),
+            FunctionDef(
+                FunctionDef {
+                    name: "f",
+                    args: Arguments {
+                        args: [
+                            Arg {
+                                name: "x",
+                                ty: None,
+                                default: None,
+                                type_comment: None,
+                                span: 81..82,
+                            },
+                        ],
+                        positional_only: [],
+                        keyword_only: [],
+                        var_args: None,
+                        kw_args: None,
+                    },
+                    returns: None,
+                    decorators: [],
+                    body: [
+                        Expr {
+                            value: Constant {
+                                value: String(
+                                    "Docstring\nwith unindented line\n    ",
+                                ),
+                                span: 91..132,
+                            },
+                        },
+                        Other(
+                            "for i in range(3):",
+                        ),
+                        Other(
+                            "return x",
+                        ),
+                    ],
+                    type_comment: None,
+                    span: 70..186,
+                },
+            ),
+            ClassDef(
+                ClassDef {
+                    name: "C",
+                    bases: [
+                        Name {
+                            id: "Base",
+                            span: 197..201,
+                        },
+                    ],
+                    keywords: [],
+                    decorators: [],
+                    body: [
+                        FunctionDef(
+                            FunctionDef {
+                                name: "g",
+                                args: Arguments {
+                                    args: [
+                                        Arg {
+                                            name: "self",
+                                            ty: None,
+                                            default: None,
+                                            type_comment: None,
+                                            span: 214..218,
+                                        },
+                                    ],
+                                    positional_only: [],
+                                    keyword_only: [],
+                                    var_args: None,
+                                    kw_args: None,
+                                },
+                                returns: None,
+                                decorators: [],
+                                body: [
+                                    Other(
+                                        "pass",
+                                    ),
+                                ],
+                                type_comment: None,
+                                span: 208..225,
+                            },
+                        ),
+                    ],
+                    span: 189..225,
+                },
+            ),
+        ]
+        "###);
+    }
+
+    #[test]
+    fn parse_if() {
+        insta::assert_debug_snapshot!(module(Input::new(r#"
+if TYPE_CHECKING:
+    import a
+elif x \
+        and y:  # comment
+    import b
+else:
+    for i in xs:
+        import c
+"#)).check_parsed(), @r###"
+[
+    If {
+        test: Name {
+            id: "TYPE_CHECKING",
+            span: 4..17,
+        },
+        body: [
+            Import {
+                names: [
+                    Alias {
+                        name: "a",
+                        asname: None,
+                    },
+                ],
+            },
+        ],
+        orelse: [
+            If {
+                test: BoolOp {
+                    op: And,
+                    values: [
+                        Name {
+                            id: "x",
+                            span: 37..38,
+                        },
+                        Name {
+                            id: "y",
+                            span: 53..54,
+                        },
+                    ],
+                    span: 37..54,
+                },
+                body: [
+                    Import {
+                        names: [
+                            Alias {
+                                name: "b",
+                                asname: None,
+                            },
+                        ],
+                    },
+                ],
+                orelse: [
+                    Other(
+                        "for i in xs:",
+                    ),
+                ],
+            },
+        ],
+    },
+]
+"###);
+    }
+
+    #[test]
+    fn parse_async_def() {
+        let (_, stmts) = module(Input::new("@cache(maxsize=1)\nasync def f(): ...\n")).unwrap();
+        assert!(matches!(&stmts[..], [Stmt::AsyncFunctionDef(f)] if f.decorators.len() == 1));
+    }
+
+    #[test]
+    fn parse_async_with() {
+        let (_, stmts) = module(Input::new("async with lock:\n    x = 1\n")).unwrap();
+        assert!(matches!(&stmts[..], [Stmt::Other(header)] if header == "async with lock:"));
+    }
+
+    #[test]
+    fn parse_semicolons() {
+        let (_, stmts) =
+            module(Input::new("x = a == b; y: int = 1;\nz = 0; del z; w = 1\n")).unwrap();
+        assert!(matches!(
+            &stmts[..],
+            [
+                Stmt::Assign { value: Expr::Compare { .. }, .. },
+                Stmt::AnnAssign { .. },
+                Stmt::Assign { .. },
+                Stmt::Other(rest),
+            ] if rest == "del z; w = 1"
+        ));
+    }
+
+    #[test]
+    fn unexpected_indent() {
+        assert!(module(Input::new("import a\n    import b\n")).is_err());
+        assert!(module(Input::new("if x:\n    import a\n  import b\n")).is_err());
+    }
+}
@@ -0,0 +1,958 @@
+//! Render the syntax tree back to Python source as `ast.unparse` does
+//!
+//! Nodes implement [std::fmt::Display], and [unparse] renders a whole module.
+//! Parentheses are inserted only where the precedence requires them,
+//! so that parsing the output gives the same tree.
+//!
+//! Statements not parsed into the tree, i.e. [Stmt::Other], are written as they are,
+//! where headers of compound statements such as `for x in xs:` are followed by `...` as their body.
+
+use super::{
+    Alias, Arg, Arguments, BoolOperator, ClassDef, CmpOp, Comprehension, Constant, Expr,
+    FunctionDef, Keyword, Operator, Stmt, UnaryOperator,
+};
+use std::fmt::{self, Display, Formatter, Write};
+
+/// Precedence of expressions from the loosest to the tightest
+///
+/// The same as `_Precedence` of the `ast` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    /// `:=`
+    NamedExpr,
+    /// `,` without parentheses
+    Tuple,
+    Yield,
+    /// `if`-`else` and `lambda`
+    Test,
+    Or,
+    And,
+    Not,
+    Cmp,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
+    Arith,
+    Term,
+    /// Unary `+`, `-` and `~`
+    Factor,
+    Power,
+    Await,
+    Atom,
+}
+
+impl Precedence {
+    fn next(self) -> Self {
+        use Precedence::*;
+        match self {
+            NamedExpr => Tuple,
+            Tuple => Yield,
+            Yield => Test,
+            Test => Or,
+            Or => And,
+            And => Not,
+            Not => Cmp,
+            Cmp => BitOr,
+            BitOr => BitXor,
+            BitXor => BitAnd,
+            BitAnd => Shift,
+            Shift => Arith,
+            Arith => Term,
+            Term => Factor,
+            Factor => Power,
+            Power => Await,
+            Await | Atom => Atom,
+        }
+    }
+}
+
+impl Operator {
+    fn precedence(self) -> Precedence {
+        match self {
+            Operator::Add | Operator::Sub => Precedence::Arith,
+            Operator::Mult
+            | Operator::MatMult
+            | Operator::Div
+            | Operator::Mod
+            | Operator::FloorDiv => Precedence::Term,
+            Operator::Pow => Precedence::Power,
+            Operator::LShift | Operator::RShift => Precedence::Shift,
+            Operator::BitOr => Precedence::BitOr,
+            Operator::BitXor => Precedence::BitXor,
+            Operator::BitAnd => Precedence::BitAnd,
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mult => "*",
+            Operator::MatMult => "@",
+            Operator::Div => "/",
+            Operator::Mod => "%",
+            Operator::Pow => "**",
+            Operator::LShift => "<<",
+            Operator::RShift => ">>",
+            Operator::BitOr => "|",
+            Operator::BitXor => "^",
+            Operator::BitAnd => "&",
+            Operator::FloorDiv => "//",
+        })
+    }
+}
+
+impl Display for BoolOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BoolOperator::And => "and",
+            BoolOperator::Or => "or",
+        })
+    }
+}
+
+impl Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UnaryOperator::Invert => "~",
+            UnaryOperator::Not => "not ",
+            UnaryOperator::UAdd => "+",
+            UnaryOperator::USub => "-",
+        })
+    }
+}
+
+impl Display for CmpOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CmpOp::Eq => "==",
+            CmpOp::NotEq => "!=",
+            CmpOp::Lt => "<",
+            CmpOp::LtE => "<=",
+            CmpOp::Gt => ">",
+            CmpOp::GtE => ">=",
+            CmpOp::Is => "is",
+            CmpOp::IsNot => "is not",
+            CmpOp::In => "in",
+            CmpOp::NotIn => "not in",
+        })
+    }
+}
+
+/// Floating point number which is read back as the same value
+fn write_float(f: &mut Formatter<'_>, value: f64) -> fmt::Result {
+    if value.is_infinite() {
+        // as `ast.unparse` does, since `inf` is not a literal
+        f.write_str("1e309")
+    } else {
+        write!(f, "{:?}", value)
+    }
+}
+
+/// Body of a string literal quoted by `quote`, escaped as `repr` does
+fn write_escaped(f: &mut impl Write, s: &str, quote: &str) -> fmt::Result {
+    let single = quote.len() == 1;
+    for c in s.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '\n' if single => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if quote.starts_with(c) => write!(f, "\\{}", c)?,
+            c if c.is_control() && c != '\n' => match c as u32 {
+                n @ 0..=0xff => write!(f, "\\x{:02x}", n)?,
+                n => write!(f, "\\u{:04x}", n)?,
+            },
+            c => f.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+/// String literal as `repr`, i.e. quoted by `'` unless the string contains only `'`
+fn write_string(f: &mut Formatter<'_>, s: &str) -> fmt::Result {
+    let quote = if s.contains('\'') && !s.contains('"') {
+        "\""
+    } else {
+        "'"
+    };
+    f.write_str(quote)?;
+    write_escaped(f, s, quote)?;
+    f.write_str(quote)
+}
+
+impl Display for Constant<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Constant::String(s) => write_string(f, s),
+            Constant::Bytes(b) => write!(f, "b'{}'", b.escape_ascii()),
+            Constant::Float(value) => write_float(f, *value),
+            Constant::Int(value) => write!(f, "{}", value),
+            Constant::BigInt(value) => write!(f, "{}", value),
+            Constant::Complex { real, imag } => {
+                if *real != 0.0 {
+                    f.write_char('(')?;
+                    write_float(f, *real)?;
+                    f.write_char('+')?;
+                }
+                write_float(f, *imag)?;
+                f.write_char('j')?;
+                if *real != 0.0 {
+                    f.write_char(')')?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Expr<'_> {
+    fn precedence(&self) -> Precedence {
+        match self {
+            Expr::NamedExpr { .. } => Precedence::NamedExpr,
+            Expr::Tuple { elts, .. } if !elts.is_empty() => Precedence::Tuple,
+            Expr::Yield { .. } | Expr::YieldFrom { .. } => Precedence::Yield,
+            Expr::IfExp { .. } | Expr::Lambda { .. } => Precedence::Test,
+            Expr::BoolOp {
+                op: BoolOperator::Or,
+                ..
+            } => Precedence::Or,
+            Expr::BoolOp {
+                op: BoolOperator::And,
+                ..
+            } => Precedence::And,
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                ..
+            } => Precedence::Not,
+            Expr::UnaryOp { .. } => Precedence::Factor,
+            Expr::Compare { .. } => Precedence::Cmp,
+            Expr::Starred { .. } => Precedence::BitOr,
+            Expr::BinOp { op, .. } => op.precedence(),
+            Expr::Await { .. } => Precedence::Await,
+            _ => Precedence::Atom,
+        }
+    }
+}
+
+/// Writes an expression in the context requiring `precedence`, with parentheses if looser
+struct Prec<'a, 'input>(&'a Expr<'input>, Precedence);
+
+impl Display for Prec<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Prec(e, precedence) = *self;
+        if e.precedence() < precedence {
+            write!(f, "({})", Prec(e, Precedence::NamedExpr))
+        } else {
+            write_expr(f, e)
+        }
+    }
+}
+
+/// Items separated by `, `
+struct Commas<I>(I);
+
+impl<I, T> Display for Commas<I>
+where
+    I: Clone + IntoIterator<Item = T>,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.0.clone().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+fn tests<'a, 'input>(
+    elts: &'a [Expr<'input>],
+) -> Commas<impl Clone + Iterator<Item = Prec<'a, 'input>>> {
+    Commas(elts.iter().map(|e| Prec(e, Precedence::Test)))
+}
+
+/// `(args, keywords)` of a call or a class definition
+fn write_call_args(
+    f: &mut Formatter<'_>,
+    args: &[Expr<'_>],
+    keywords: &[Keyword<'_>],
+) -> fmt::Result {
+    write!(f, "({}", tests(args))?;
+    for (i, keyword) in keywords.iter().enumerate() {
+        if i > 0 || !args.is_empty() {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", keyword)?;
+    }
+    f.write_char(')')
+}
+
+fn write_generators(f: &mut Formatter<'_>, generators: &[Comprehension<'_>]) -> fmt::Result {
+    for generator in generators {
+        write!(f, " {}", generator)?;
+    }
+    Ok(())
+}
+
+/// Slice of a subscript, where a tuple is written without parentheses as `x[a, b:c]`
+fn write_slice(f: &mut Formatter<'_>, slice: &Expr<'_>) -> fmt::Result {
+    match slice {
+        Expr::Tuple { elts, .. } if elts.len() == 1 => {
+            write!(f, "{},", Prec(&elts[0], Precedence::Test))
+        }
+        Expr::Tuple { elts, .. } if !elts.is_empty() => write!(f, "{}", tests(elts)),
+        slice => write!(f, "{}", Prec(slice, Precedence::Test)),
+    }
+}
+
+fn write_expr(f: &mut Formatter<'_>, e: &Expr<'_>) -> fmt::Result {
+    use Precedence as P;
+    match e {
+        Expr::Name { id, .. } => f.write_str(id),
+        Expr::Constant { value, .. } => write!(f, "{}", value),
+        Expr::None { .. } => f.write_str("None"),
+        Expr::Ellipsis { .. } => f.write_str("..."),
+        Expr::Tuple { elts, .. } => match &elts[..] {
+            [] => f.write_str("()"),
+            [elt] => write!(f, "{},", Prec(elt, P::Test)),
+            elts => write!(f, "{}", tests(elts)),
+        },
+        Expr::List { elts, .. } => write!(f, "[{}]", tests(elts)),
+        // `{}` is a dict
+        Expr::Set { elts, .. } if elts.is_empty() => f.write_str("{*()}"),
+        Expr::Set { elts, .. } => write!(f, "{{{}}}", tests(elts)),
+        Expr::Dict { keys, values, .. } => {
+            f.write_char('{')?;
+            for (i, (key, value)) in keys.iter().zip(values).enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                match key {
+                    Some(key) => write!(f, "{}: {}", Prec(key, P::Test), Prec(value, P::Test))?,
+                    None => write!(f, "**{}", Prec(value, P::BitOr))?,
+                }
+            }
+            f.write_char('}')
+        }
+        Expr::ListComp {
+            elt, generators, ..
+        } => {
+            write!(f, "[{}", Prec(elt, P::Test))?;
+            write_generators(f, generators)?;
+            f.write_char(']')
+        }
+        Expr::SetComp {
+            elt, generators, ..
+        } => {
+            write!(f, "{{{}", Prec(elt, P::Test))?;
+            write_generators(f, generators)?;
+            f.write_char('}')
+        }
+        Expr::DictComp {
+            key,
+            value,
+            generators,
+            ..
+        } => {
+            write!(f, "{{{}: {}", Prec(key, P::Test), Prec(value, P::Test))?;
+            write_generators(f, generators)?;
+            f.write_char('}')
+        }
+        Expr::GeneratorExp {
+            elt, generators, ..
+        } => {
+            write!(f, "({}", Prec(elt, P::Test))?;
+            write_generators(f, generators)?;
+            f.write_char(')')
+        }
+        Expr::Compare {
+            left,
+            ops,
+            comparators,
+            ..
+        } => {
+            write!(f, "{}", Prec(left, P::Cmp.next()))?;
+            for (op, right) in ops.iter().zip(comparators) {
+                write!(f, " {} {}", op, Prec(right, P::Cmp.next()))?;
+            }
+            Ok(())
+        }
+        Expr::Attribute { value, attr, .. } => match value.as_ref() {
+            // `1.real` is a syntax error
+            Expr::Constant {
+                value: Constant::Int(_) | Constant::BigInt(_),
+                ..
+            } => write!(f, "({}).{}", value, attr),
+            value => write!(f, "{}.{}", Prec(value, P::Atom), attr),
+        },
+        Expr::Call {
+            func,
+            args,
+            keywords,
+            ..
+        } => {
+            write!(f, "{}", Prec(func, P::Atom))?;
+            write_call_args(f, args, keywords)
+        }
+        Expr::Subscript { value, slice, .. } => {
+            write!(f, "{}[", Prec(value, P::Atom))?;
+            write_slice(f, slice)?;
+            f.write_char(']')
+        }
+        Expr::Slice {
+            lower, upper, step, ..
+        } => {
+            let bound = |bound: &Option<Box<Expr>>| {
+                bound
+                    .as_deref()
+                    .map(|e| Prec(e, P::Test).to_string())
+                    .unwrap_or_default()
+            };
+            write!(f, "{}:{}", bound(lower), bound(upper))?;
+            if step.is_some() {
+                write!(f, ":{}", bound(step))?;
+            }
+            Ok(())
+        }
+        Expr::BinOp {
+            left, op, right, ..
+        } => {
+            let precedence = op.precedence();
+            // `**` is right associative, and the others are left associative
+            let (left_precedence, right_precedence) = if *op == Operator::Pow {
+                (precedence.next(), precedence)
+            } else {
+                (precedence, precedence.next())
+            };
+            write!(
+                f,
+                "{} {} {}",
+                Prec(left, left_precedence),
+                op,
+                Prec(right, right_precedence)
+            )
+        }
+        Expr::BoolOp { op, values, .. } => {
+            let precedence = e.precedence().next();
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", op)?;
+                }
+                write!(f, "{}", Prec(value, precedence))?;
+            }
+            Ok(())
+        }
+        Expr::UnaryOp { op, operand, .. } => {
+            write!(f, "{}{}", op, Prec(operand, e.precedence()))
+        }
+        Expr::NamedExpr { target, value, .. } => {
+            write!(f, "{} := {}", Prec(target, P::Atom), Prec(value, P::Test))
+        }
+        Expr::Lambda { args, body, .. } => {
+            f.write_str("lambda")?;
+            if !args.is_empty() {
+                write!(f, " {}", args)?;
+            }
+            write!(f, ": {}", Prec(body, P::Test))
+        }
+        Expr::IfExp {
+            test, body, orelse, ..
+        } => write!(
+            f,
+            "{} if {} else {}",
+            Prec(body, P::Test.next()),
+            Prec(test, P::Test.next()),
+            Prec(orelse, P::Test)
+        ),
+        Expr::Await { value, .. } => write!(f, "await {}", Prec(value, P::Atom)),
+        Expr::Yield { value: None, .. } => f.write_str("yield"),
+        Expr::Yield {
+            value: Some(value), ..
+        } => write!(f, "yield {}", Prec(value, P::Tuple)),
+        Expr::YieldFrom { value, .. } => write!(f, "yield from {}", Prec(value, P::Test)),
+        Expr::Starred { value, .. } => write!(f, "*{}", Prec(value, P::BitOr)),
+        Expr::JoinedStr { values, .. } => write_f_string(f, values),
+        Expr::FormattedValue { .. } => write_f_string(f, std::slice::from_ref(e)),
+    }
+}
+
+/// f-string quoted by one not used in the replacement fields,
+/// since Python before 3.12 does not allow to reuse the quote in them
+fn write_f_string(f: &mut Formatter<'_>, values: &[Expr<'_>]) -> fmt::Result {
+    let mut fields = String::new();
+    collect_fields(&mut fields, values)?;
+    let quote = ["'", "\"", "'''", "\"\"\""]
+        .into_iter()
+        .find(|quote| !fields.contains(quote))
+        .unwrap_or("'");
+    let mut body = String::new();
+    write_f_string_body(&mut body, values, quote)?;
+    // A triple quote cannot be closed right after the same quote
+    if quote.len() == 3 && body.ends_with(&quote[..1]) {
+        body.pop();
+        write!(body, "\\{}", &quote[..1])?;
+    }
+    write!(f, "f{}{}{}", quote, body, quote)
+}
+
+/// Source of the expressions in the replacement fields, to choose the quote
+fn collect_fields(out: &mut String, values: &[Expr<'_>]) -> fmt::Result {
+    for value in values {
+        if let Expr::FormattedValue {
+            value, format_spec, ..
+        } = value
+        {
+            write!(out, "{}", value)?;
+            if let Some(Expr::JoinedStr { values, .. }) = format_spec.as_deref() {
+                collect_fields(out, values)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_f_string_body(out: &mut String, values: &[Expr<'_>], quote: &str) -> fmt::Result {
+    for value in values {
+        match value {
+            Expr::Constant {
+                value: Constant::String(text),
+                ..
+            } => {
+                let mut escaped = String::new();
+                write_escaped(&mut escaped, text, quote)?;
+                out.push_str(&escaped.replace('{', "{{").replace('}', "}}"));
+            }
+            Expr::FormattedValue {
+                value,
+                conversion,
+                format_spec,
+                ..
+            } => {
+                let field = Prec(value, Precedence::Test.next()).to_string();
+                // `{{` is an escaped brace
+                let space = if field.starts_with('{') { " " } else { "" };
+                write!(out, "{{{}{}", space, field)?;
+                if let Some(conversion) = conversion {
+                    write!(out, "!{}", conversion)?;
+                }
+                if let Some(Expr::JoinedStr { values, .. }) = format_spec.as_deref() {
+                    out.push(':');
+                    write_f_string_body(out, values, quote)?;
+                }
+                out.push('}');
+            }
+            other => write!(out, "{{{}}}", Prec(other, Precedence::Test.next()))?,
+        }
+    }
+    Ok(())
+}
+
+/// Expression as `ast.unparse`, e.g. a tuple is parenthesized
+impl Display for Expr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Prec(self, Precedence::Test))
+    }
+}
+
+impl Display for Keyword<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.arg {
+            Some(arg) => write!(f, "{}={}", arg, Prec(&self.value, Precedence::Test)),
+            None => write!(f, "**{}", Prec(&self.value, Precedence::BitOr)),
+        }
+    }
+}
+
+impl Display for Comprehension<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_async {
+            f.write_str("async ")?;
+        }
+        write!(
+            f,
+            "for {} in {}",
+            Prec(&self.target, Precedence::Tuple),
+            Prec(&self.iter, Precedence::Test.next())
+        )?;
+        for test in &self.ifs {
+            write!(f, " if {}", Prec(test, Precedence::Test.next()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parameter as `inspect.Parameter`, e.g. `x: int = 1` or `x=1`
+impl Display for Arg<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        if let Some(ty) = &self.ty {
+            write!(f, ": {}", ty)?;
+        }
+        match (&self.default, &self.ty) {
+            (Some(default), Some(_)) => write!(f, " = {}", default),
+            (Some(default), None) => write!(f, "={}", default),
+            (None, _) => Ok(()),
+        }
+    }
+}
+
+impl Arguments<'_> {
+    fn is_empty(&self) -> bool {
+        self.args.is_empty()
+            && self.positional_only.is_empty()
+            && self.keyword_only.is_empty()
+            && self.var_args.is_none()
+            && self.kw_args.is_none()
+    }
+}
+
+/// Parameters without parentheses, e.g. `x, /, y, *args, z, **kwargs`
+impl Display for Arguments<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut params: Vec<String> = self.positional_only.iter().map(Arg::to_string).collect();
+        if !params.is_empty() {
+            params.push("/".to_string());
+        }
+        params.extend(self.args.iter().map(Arg::to_string));
+        match &self.var_args {
+            Some(arg) => params.push(format!("*{}", arg)),
+            None if !self.keyword_only.is_empty() => params.push("*".to_string()),
+            None => {}
+        }
+        params.extend(self.keyword_only.iter().map(Arg::to_string));
+        params.extend(self.kw_args.iter().map(|arg| format!("**{}", arg)));
+        write!(f, "{}", Commas(&params))
+    }
+}
+
+impl Display for Alias<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        if let Some(asname) = &self.asname {
+            write!(f, " as {}", asname)?;
+        }
+        Ok(())
+    }
+}
+
+const INDENT: &str = "    ";
+
+/// Lines of statements indented by `depth` levels, each ending with a newline
+fn write_block(f: &mut Formatter<'_>, stmts: &[Stmt<'_>], depth: usize) -> fmt::Result {
+    if stmts.is_empty() {
+        return writeln!(f, "{}pass", INDENT.repeat(depth));
+    }
+    for stmt in stmts {
+        write_stmt(f, stmt, depth)?;
+    }
+    Ok(())
+}
+
+fn write_function_def(
+    f: &mut Formatter<'_>,
+    def: &FunctionDef<'_>,
+    is_async: bool,
+    depth: usize,
+) -> fmt::Result {
+    let indent = INDENT.repeat(depth);
+    for decorator in &def.decorators {
+        writeln!(f, "{}@{}", indent, Prec(decorator, Precedence::NamedExpr))?;
+    }
+    let async_ = if is_async { "async " } else { "" };
+    write!(f, "{}{}def {}({})", indent, async_, def.name, def.args)?;
+    if let Some(returns) = &def.returns {
+        write!(f, " -> {}", returns)?;
+    }
+    write!(f, ":")?;
+    // Type comments of arguments are dropped as `ast.unparse` does
+    if let Some(type_comment) = &def.type_comment {
+        write!(f, "  # type: {}", type_comment)?;
+    }
+    writeln!(f)?;
+    write_block(f, &def.body, depth + 1)
+}
+
+fn write_class_def(f: &mut Formatter<'_>, def: &ClassDef<'_>, depth: usize) -> fmt::Result {
+    let indent = INDENT.repeat(depth);
+    for decorator in &def.decorators {
+        writeln!(f, "{}@{}", indent, Prec(decorator, Precedence::NamedExpr))?;
+    }
+    write!(f, "{}class {}", indent, def.name)?;
+    if !def.bases.is_empty() || !def.keywords.is_empty() {
+        write_call_args(f, &def.bases, &def.keywords)?;
+    }
+    writeln!(f, ":")?;
+    write_block(f, &def.body, depth + 1)
+}
+
+fn write_stmt(f: &mut Formatter<'_>, stmt: &Stmt<'_>, depth: usize) -> fmt::Result {
+    use Precedence as P;
+    let indent = INDENT.repeat(depth);
+    match stmt {
+        Stmt::FunctionDef(def) => write_function_def(f, def, false, depth),
+        Stmt::AsyncFunctionDef(def) => write_function_def(f, def, true, depth),
+        Stmt::ClassDef(def) => write_class_def(f, def, depth),
+        Stmt::Import { names } => writeln!(f, "{}import {}", indent, Commas(names)),
+        Stmt::ImportFrom {
+            module,
+            names,
+            level,
+        } => writeln!(
+            f,
+            "{}from {}{} import {}",
+            indent,
+            ".".repeat(*level),
+            module.as_deref().unwrap_or_default(),
+            Commas(names)
+        ),
+        Stmt::Assign { targets, value } => {
+            f.write_str(&indent)?;
+            for target in targets {
+                write!(f, "{} = ", Prec(target, P::Tuple))?;
+            }
+            writeln!(f, "{}", Prec(value, P::Tuple))
+        }
+        Stmt::AnnAssign {
+            target,
+            annotation,
+            value,
+        } => {
+            write!(f, "{}{}: {}", indent, Prec(target, P::Atom), annotation)?;
+            if let Some(value) = value {
+                write!(f, " = {}", Prec(value, P::Tuple))?;
+            }
+            writeln!(f)
+        }
+        Stmt::If { test, body, orelse } => {
+            writeln!(f, "{}if {}:", indent, Prec(test, P::NamedExpr))?;
+            write_block(f, body, depth + 1)?;
+            let mut orelse = orelse;
+            loop {
+                match &orelse[..] {
+                    [] => return Ok(()),
+                    [Stmt::If {
+                        test,
+                        body,
+                        orelse: rest,
+                    }] => {
+                        writeln!(f, "{}elif {}:", indent, Prec(test, P::NamedExpr))?;
+                        write_block(f, body, depth + 1)?;
+                        orelse = rest;
+                    }
+                    stmts => {
+                        writeln!(f, "{}else:", indent)?;
+                        return write_block(f, stmts, depth + 1);
+                    }
+                }
+            }
+        }
+        Stmt::Expr { value } => writeln!(f, "{}{}", indent, Prec(value, P::Tuple)),
+        Stmt::Other(source) => {
+            writeln!(f, "{}{}", indent, source)?;
+            // The body of the compound statement is not kept
+            if source.ends_with(':') {
+                writeln!(f, "{}{}...", indent, INDENT)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Statement with its body, ending with a newline
+impl Display for Stmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_stmt(f, self, 0)
+    }
+}
+
+/// Definition with its decorators and body, ending with a newline
+impl Display for FunctionDef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_function_def(f, self, false, 0)
+    }
+}
+
+/// Definition with its body, ending with a newline
+impl Display for ClassDef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_class_def(f, self, 0)
+    }
+}
+
+/// Python source of a module, which is parsed into the same statements by [super::module]
+pub fn unparse(stmts: &[Stmt<'_>]) -> String {
+    stmts.iter().map(Stmt::to_string).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::{module, named_expr, test::CheckParsed, Input};
+
+    /// Parse, print, and check the printed one is parsed into the same tree
+    #[track_caller]
+    fn round_trip(source: &str) -> String {
+        let stmts = module(Input::new(source)).check_parsed();
+        let printed = unparse(&stmts);
+        let reparsed = module(Input::new(&printed)).check_parsed();
+        assert_eq!(stmts, reparsed, "{}", printed);
+        printed
+    }
+
+    #[track_caller]
+    fn expr(source: &str) -> String {
+        let e = named_expr(Input::new(source)).check_parsed();
+        let printed = e.to_string();
+        assert_eq!(
+            named_expr(Input::new(&printed)).check_parsed(),
+            e,
+            "{}",
+            printed
+        );
+        printed
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(expr("(a + b) * c"), "(a + b) * c");
+        assert_eq!(expr("a + (b * c)"), "a + b * c");
+        assert_eq!(expr("a - (b - c)"), "a - (b - c)");
+        assert_eq!(expr("(a ** b) ** c"), "(a ** b) ** c");
+        assert_eq!(expr("a ** (b ** c)"), "a ** b ** c");
+        assert_eq!(expr("-x ** 2"), "-x ** 2");
+        assert_eq!(expr("(-x) ** 2"), "(-x) ** 2");
+        assert_eq!(expr("not (a and b) or c"), "not (a and b) or c");
+        assert_eq!(expr("(a < b) < c"), "(a < b) < c");
+        assert_eq!(
+            expr("a if (b if c else d) else e"),
+            "a if (b if c else d) else e"
+        );
+        assert_eq!(expr("(lambda: x)()"), "(lambda: x)()");
+        assert_eq!(expr("(await x).y"), "(await x).y");
+        assert_eq!(expr("(1).real"), "(1).real");
+        assert_eq!(expr("(x := 1)"), "(x := 1)");
+        assert_eq!(
+            expr("f((x := 1), *a, k=(y := 2), **kw)"),
+            "f((x := 1), *a, k=(y := 2), **kw)"
+        );
+    }
+
+    #[test]
+    fn displays() {
+        assert_eq!(expr("(1, 2)"), "(1, 2)");
+        assert_eq!(expr("(1,)"), "(1,)");
+        assert_eq!(expr("()"), "()");
+        assert_eq!(expr("x[1:2, ::3, a,]"), "x[1:2, ::3, a]");
+        assert_eq!(expr("x[a,]"), "x[a,]");
+        assert_eq!(expr("x[(a, b)]"), "x[a, b]");
+        assert_eq!(
+            expr("{**a, 'b': [*c], 'd': {e}}"),
+            "{**a, 'b': [*c], 'd': {e}}"
+        );
+        assert_eq!(
+            expr("[x async for x, y in z if x if y]"),
+            "[x async for x, y in z if x if y]"
+        );
+        assert_eq!(
+            expr("{k: v for k, v in (yield)}"),
+            "{k: v for k, v in (yield)}"
+        );
+        assert_eq!(expr("(x for x in xs)"), "(x for x in xs)");
+        assert_eq!(
+            expr("lambda a, /, b=1, *c, d, **e: a"),
+            "lambda a, /, b=1, *c, d, **e: a"
+        );
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(expr("'it\\'s'"), "\"it's\"");
+        assert_eq!(expr("'\"\\'\\n\\t\\x00'"), "'\"\\'\\n\\t\\x00'");
+        assert_eq!(expr("b'\\x00\\''"), "b'\\x00\\''");
+        assert_eq!(
+            expr("0x10 + 1_0.5e3 + 2j + 1e400"),
+            "16 + 10500.0 + 2.0j + 1e309"
+        );
+        assert_eq!(expr("f'{x!r:>{width}} {{}}'"), "f'{x!r:>{width}} {{}}'");
+        assert_eq!(expr("f\"{x['k']}\""), "f\"{x['k']}\"");
+        assert_eq!(expr("f'{ {1}}'"), "f'{ {1}}'");
+        assert_eq!(expr("f'{(lambda: 1)()}'"), "f'{(lambda: 1)()}'");
+    }
+
+    #[test]
+    fn statements() {
+        insta::assert_snapshot!(round_trip(r#"
+import os.path as p, sys
+from .. import (a as b,)
+from .c import *
+
+@decorator(1)
+@property
+async def f(x: int, /, y: str = 'y', *args, z: list[int], **kwargs) -> tuple[int, ...]:
+    x = y = yield 1, 2
+    x: int
+    x.y: Optional[str] = None
+    for x in y:
+        pass
+    if (n := len(x)) > 1:
+        return n
+    elif x:
+        print(*x, sep='')
+    else:
+        if y:
+            ...
+        pass
+
+class C(Base, metaclass=Meta):
+    def g(self): ...
+"#), @r###"
+import os.path as p, sys
+from .. import a as b
+from .c import *
+@decorator(1)
+@property
+async def f(x: int, /, y: str = 'y', *args, z: list[int], **kwargs) -> tuple[int, ...]:
+    x = y = yield 1, 2
+    x: int
+    x.y: Optional[str] = None
+    for x in y:
+        ...
+    if (n := len(x)) > 1:
+        return n
+    elif x:
+        print(*x, sep='')
+    else:
+        if y:
+            ...
+        pass
+class C(Base, metaclass=Meta):
+    def g(self):
+        ...
+"###);
+    }
+
+    #[test]
+    fn type_comments() {
+        insta::assert_snapshot!(round_trip("def f(a, b):\n    # type: (int, str) -> bool\n    return a\n"), @r###"
+        def f(a, b):  # type: (int, str) -> bool
+            return a
+        "###);
+    }
+
+    #[test]
+    fn python_sources() {
+        let root = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+        for entry in std::fs::read_dir(root).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "py") {
+                round_trip(&std::fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+}
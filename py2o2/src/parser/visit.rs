@@ -0,0 +1,344 @@
+//! Syntax tree traversal by shared borrow, in the style of [syn::visit](https://docs.rs/syn/latest/syn/visit/)
+//!
+//! Each method of [Visit] defaults to the free function of the same name,
+//! which visits the children of the node in the order they appear in the source.
+//! Override a method to inspect the node, and call the free function to keep walking into it.
+//!
+//! Operators, identifiers and spans are leaves, and are not visited separately.
+
+use super::{
+    Alias, Arg, Arguments, ClassDef, Comprehension, Constant, Expr, FunctionDef, Keyword, Stmt,
+};
+
+/// Traversal over a syntax tree borrowed for `'ast`, see the [module documentation](self)
+pub trait Visit<'ast> {
+    fn visit_stmts(&mut self, i: &'ast [Stmt<'ast>]) {
+        visit_stmts(self, i)
+    }
+    fn visit_stmt(&mut self, i: &'ast Stmt<'ast>) {
+        visit_stmt(self, i)
+    }
+    fn visit_function_def(&mut self, i: &'ast FunctionDef<'ast>) {
+        visit_function_def(self, i)
+    }
+    fn visit_class_def(&mut self, i: &'ast ClassDef<'ast>) {
+        visit_class_def(self, i)
+    }
+    fn visit_alias(&mut self, i: &'ast Alias<'ast>) {
+        visit_alias(self, i)
+    }
+    fn visit_arguments(&mut self, i: &'ast Arguments<'ast>) {
+        visit_arguments(self, i)
+    }
+    fn visit_arg(&mut self, i: &'ast Arg<'ast>) {
+        visit_arg(self, i)
+    }
+    fn visit_expr(&mut self, i: &'ast Expr<'ast>) {
+        visit_expr(self, i)
+    }
+    fn visit_constant(&mut self, i: &'ast Constant<'ast>) {
+        visit_constant(self, i)
+    }
+    fn visit_keyword(&mut self, i: &'ast Keyword<'ast>) {
+        visit_keyword(self, i)
+    }
+    fn visit_comprehension(&mut self, i: &'ast Comprehension<'ast>) {
+        visit_comprehension(self, i)
+    }
+}
+
+pub fn visit_stmts<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, stmts: &'ast [Stmt<'ast>]) {
+    for stmt in stmts {
+        v.visit_stmt(stmt);
+    }
+}
+
+pub fn visit_stmt<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, stmt: &'ast Stmt<'ast>) {
+    match stmt {
+        Stmt::FunctionDef(f) | Stmt::AsyncFunctionDef(f) => v.visit_function_def(f),
+        Stmt::ClassDef(c) => v.visit_class_def(c),
+        Stmt::Import { names } | Stmt::ImportFrom { names, .. } => {
+            for alias in names {
+                v.visit_alias(alias);
+            }
+        }
+        Stmt::Assign { targets, value } => {
+            for target in targets {
+                v.visit_expr(target);
+            }
+            v.visit_expr(value);
+        }
+        Stmt::AnnAssign {
+            target,
+            annotation,
+            value,
+        } => {
+            v.visit_expr(target);
+            v.visit_expr(annotation);
+            if let Some(value) = value {
+                v.visit_expr(value);
+            }
+        }
+        Stmt::If { test, body, orelse } => {
+            v.visit_expr(test);
+            v.visit_stmts(body);
+            v.visit_stmts(orelse);
+        }
+        Stmt::Expr { value } => v.visit_expr(value),
+        Stmt::Other(_) => {}
+    }
+}
+
+pub fn visit_function_def<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, f: &'ast FunctionDef<'ast>) {
+    for decorator in &f.decorators {
+        v.visit_expr(decorator);
+    }
+    v.visit_arguments(&f.args);
+    if let Some(returns) = &f.returns {
+        v.visit_expr(returns);
+    }
+    v.visit_stmts(&f.body);
+}
+
+pub fn visit_class_def<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, c: &'ast ClassDef<'ast>) {
+    for decorator in &c.decorators {
+        v.visit_expr(decorator);
+    }
+    for base in &c.bases {
+        v.visit_expr(base);
+    }
+    for keyword in &c.keywords {
+        v.visit_keyword(keyword);
+    }
+    v.visit_stmts(&c.body);
+}
+
+pub fn visit_alias<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _alias: &'ast Alias<'ast>) {}
+
+pub fn visit_arguments<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, args: &'ast Arguments<'ast>) {
+    for arg in args.positional_only.iter().chain(&args.args) {
+        v.visit_arg(arg);
+    }
+    if let Some(arg) = &args.var_args {
+        v.visit_arg(arg);
+    }
+    for arg in &args.keyword_only {
+        v.visit_arg(arg);
+    }
+    if let Some(arg) = &args.kw_args {
+        v.visit_arg(arg);
+    }
+}
+
+pub fn visit_arg<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, arg: &'ast Arg<'ast>) {
+    if let Some(ty) = &arg.ty {
+        v.visit_expr(ty);
+    }
+    if let Some(default) = &arg.default {
+        v.visit_expr(default);
+    }
+}
+
+pub fn visit_expr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, expr: &'ast Expr<'ast>) {
+    match expr {
+        Expr::Name { .. } | Expr::None { .. } | Expr::Ellipsis { .. } => {}
+        Expr::Constant { value, .. } => v.visit_constant(value),
+        Expr::Tuple { elts, .. }
+        | Expr::List { elts, .. }
+        | Expr::Set { elts, .. }
+        | Expr::JoinedStr { values: elts, .. }
+        | Expr::BoolOp { values: elts, .. } => {
+            for elt in elts {
+                v.visit_expr(elt);
+            }
+        }
+        Expr::Compare {
+            left, comparators, ..
+        } => {
+            v.visit_expr(left);
+            for comparator in comparators {
+                v.visit_expr(comparator);
+            }
+        }
+        Expr::Attribute { value, .. }
+        | Expr::UnaryOp { operand: value, .. }
+        | Expr::Await { value, .. }
+        | Expr::YieldFrom { value, .. }
+        | Expr::Starred { value, .. } => v.visit_expr(value),
+        Expr::Call {
+            func,
+            args,
+            keywords,
+            ..
+        } => {
+            v.visit_expr(func);
+            for arg in args {
+                v.visit_expr(arg);
+            }
+            for keyword in keywords {
+                v.visit_keyword(keyword);
+            }
+        }
+        Expr::Subscript { value, slice, .. } => {
+            v.visit_expr(value);
+            v.visit_expr(slice);
+        }
+        Expr::BinOp { left, right, .. } => {
+            v.visit_expr(left);
+            v.visit_expr(right);
+        }
+        Expr::NamedExpr { target, value, .. } => {
+            v.visit_expr(target);
+            v.visit_expr(value);
+        }
+        Expr::Lambda { args, body, .. } => {
+            v.visit_arguments(args);
+            v.visit_expr(body);
+        }
+        // `body if test else orelse`
+        Expr::IfExp {
+            test, body, orelse, ..
+        } => {
+            v.visit_expr(body);
+            v.visit_expr(test);
+            v.visit_expr(orelse);
+        }
+        Expr::Dict { keys, values, .. } => {
+            for (key, value) in keys.iter().zip(values) {
+                if let Some(key) = key {
+                    v.visit_expr(key);
+                }
+                v.visit_expr(value);
+            }
+        }
+        Expr::ListComp {
+            elt, generators, ..
+        }
+        | Expr::SetComp {
+            elt, generators, ..
+        }
+        | Expr::GeneratorExp {
+            elt, generators, ..
+        } => {
+            v.visit_expr(elt);
+            for generator in generators {
+                v.visit_comprehension(generator);
+            }
+        }
+        Expr::DictComp {
+            key,
+            value,
+            generators,
+            ..
+        } => {
+            v.visit_expr(key);
+            v.visit_expr(value);
+            for generator in generators {
+                v.visit_comprehension(generator);
+            }
+        }
+        Expr::Yield { value, .. } => {
+            if let Some(value) = value {
+                v.visit_expr(value);
+            }
+        }
+        Expr::FormattedValue {
+            value, format_spec, ..
+        } => {
+            v.visit_expr(value);
+            if let Some(format_spec) = format_spec {
+                v.visit_expr(format_spec);
+            }
+        }
+        Expr::Slice {
+            lower, upper, step, ..
+        } => {
+            for bound in [lower, upper, step].into_iter().flatten() {
+                v.visit_expr(bound);
+            }
+        }
+    }
+}
+
+pub fn visit_constant<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _constant: &'ast Constant<'ast>) {}
+
+pub fn visit_keyword<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, keyword: &'ast Keyword<'ast>) {
+    v.visit_expr(&keyword.value);
+}
+
+pub fn visit_comprehension<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    comprehension: &'ast Comprehension<'ast>,
+) {
+    v.visit_expr(&comprehension.target);
+    v.visit_expr(&comprehension.iter);
+    for test in &comprehension.ifs {
+        v.visit_expr(test);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::{module, test::CheckParsed, Input};
+
+    /// Names referenced in the tree
+    #[derive(Default)]
+    struct Names<'ast>(Vec<&'ast str>);
+
+    impl<'ast> Visit<'ast> for Names<'ast> {
+        fn visit_expr(&mut self, expr: &'ast Expr<'ast>) {
+            if let Expr::Name { id, .. } = expr {
+                self.0.push(id);
+            }
+            visit_expr(self, expr);
+        }
+    }
+
+    #[test]
+    fn collect_names() {
+        let stmts = module(Input::new(
+            "@a.b\ndef f(x: c = d, /, *args: e, k: f[g], **kw) -> h | None:\n    y = [i for j in k if l]\n",
+        ))
+        .check_parsed();
+        let mut names = Names::default();
+        names.visit_stmts(&stmts);
+        insta::assert_debug_snapshot!(names.0, @r###"
+        [
+            "a",
+            "c",
+            "d",
+            "e",
+            "f",
+            "g",
+            "h",
+            "y",
+            "i",
+            "j",
+            "k",
+            "l",
+        ]
+        "###);
+
+        let stmts = module(Input::new(
+            "x: t = {k: v, **m}\nif y:\n    z = a if b else c\n",
+        ))
+        .check_parsed();
+        let mut names = Names::default();
+        names.visit_stmts(&stmts);
+        insta::assert_debug_snapshot!(names.0, @r###"
+        [
+            "x",
+            "t",
+            "k",
+            "v",
+            "m",
+            "y",
+            "z",
+            "a",
+            "b",
+            "c",
+        ]
+        "###);
+    }
+}
@@ -0,0 +1,374 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TARGET: &str = "annotated";
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json(TARGET)?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "narrow": {
+                "name": "narrow",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "annotated",
+                            "inner": {
+                                "kind": "primitive",
+                                "name": "int"
+                            },
+                            "metadata": [
+                                {
+                                    "kind": "rust",
+                                    "type": "u8"
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "annotated",
+                    "inner": {
+                        "kind": "primitive",
+                        "name": "int"
+                    },
+                    "metadata": [
+                        {
+                            "kind": "rust",
+                            "type": "u8"
+                        }
+                    ]
+                },
+                "doc": null,
+                "signature": "def narrow(x: typing.Annotated[int, Rust(type='u8')]) -> typing.Annotated[int, Rust(type='u8')]",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "other_metadata": {
+                "name": "other_metadata",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": null,
+                "signature": "def other_metadata(x: typing.Annotated[float, 'unit: meter']) -> float",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "rename": {
+                "name": "rename",
+                "parameters": [
+                    {
+                        "name": "type",
+                        "type": {
+                            "kind": "annotated",
+                            "inner": {
+                                "kind": "primitive",
+                                "name": "str"
+                            },
+                            "metadata": [
+                                {
+                                    "kind": "rename",
+                                    "name": "kind"
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": null,
+                "signature": "def rename(type: typing.Annotated[str, Rename(name='kind')]) -> str",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "skipped": {
+                "name": "skipped",
+                "parameters": [],
+                "return": {
+                    "kind": "annotated",
+                    "inner": {
+                        "kind": "none"
+                    },
+                    "metadata": [
+                        {
+                            "kind": "skip"
+                        }
+                    ]
+                },
+                "doc": null,
+                "signature": "def skipped() -> typing.Annotated[NoneType, Skip()]",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "skipped_parameter": {
+                "name": "skipped_parameter",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "annotated",
+                            "inner": {
+                                "kind": "union",
+                                "args": [
+                                    {
+                                        "kind": "primitive",
+                                        "name": "int"
+                                    },
+                                    {
+                                        "kind": "primitive",
+                                        "name": "str"
+                                    }
+                                ]
+                            },
+                            "metadata": [
+                                {
+                                    "kind": "skip"
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "none"
+                },
+                "doc": null,
+                "signature": "def skipped_parameter(x: typing.Annotated[int | str, Skip()]) -> None",
+                "overloads": [],
+                "source": "runtime"
+            }
+        },
+        "type_definitions": {}
+    }
+    "###);
+
+    let interface = Interface::from_json(&json)?;
+    insta::assert_debug_snapshot!(interface, @r###"
+    Interface {
+        functions: {
+            "narrow": Function {
+                name: "narrow",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Annotated {
+                            inner: Primitive(
+                                Int,
+                            ),
+                            metadata: [
+                                Rust {
+                                    type: "u8",
+                                },
+                            ],
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Annotated {
+                    inner: Primitive(
+                        Int,
+                    ),
+                    metadata: [
+                        Rust {
+                            type: "u8",
+                        },
+                    ],
+                },
+                doc: None,
+                signature: Some(
+                    "def narrow(x: typing.Annotated[int, Rust(type='u8')]) -> typing.Annotated[int, Rust(type='u8')]",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "other_metadata": Function {
+                name: "other_metadata",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Primitive(
+                            Float,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Float,
+                ),
+                doc: None,
+                signature: Some(
+                    "def other_metadata(x: typing.Annotated[float, 'unit: meter']) -> float",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "rename": Function {
+                name: "rename",
+                parameters: [
+                    Parameter {
+                        name: "type",
+                        type: Annotated {
+                            inner: Primitive(
+                                Str,
+                            ),
+                            metadata: [
+                                Rename {
+                                    name: "kind",
+                                },
+                            ],
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Str,
+                ),
+                doc: None,
+                signature: Some(
+                    "def rename(type: typing.Annotated[str, Rename(name='kind')]) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "skipped": Function {
+                name: "skipped",
+                parameters: [],
+                return: Annotated {
+                    inner: None,
+                    metadata: [
+                        Skip,
+                    ],
+                },
+                doc: None,
+                signature: Some(
+                    "def skipped() -> typing.Annotated[NoneType, Skip()]",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "skipped_parameter": Function {
+                name: "skipped_parameter",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Annotated {
+                            inner: Union {
+                                args: [
+                                    Primitive(
+                                        Int,
+                                    ),
+                                    Primitive(
+                                        Str,
+                                    ),
+                                ],
+                            },
+                            metadata: [
+                                Skip,
+                            ],
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: None,
+                doc: None,
+                signature: Some(
+                    "def skipped_parameter(x: typing.Annotated[int | str, Skip()]) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+        },
+        type_definitions: {},
+        without_signature: [],
+    }
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module(TARGET)?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::with_markers(
+        "annotated",
+    );
+    /// # Python signature
+    ///
+    /// ```python
+    /// def narrow(x: typing.Annotated[int, Rust(type='u8')]) -> typing.Annotated[int, Rust(type='u8')]
+    /// ```
+    pub fn narrow<'py>(py: ::pyo3::Python<'py>, x: u8) -> ::pyo3::PyResult<u8> {
+        let result = MODULE.getattr(py, "narrow")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def other_metadata(x: typing.Annotated[float, 'unit: meter']) -> float
+    /// ```
+    pub fn other_metadata<'py>(py: ::pyo3::Python<'py>, x: f64) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "other_metadata")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def rename(type: typing.Annotated[str, Rename(name='kind')]) -> str
+    /// ```
+    pub fn rename<'py>(
+        py: ::pyo3::Python<'py>,
+        kind: &str,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "rename")?.call((kind,), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn invalid_rust_type() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let mut interface = Interface::from_py_module(TARGET)?;
+    let narrow = interface.functions.get_mut("narrow").unwrap();
+    narrow.r#return = Type::Annotated {
+        inner: Box::new(Type::Primitive(Primitive::Int)),
+        metadata: vec![Annotation::Rust {
+            r#type: "u8 u16".to_string(),
+        }],
+    };
+    let err = generate(TARGET, &interface, true).unwrap_err();
+    insta::assert_snapshot!(err, @"Invalid Rust type in annotation: u8 u16");
+    Ok(())
+}
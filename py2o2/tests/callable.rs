@@ -24,10 +24,14 @@ fn inspect() -> Result<()> {
                                     "name": "int"
                                 }
                             ],
+                            "keywords": [],
+                            "param_spec": null,
                             "return": {
                                 "kind": "none"
                             }
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     },
                     {
                         "name": "on_error",
@@ -42,15 +46,23 @@ fn inspect() -> Result<()> {
                                     "kind": "exception"
                                 }
                             ],
+                            "keywords": [],
+                            "param_spec": null,
                             "return": {
                                 "kind": "none"
                             }
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "none"
-                }
+                },
+                "doc": null,
+                "signature": "def async_query(on_success: collections.abc.Callable[[int], None], on_error: collections.abc.Callable[[int, Exception], None]) -> None",
+                "overloads": [],
+                "source": "runtime"
             },
             "caller": {
                 "name": "caller",
@@ -69,16 +81,57 @@ fn inspect() -> Result<()> {
                                     "name": "float"
                                 }
                             ],
+                            "keywords": [],
+                            "param_spec": null,
                             "return": {
                                 "kind": "primitive",
                                 "name": "float"
                             }
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "none"
-                }
+                },
+                "doc": null,
+                "signature": "def caller(f: collections.abc.Callable[[int, float], float]) -> None",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "concatenate": {
+                "name": "concatenate",
+                "parameters": [
+                    {
+                        "name": "f",
+                        "type": {
+                            "kind": "callable",
+                            "args": [
+                                {
+                                    "kind": "primitive",
+                                    "name": "int"
+                                }
+                            ],
+                            "keywords": [],
+                            "param_spec": "P",
+                            "return": {
+                                "kind": "primitive",
+                                "name": "float"
+                            }
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": null,
+                "signature": "def concatenate(f: collections.abc.Callable[typing.Concatenate[int, ~P], float]) -> float",
+                "overloads": [],
+                "source": "runtime"
             },
             "ellipsis_callable": {
                 "name": "ellipsis_callable",
@@ -92,15 +145,23 @@ fn inspect() -> Result<()> {
                                     "kind": "ellipsis"
                                 }
                             ],
+                            "keywords": [],
+                            "param_spec": null,
                             "return": {
                                 "kind": "none"
                             }
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "none"
-                }
+                },
+                "doc": null,
+                "signature": "def ellipsis_callable(f: collections.abc.Callable[..., None]) -> None",
+                "overloads": [],
+                "source": "runtime"
             },
             "feeder": {
                 "name": "feeder",
@@ -110,16 +171,150 @@ fn inspect() -> Result<()> {
                         "type": {
                             "kind": "callable",
                             "args": [],
+                            "keywords": [],
+                            "param_spec": null,
                             "return": {
                                 "kind": "primitive",
                                 "name": "str"
                             }
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "none"
-                }
+                },
+                "doc": null,
+                "signature": "def feeder(get_next_item: collections.abc.Callable[[], str]) -> None",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "keyword_callback": {
+                "name": "keyword_callback",
+                "parameters": [
+                    {
+                        "name": "f",
+                        "type": {
+                            "kind": "callable",
+                            "args": [
+                                {
+                                    "kind": "primitive",
+                                    "name": "int"
+                                }
+                            ],
+                            "keywords": [
+                                {
+                                    "name": "prefix",
+                                    "type": {
+                                        "kind": "primitive",
+                                        "name": "str"
+                                    },
+                                    "doc": null
+                                }
+                            ],
+                            "param_spec": null,
+                            "return": {
+                                "kind": "primitive",
+                                "name": "str"
+                            }
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": null,
+                "signature": "def keyword_callback(f: callable.Formatter) -> str",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "unexpected_keyword": {
+                "name": "unexpected_keyword",
+                "parameters": [
+                    {
+                        "name": "f",
+                        "type": {
+                            "kind": "callable",
+                            "args": [
+                                {
+                                    "kind": "primitive",
+                                    "name": "int"
+                                }
+                            ],
+                            "keywords": [
+                                {
+                                    "name": "prefix",
+                                    "type": {
+                                        "kind": "primitive",
+                                        "name": "str"
+                                    },
+                                    "doc": null
+                                }
+                            ],
+                            "param_spec": null,
+                            "return": {
+                                "kind": "primitive",
+                                "name": "str"
+                            }
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": null,
+                "signature": "def unexpected_keyword(f: callable.Formatter) -> str",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "unexpected_positional": {
+                "name": "unexpected_positional",
+                "parameters": [
+                    {
+                        "name": "f",
+                        "type": {
+                            "kind": "callable",
+                            "args": [
+                                {
+                                    "kind": "primitive",
+                                    "name": "int"
+                                }
+                            ],
+                            "keywords": [
+                                {
+                                    "name": "prefix",
+                                    "type": {
+                                        "kind": "primitive",
+                                        "name": "str"
+                                    },
+                                    "doc": null
+                                }
+                            ],
+                            "param_spec": null,
+                            "return": {
+                                "kind": "primitive",
+                                "name": "str"
+                            }
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": null,
+                "signature": "def unexpected_positional(f: callable.Formatter) -> str",
+                "overloads": [],
+                "source": "runtime"
             }
         },
         "type_definitions": {}
@@ -141,8 +336,12 @@ fn inspect() -> Result<()> {
                                     Int,
                                 ),
                             ],
+                            keywords: [],
+                            param_spec: None,
                             return: None,
                         },
+                        doc: None,
+                        inferred: false,
                     },
                     Parameter {
                         name: "on_error",
@@ -153,11 +352,21 @@ fn inspect() -> Result<()> {
                                 ),
                                 Exception,
                             ],
+                            keywords: [],
+                            param_spec: None,
                             return: None,
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: None,
+                doc: None,
+                signature: Some(
+                    "def async_query(on_success: collections.abc.Callable[[int], None], on_error: collections.abc.Callable[[int, Exception], None]) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "caller": Function {
                 name: "caller",
@@ -173,13 +382,56 @@ fn inspect() -> Result<()> {
                                     Float,
                                 ),
                             ],
+                            keywords: [],
+                            param_spec: None,
                             return: Primitive(
                                 Float,
                             ),
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: None,
+                doc: None,
+                signature: Some(
+                    "def caller(f: collections.abc.Callable[[int, float], float]) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "concatenate": Function {
+                name: "concatenate",
+                parameters: [
+                    Parameter {
+                        name: "f",
+                        type: Callable {
+                            args: [
+                                Primitive(
+                                    Int,
+                                ),
+                            ],
+                            keywords: [],
+                            param_spec: Some(
+                                "P",
+                            ),
+                            return: Primitive(
+                                Float,
+                            ),
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Float,
+                ),
+                doc: None,
+                signature: Some(
+                    "def concatenate(f: collections.abc.Callable[typing.Concatenate[int, ~P], float]) -> float",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "ellipsis_callable": Function {
                 name: "ellipsis_callable",
@@ -190,11 +442,21 @@ fn inspect() -> Result<()> {
                             args: [
                                 Ellipsis,
                             ],
+                            keywords: [],
+                            param_spec: None,
                             return: None,
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: None,
+                doc: None,
+                signature: Some(
+                    "def ellipsis_callable(f: collections.abc.Callable[..., None]) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "feeder": Function {
                 name: "feeder",
@@ -203,16 +465,147 @@ fn inspect() -> Result<()> {
                         name: "get_next_item",
                         type: Callable {
                             args: [],
+                            keywords: [],
+                            param_spec: None,
                             return: Primitive(
                                 Str,
                             ),
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: None,
+                doc: None,
+                signature: Some(
+                    "def feeder(get_next_item: collections.abc.Callable[[], str]) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "keyword_callback": Function {
+                name: "keyword_callback",
+                parameters: [
+                    Parameter {
+                        name: "f",
+                        type: Callable {
+                            args: [
+                                Primitive(
+                                    Int,
+                                ),
+                            ],
+                            keywords: [
+                                Parameter {
+                                    name: "prefix",
+                                    type: Primitive(
+                                        Str,
+                                    ),
+                                    doc: None,
+                                    inferred: false,
+                                },
+                            ],
+                            param_spec: None,
+                            return: Primitive(
+                                Str,
+                            ),
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Str,
+                ),
+                doc: None,
+                signature: Some(
+                    "def keyword_callback(f: callable.Formatter) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "unexpected_keyword": Function {
+                name: "unexpected_keyword",
+                parameters: [
+                    Parameter {
+                        name: "f",
+                        type: Callable {
+                            args: [
+                                Primitive(
+                                    Int,
+                                ),
+                            ],
+                            keywords: [
+                                Parameter {
+                                    name: "prefix",
+                                    type: Primitive(
+                                        Str,
+                                    ),
+                                    doc: None,
+                                    inferred: false,
+                                },
+                            ],
+                            param_spec: None,
+                            return: Primitive(
+                                Str,
+                            ),
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Str,
+                ),
+                doc: None,
+                signature: Some(
+                    "def unexpected_keyword(f: callable.Formatter) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "unexpected_positional": Function {
+                name: "unexpected_positional",
+                parameters: [
+                    Parameter {
+                        name: "f",
+                        type: Callable {
+                            args: [
+                                Primitive(
+                                    Int,
+                                ),
+                            ],
+                            keywords: [
+                                Parameter {
+                                    name: "prefix",
+                                    type: Primitive(
+                                        Str,
+                                    ),
+                                    doc: None,
+                                    inferred: false,
+                                },
+                            ],
+                            param_spec: None,
+                            return: Primitive(
+                                Str,
+                            ),
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Str,
+                ),
+                doc: None,
+                signature: Some(
+                    "def unexpected_positional(f: callable.Formatter) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
             },
         },
         type_definitions: {},
+        without_signature: [],
     }
     "###);
 
@@ -224,35 +617,78 @@ fn codegen() -> Result<()> {
     std::env::set_var("PYTHONPATH", PYTHON_ROOT);
     let interface = Interface::from_py_module(TARGET)?;
     insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "callable",
+    );
+    /// # Python signature
+    ///
+    /// ```python
+    /// def async_query(on_success: collections.abc.Callable[[int], None], on_error: collections.abc.Callable[[int, Exception], None]) -> None
+    /// ```
     pub fn async_query<'py>(
         py: ::pyo3::Python<'py>,
-        on_success: impl Fn((i64,)) -> () + Send + 'static,
-        on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) -> () + Send + 'static,
+        on_success: impl Fn((i64,)) + Send + 'static,
+        on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) + Send + 'static,
     ) -> ::pyo3::PyResult<()> {
         let on_success = ::py2o2_runtime::as_pycfunc(py, on_success)?;
         let on_error = ::py2o2_runtime::as_pycfunc(py, on_error)?;
-        let _ = py
-            .import("callable")?
-            .getattr("async_query")?
-            .call((on_success, on_error), None)?;
+        let _ = MODULE.getattr(py, "async_query")?.call((on_success, on_error), None)?;
         Ok(())
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def caller(f: collections.abc.Callable[[int, float], float]) -> None
+    /// ```
     pub fn caller<'py>(
         py: ::pyo3::Python<'py>,
         f: impl Fn((i64, f64)) -> f64 + Send + 'static,
     ) -> ::pyo3::PyResult<()> {
         let f = ::py2o2_runtime::as_pycfunc(py, f)?;
-        let _ = py.import("callable")?.getattr("caller")?.call((f,), None)?;
+        let _ = MODULE.getattr(py, "caller")?.call((f,), None)?;
         Ok(())
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def concatenate(f: collections.abc.Callable[typing.Concatenate[int, ~P], float]) -> float
+    /// ```
+    pub fn concatenate<'py>(
+        py: ::pyo3::Python<'py>,
+        f: impl Fn(
+            (i64,),
+            &::pyo3::types::PyTuple,
+            Option<&::pyo3::types::PyDict>,
+        ) -> f64 + Send + 'static,
+    ) -> ::pyo3::PyResult<f64> {
+        let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+            py,
+            move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                Ok(f(args.get_slice(0, 1).extract()?, args.get_slice(1, args.len()), kwargs))
+            },
+        )?;
+        let result = MODULE.getattr(py, "concatenate")?.call((f,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def ellipsis_callable(f: collections.abc.Callable[..., None]) -> None
+    /// ```
     pub fn ellipsis_callable<'py>(
         py: ::pyo3::Python<'py>,
-        f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) -> () + Send + 'static,
+        f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) + Send + 'static,
     ) -> ::pyo3::PyResult<()> {
         let f = ::py2o2_runtime::as_pycfunc(py, f)?;
-        let _ = py.import("callable")?.getattr("ellipsis_callable")?.call((f,), None)?;
+        let _ = MODULE.getattr(py, "ellipsis_callable")?.call((f,), None)?;
         Ok(())
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def feeder(get_next_item: collections.abc.Callable[[], str]) -> None
+    /// ```
     pub fn feeder<'py>(
         py: ::pyo3::Python<'py>,
         get_next_item: impl Fn() -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
@@ -261,42 +697,172 @@ fn codegen() -> Result<()> {
             py,
             move |_input: [usize; 0]| get_next_item(),
         )?;
-        let _ = py.import("callable")?.getattr("feeder")?.call((get_next_item,), None)?;
+        let _ = MODULE.getattr(py, "feeder")?.call((get_next_item,), None)?;
         Ok(())
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def keyword_callback(f: callable.Formatter) -> str
+    /// ```
+    pub fn keyword_callback<'py>(
+        py: ::pyo3::Python<'py>,
+        f: impl Fn(
+            (i64,),
+            (Option<&str>,),
+        ) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+            py,
+            move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+                Ok(
+                    f(
+                        args.get_slice(0, 1).extract()?,
+                        (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+                    ),
+                )
+            },
+        )?;
+        let result = MODULE.getattr(py, "keyword_callback")?.call((f,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def unexpected_keyword(f: callable.Formatter) -> str
+    /// ```
+    pub fn unexpected_keyword<'py>(
+        py: ::pyo3::Python<'py>,
+        f: impl Fn(
+            (i64,),
+            (Option<&str>,),
+        ) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+            py,
+            move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+                Ok(
+                    f(
+                        args.get_slice(0, 1).extract()?,
+                        (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+                    ),
+                )
+            },
+        )?;
+        let result = MODULE.getattr(py, "unexpected_keyword")?.call((f,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def unexpected_positional(f: callable.Formatter) -> str
+    /// ```
+    pub fn unexpected_positional<'py>(
+        py: ::pyo3::Python<'py>,
+        f: impl Fn(
+            (i64,),
+            (Option<&str>,),
+        ) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+            py,
+            move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+                Ok(
+                    f(
+                        args.get_slice(0, 1).extract()?,
+                        (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+                    ),
+                )
+            },
+        )?;
+        let result = MODULE.getattr(py, "unexpected_positional")?.call((f,), None)?;
+        result.extract()
+    }
     "###);
 
     insta::assert_snapshot!(generate(TARGET, &interface, false)?, @r###"
     pub mod callable {
+        /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+        pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+            "callable",
+        );
+        /// # Python signature
+        ///
+        /// ```python
+        /// def async_query(on_success: collections.abc.Callable[[int], None], on_error: collections.abc.Callable[[int, Exception], None]) -> None
+        /// ```
         pub fn async_query<'py>(
             py: ::pyo3::Python<'py>,
-            on_success: impl Fn((i64,)) -> () + Send + 'static,
-            on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) -> () + Send + 'static,
+            on_success: impl Fn((i64,)) + Send + 'static,
+            on_error: impl Fn((i64, ::pyo3::Py<::pyo3::PyAny>)) + Send + 'static,
         ) -> ::pyo3::PyResult<()> {
             let on_success = ::py2o2_runtime::as_pycfunc(py, on_success)?;
             let on_error = ::py2o2_runtime::as_pycfunc(py, on_error)?;
-            let _ = py
-                .import("callable")?
-                .getattr("async_query")?
-                .call((on_success, on_error), None)?;
+            let _ = MODULE.getattr(py, "async_query")?.call((on_success, on_error), None)?;
             Ok(())
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def caller(f: collections.abc.Callable[[int, float], float]) -> None
+        /// ```
         pub fn caller<'py>(
             py: ::pyo3::Python<'py>,
             f: impl Fn((i64, f64)) -> f64 + Send + 'static,
         ) -> ::pyo3::PyResult<()> {
             let f = ::py2o2_runtime::as_pycfunc(py, f)?;
-            let _ = py.import("callable")?.getattr("caller")?.call((f,), None)?;
+            let _ = MODULE.getattr(py, "caller")?.call((f,), None)?;
             Ok(())
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def concatenate(f: collections.abc.Callable[typing.Concatenate[int, ~P], float]) -> float
+        /// ```
+        pub fn concatenate<'py>(
+            py: ::pyo3::Python<'py>,
+            f: impl Fn(
+                (i64,),
+                &::pyo3::types::PyTuple,
+                Option<&::pyo3::types::PyDict>,
+            ) -> f64 + Send + 'static,
+        ) -> ::pyo3::PyResult<f64> {
+            let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+                py,
+                move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                    Ok(
+                        f(
+                            args.get_slice(0, 1).extract()?,
+                            args.get_slice(1, args.len()),
+                            kwargs,
+                        ),
+                    )
+                },
+            )?;
+            let result = MODULE.getattr(py, "concatenate")?.call((f,), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def ellipsis_callable(f: collections.abc.Callable[..., None]) -> None
+        /// ```
         pub fn ellipsis_callable<'py>(
             py: ::pyo3::Python<'py>,
-            f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) -> () + Send + 'static,
+            f: impl Fn((::pyo3::Py<::pyo3::PyAny>,)) + Send + 'static,
         ) -> ::pyo3::PyResult<()> {
             let f = ::py2o2_runtime::as_pycfunc(py, f)?;
-            let _ = py.import("callable")?.getattr("ellipsis_callable")?.call((f,), None)?;
+            let _ = MODULE.getattr(py, "ellipsis_callable")?.call((f,), None)?;
             Ok(())
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def feeder(get_next_item: collections.abc.Callable[[], str]) -> None
+        /// ```
         pub fn feeder<'py>(
             py: ::pyo3::Python<'py>,
             get_next_item: impl Fn() -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
@@ -305,10 +871,109 @@ fn codegen() -> Result<()> {
                 py,
                 move |_input: [usize; 0]| get_next_item(),
             )?;
-            let _ = py.import("callable")?.getattr("feeder")?.call((get_next_item,), None)?;
+            let _ = MODULE.getattr(py, "feeder")?.call((get_next_item,), None)?;
             Ok(())
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def keyword_callback(f: callable.Formatter) -> str
+        /// ```
+        pub fn keyword_callback<'py>(
+            py: ::pyo3::Python<'py>,
+            f: impl Fn(
+                (i64,),
+                (Option<&str>,),
+            ) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+        ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+            let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+                py,
+                move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                    ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+                    Ok(
+                        f(
+                            args.get_slice(0, 1).extract()?,
+                            (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+                        ),
+                    )
+                },
+            )?;
+            let result = MODULE.getattr(py, "keyword_callback")?.call((f,), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def unexpected_keyword(f: callable.Formatter) -> str
+        /// ```
+        pub fn unexpected_keyword<'py>(
+            py: ::pyo3::Python<'py>,
+            f: impl Fn(
+                (i64,),
+                (Option<&str>,),
+            ) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+        ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+            let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+                py,
+                move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                    ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+                    Ok(
+                        f(
+                            args.get_slice(0, 1).extract()?,
+                            (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+                        ),
+                    )
+                },
+            )?;
+            let result = MODULE.getattr(py, "unexpected_keyword")?.call((f,), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def unexpected_positional(f: callable.Formatter) -> str
+        /// ```
+        pub fn unexpected_positional<'py>(
+            py: ::pyo3::Python<'py>,
+            f: impl Fn(
+                (i64,),
+                (Option<&str>,),
+            ) -> ::pyo3::Py<::pyo3::types::PyString> + Send + 'static,
+        ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+            let f = ::py2o2_runtime::as_pycfunc_with_kwargs(
+                py,
+                move |args: &::pyo3::types::PyTuple, kwargs: Option<&::pyo3::types::PyDict>| {
+                    ::py2o2_runtime::check_arguments(args, kwargs, 1, &["prefix"])?;
+                    Ok(
+                        f(
+                            args.get_slice(0, 1).extract()?,
+                            (::py2o2_runtime::extract_keyword(kwargs, "prefix")?,),
+                        ),
+                    )
+                },
+            )?;
+            let result = MODULE.getattr(py, "unexpected_positional")?.call((f,), None)?;
+            result.extract()
+        }
     }
     "###);
     Ok(())
 }
+
+#[test]
+fn param_spec_with_keywords() {
+    // `def __call__(self, *args: P.args, key: int, **kwargs: P.kwargs)` is forbidden by PEP 612
+    let ty = Type::Callable {
+        args: Vec::new(),
+        keywords: vec![Parameter {
+            name: "key".to_string(),
+            r#type: Type::Primitive(Primitive::Int),
+            doc: None,
+            inferred: false,
+        }],
+        param_spec: Some("P".to_string()),
+        r#return: Box::new(Type::None),
+    };
+    let err = as_input_type(&ty).unwrap_err();
+    insta::assert_snapshot!(err, @"Keyword-only parameters cannot be combined with ParamSpec `P`");
+}
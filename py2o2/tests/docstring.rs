@@ -0,0 +1,414 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TARGET: &str = "docstring";
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json(TARGET)?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "google": {
+                "name": "google",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        "doc": "Number of greetings.",
+                        "inferred": false
+                    },
+                    {
+                        "name": "name",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": "Whom to greet. Long descriptions may continue on the next line.",
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": "Greet in Google style.\n\nArgs:\n    x (int): Number of greetings.\n    name: Whom to greet. Long descriptions\n        may continue on the next line.\n\nReturns:\n    Greeting message.",
+                "signature": "def google(x: int, name: str) -> str",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "numpy": {
+                "name": "numpy",
+                "parameters": [
+                    {
+                        "name": "width",
+                        "type": {
+                            "kind": "user_defined",
+                            "module": "docstring",
+                            "name": "Pixel",
+                            "supertype": {
+                                "kind": "primitive",
+                                "name": "int"
+                            }
+                        },
+                        "doc": "Width of the rectangle.",
+                        "inferred": false
+                    },
+                    {
+                        "name": "height",
+                        "type": {
+                            "kind": "user_defined",
+                            "module": "docstring",
+                            "name": "Pixel",
+                            "supertype": {
+                                "kind": "primitive",
+                                "name": "int"
+                            }
+                        },
+                        "doc": "Height of the rectangle.",
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": "Area of a rectangle in NumPy style.\n\nParameters\n----------\nwidth : Pixel\n    Width of the rectangle.\nheight : Pixel\n    Height of the rectangle.\n\nReturns\n-------\nint\n    Area of the rectangle.",
+                "signature": "def numpy(width: docstring.Pixel, height: docstring.Pixel) -> int",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "sphinx": {
+                "name": "sphinx",
+                "parameters": [
+                    {
+                        "name": "a",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": "The first number.",
+                        "inferred": false
+                    },
+                    {
+                        "name": "b",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": "The second number.",
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": "Sum of two numbers in Sphinx style.\n\n:param a: The first number.\n:param float b: The second number.\n:return: The sum.",
+                "signature": "def sphinx(a: float, b: float) -> float",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "undocumented": {
+                "name": "undocumented",
+                "parameters": [
+                    {
+                        "name": "a",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "none"
+                },
+                "doc": null,
+                "signature": "def undocumented(a: int) -> None",
+                "overloads": [],
+                "source": "runtime"
+            }
+        },
+        "type_definitions": {
+            "Pixel": {
+                "module": "docstring",
+                "name": "Pixel",
+                "supertype": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": "Length in pixels",
+                "source": "runtime"
+            }
+        }
+    }
+    "###);
+
+    let interface = Interface::from_json(&json)?;
+    insta::assert_debug_snapshot!(interface, @r###"
+    Interface {
+        functions: {
+            "google": Function {
+                name: "google",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Primitive(
+                            Int,
+                        ),
+                        doc: Some(
+                            "Number of greetings.",
+                        ),
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "name",
+                        type: Primitive(
+                            Str,
+                        ),
+                        doc: Some(
+                            "Whom to greet. Long descriptions may continue on the next line.",
+                        ),
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Str,
+                ),
+                doc: Some(
+                    "Greet in Google style.\n\nArgs:\n    x (int): Number of greetings.\n    name: Whom to greet. Long descriptions\n        may continue on the next line.\n\nReturns:\n    Greeting message.",
+                ),
+                signature: Some(
+                    "def google(x: int, name: str) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "numpy": Function {
+                name: "numpy",
+                parameters: [
+                    Parameter {
+                        name: "width",
+                        type: UserDefined {
+                            module: "docstring",
+                            name: "Pixel",
+                            supertype: Primitive(
+                                Int,
+                            ),
+                        },
+                        doc: Some(
+                            "Width of the rectangle.",
+                        ),
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "height",
+                        type: UserDefined {
+                            module: "docstring",
+                            name: "Pixel",
+                            supertype: Primitive(
+                                Int,
+                            ),
+                        },
+                        doc: Some(
+                            "Height of the rectangle.",
+                        ),
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Int,
+                ),
+                doc: Some(
+                    "Area of a rectangle in NumPy style.\n\nParameters\n----------\nwidth : Pixel\n    Width of the rectangle.\nheight : Pixel\n    Height of the rectangle.\n\nReturns\n-------\nint\n    Area of the rectangle.",
+                ),
+                signature: Some(
+                    "def numpy(width: docstring.Pixel, height: docstring.Pixel) -> int",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "sphinx": Function {
+                name: "sphinx",
+                parameters: [
+                    Parameter {
+                        name: "a",
+                        type: Primitive(
+                            Float,
+                        ),
+                        doc: Some(
+                            "The first number.",
+                        ),
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "b",
+                        type: Primitive(
+                            Float,
+                        ),
+                        doc: Some(
+                            "The second number.",
+                        ),
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Float,
+                ),
+                doc: Some(
+                    "Sum of two numbers in Sphinx style.\n\n:param a: The first number.\n:param float b: The second number.\n:return: The sum.",
+                ),
+                signature: Some(
+                    "def sphinx(a: float, b: float) -> float",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "undocumented": Function {
+                name: "undocumented",
+                parameters: [
+                    Parameter {
+                        name: "a",
+                        type: Primitive(
+                            Int,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: None,
+                doc: None,
+                signature: Some(
+                    "def undocumented(a: int) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+        },
+        type_definitions: {
+            "Pixel": TypeDefinition {
+                name: "Pixel",
+                module: "docstring",
+                supertype: Primitive(
+                    Int,
+                ),
+                doc: Some(
+                    "Length in pixels",
+                ),
+                source: Runtime,
+            },
+        },
+        without_signature: [],
+    }
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module(TARGET)?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "docstring",
+    );
+    /// Length in pixels
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Pixel(pub i64);
+    impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for Pixel {
+        fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+            self.0.into_py(py)
+        }
+    }
+    /// Greet in Google style.
+    ///
+    /// ```text
+    /// Args:
+    ///     x (int): Number of greetings.
+    ///     name: Whom to greet. Long descriptions
+    ///         may continue on the next line.
+    ///
+    /// Returns:
+    ///     Greeting message.
+    /// ```
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def google(x: int, name: str) -> str
+    /// ```
+    pub fn google<'py>(
+        py: ::pyo3::Python<'py>,
+        x: i64,
+        name: &str,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "google")?.call((x, name), None)?;
+        result.extract()
+    }
+    /// Area of a rectangle in NumPy style.
+    ///
+    /// ```text
+    /// Parameters
+    /// ----------
+    /// width : Pixel
+    ///     Width of the rectangle.
+    /// height : Pixel
+    ///     Height of the rectangle.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     Area of the rectangle.
+    /// ```
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def numpy(width: docstring.Pixel, height: docstring.Pixel) -> int
+    /// ```
+    pub fn numpy<'py>(
+        py: ::pyo3::Python<'py>,
+        width: Pixel,
+        height: Pixel,
+    ) -> ::pyo3::PyResult<i64> {
+        let result = MODULE.getattr(py, "numpy")?.call((width, height), None)?;
+        result.extract()
+    }
+    /// Sum of two numbers in Sphinx style.
+    ///
+    /// ```text
+    /// :param a: The first number.
+    /// :param float b: The second number.
+    /// :return: The sum.
+    /// ```
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def sphinx(a: float, b: float) -> float
+    /// ```
+    pub fn sphinx<'py>(py: ::pyo3::Python<'py>, a: f64, b: f64) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "sphinx")?.call((a, b), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def undocumented(a: int) -> None
+    /// ```
+    pub fn undocumented<'py>(py: ::pyo3::Python<'py>, a: i64) -> ::pyo3::PyResult<()> {
+        let _ = MODULE.getattr(py, "undocumented")?.call((a,), None)?;
+        Ok(())
+    }
+    "###);
+    Ok(())
+}
@@ -15,8 +15,12 @@ fn inspect() -> Result<()> {
                 "name": "a1",
                 "parameters": [],
                 "return": {
-                    "kind": "none"
-                }
+                    "kind": "unknown"
+                },
+                "doc": null,
+                "signature": "def a1()",
+                "overloads": [],
+                "source": "runtime"
             },
             "a2": {
                 "name": "a2",
@@ -26,12 +30,18 @@ fn inspect() -> Result<()> {
                         "type": {
                             "kind": "primitive",
                             "name": "int"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
-                    "kind": "none"
-                }
+                    "kind": "unknown"
+                },
+                "doc": null,
+                "signature": "def a2(x: int)",
+                "overloads": [],
+                "source": "runtime"
             },
             "a3": {
                 "name": "a3",
@@ -41,19 +51,27 @@ fn inspect() -> Result<()> {
                         "type": {
                             "kind": "primitive",
                             "name": "str"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     },
                     {
                         "name": "z",
                         "type": {
                             "kind": "primitive",
                             "name": "float"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
-                    "kind": "none"
-                }
+                    "kind": "unknown"
+                },
+                "doc": null,
+                "signature": "def a3(y: str, z: float)",
+                "overloads": [],
+                "source": "runtime"
             },
             "a4": {
                 "name": "a4",
@@ -61,7 +79,11 @@ fn inspect() -> Result<()> {
                 "return": {
                     "kind": "primitive",
                     "name": "int"
-                }
+                },
+                "doc": null,
+                "signature": "def a4() -> int",
+                "overloads": [],
+                "source": "runtime"
             },
             "a5": {
                 "name": "a5",
@@ -71,13 +93,19 @@ fn inspect() -> Result<()> {
                         "type": {
                             "kind": "primitive",
                             "name": "int"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "primitive",
                     "name": "str"
-                }
+                },
+                "doc": null,
+                "signature": "def a5(x: int) -> str",
+                "overloads": [],
+                "source": "runtime"
             },
             "a6": {
                 "name": "a6",
@@ -94,7 +122,11 @@ fn inspect() -> Result<()> {
                             "name": "str"
                         }
                     ]
-                }
+                },
+                "doc": null,
+                "signature": "def a6() -> tuple[int, str]",
+                "overloads": [],
+                "source": "runtime"
             },
             "a7": {
                 "name": "a7",
@@ -104,7 +136,9 @@ fn inspect() -> Result<()> {
                         "type": {
                             "kind": "primitive",
                             "name": "int"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
@@ -123,7 +157,39 @@ fn inspect() -> Result<()> {
                             "name": "float"
                         }
                     ]
-                }
+                },
+                "doc": null,
+                "signature": "def a7(x: int) -> tuple[int, str, float]",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "a8": {
+                "name": "a8",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "unknown"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    },
+                    {
+                        "name": "y",
+                        "type": {
+                            "kind": "unknown"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "unknown"
+                },
+                "doc": null,
+                "signature": "def a8(x, y)",
+                "overloads": [],
+                "source": "runtime"
             }
         },
         "type_definitions": {}
@@ -137,7 +203,13 @@ fn inspect() -> Result<()> {
             "a1": Function {
                 name: "a1",
                 parameters: [],
-                return: None,
+                return: Unknown,
+                doc: None,
+                signature: Some(
+                    "def a1()",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "a2": Function {
                 name: "a2",
@@ -147,9 +219,17 @@ fn inspect() -> Result<()> {
                         type: Primitive(
                             Int,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                 ],
-                return: None,
+                return: Unknown,
+                doc: None,
+                signature: Some(
+                    "def a2(x: int)",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "a3": Function {
                 name: "a3",
@@ -159,15 +239,25 @@ fn inspect() -> Result<()> {
                         type: Primitive(
                             Str,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                     Parameter {
                         name: "z",
                         type: Primitive(
                             Float,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                 ],
-                return: None,
+                return: Unknown,
+                doc: None,
+                signature: Some(
+                    "def a3(y: str, z: float)",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "a4": Function {
                 name: "a4",
@@ -175,6 +265,12 @@ fn inspect() -> Result<()> {
                 return: Primitive(
                     Int,
                 ),
+                doc: None,
+                signature: Some(
+                    "def a4() -> int",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "a5": Function {
                 name: "a5",
@@ -184,11 +280,19 @@ fn inspect() -> Result<()> {
                         type: Primitive(
                             Int,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: Primitive(
                     Str,
                 ),
+                doc: None,
+                signature: Some(
+                    "def a5(x: int) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "a6": Function {
                 name: "a6",
@@ -203,6 +307,12 @@ fn inspect() -> Result<()> {
                         ),
                     ],
                 },
+                doc: None,
+                signature: Some(
+                    "def a6() -> tuple[int, str]",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "a7": Function {
                 name: "a7",
@@ -212,6 +322,8 @@ fn inspect() -> Result<()> {
                         type: Primitive(
                             Int,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: Tuple {
@@ -227,12 +339,55 @@ fn inspect() -> Result<()> {
                         ),
                     ],
                 },
+                doc: None,
+                signature: Some(
+                    "def a7(x: int) -> tuple[int, str, float]",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "a8": Function {
+                name: "a8",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Unknown,
+                        doc: None,
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "y",
+                        type: Unknown,
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Unknown,
+                doc: None,
+                signature: Some(
+                    "def a8(x, y)",
+                ),
+                overloads: [],
+                source: Runtime,
             },
         },
         type_definitions: {},
+        without_signature: [],
     }
     "###);
 
+    assert_eq!(
+        interface.unannotated(),
+        [
+            "a1 -> return",
+            "a2 -> return",
+            "a3 -> return",
+            "a8(x)",
+            "a8(y)",
+            "a8 -> return"
+        ]
+    );
+
     Ok(())
 }
 
@@ -241,81 +396,196 @@ fn codegen() -> Result<()> {
     std::env::set_var("PYTHONPATH", PYTHON_ROOT);
     let interface = Interface::from_py_module(TARGET)?;
     insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
-    pub fn a1<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<()> {
-        let _ = py.import("example")?.getattr("a1")?.call((), None)?;
-        Ok(())
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "example",
+    );
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a1()
+    /// ```
+    pub fn a1<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "a1")?.call((), None)?;
+        result.extract()
     }
-    pub fn a2<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<()> {
-        let _ = py.import("example")?.getattr("a2")?.call((x,), None)?;
-        Ok(())
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a2(x: int)
+    /// ```
+    pub fn a2<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "a2")?.call((x,), None)?;
+        result.extract()
     }
-    pub fn a3<'py>(py: ::pyo3::Python<'py>, y: &str, z: f64) -> ::pyo3::PyResult<()> {
-        let _ = py.import("example")?.getattr("a3")?.call((y, z), None)?;
-        Ok(())
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a3(y: str, z: float)
+    /// ```
+    pub fn a3<'py>(
+        py: ::pyo3::Python<'py>,
+        y: &str,
+        z: f64,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "a3")?.call((y, z), None)?;
+        result.extract()
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a4() -> int
+    /// ```
     pub fn a4<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<i64> {
-        let result = py.import("example")?.getattr("a4")?.call((), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "a4")?.call((), None)?;
+        result.extract()
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a5(x: int) -> str
+    /// ```
     pub fn a5<'py>(
         py: ::pyo3::Python<'py>,
         x: i64,
     ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
-        let result = py.import("example")?.getattr("a5")?.call((x,), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "a5")?.call((x,), None)?;
+        result.extract()
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a6() -> tuple[int, str]
+    /// ```
     pub fn a6<'py>(
         py: ::pyo3::Python<'py>,
     ) -> ::pyo3::PyResult<(i64, ::pyo3::Py<::pyo3::types::PyString>)> {
-        let result = py.import("example")?.getattr("a6")?.call((), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "a6")?.call((), None)?;
+        result.extract()
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a7(x: int) -> tuple[int, str, float]
+    /// ```
     pub fn a7<'py>(
         py: ::pyo3::Python<'py>,
         x: i64,
     ) -> ::pyo3::PyResult<(i64, ::pyo3::Py<::pyo3::types::PyString>, f64)> {
-        let result = py.import("example")?.getattr("a7")?.call((x,), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "a7")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def a8(x, y)
+    /// ```
+    pub fn a8<'py>(
+        py: ::pyo3::Python<'py>,
+        x: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+        y: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "a8")?.call((x, y), None)?;
+        result.extract()
     }
     "###);
 
     insta::assert_snapshot!(generate(TARGET, &interface, false)?, @r###"
     pub mod example {
-        pub fn a1<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<()> {
-            let _ = py.import("example")?.getattr("a1")?.call((), None)?;
-            Ok(())
+        /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+        pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+            "example",
+        );
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a1()
+        /// ```
+        pub fn a1<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<::pyo3::PyObject> {
+            let result = MODULE.getattr(py, "a1")?.call((), None)?;
+            result.extract()
         }
-        pub fn a2<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<()> {
-            let _ = py.import("example")?.getattr("a2")?.call((x,), None)?;
-            Ok(())
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a2(x: int)
+        /// ```
+        pub fn a2<'py>(
+            py: ::pyo3::Python<'py>,
+            x: i64,
+        ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+            let result = MODULE.getattr(py, "a2")?.call((x,), None)?;
+            result.extract()
         }
-        pub fn a3<'py>(py: ::pyo3::Python<'py>, y: &str, z: f64) -> ::pyo3::PyResult<()> {
-            let _ = py.import("example")?.getattr("a3")?.call((y, z), None)?;
-            Ok(())
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a3(y: str, z: float)
+        /// ```
+        pub fn a3<'py>(
+            py: ::pyo3::Python<'py>,
+            y: &str,
+            z: f64,
+        ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+            let result = MODULE.getattr(py, "a3")?.call((y, z), None)?;
+            result.extract()
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a4() -> int
+        /// ```
         pub fn a4<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<i64> {
-            let result = py.import("example")?.getattr("a4")?.call((), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "a4")?.call((), None)?;
+            result.extract()
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a5(x: int) -> str
+        /// ```
         pub fn a5<'py>(
             py: ::pyo3::Python<'py>,
             x: i64,
         ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
-            let result = py.import("example")?.getattr("a5")?.call((x,), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "a5")?.call((x,), None)?;
+            result.extract()
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a6() -> tuple[int, str]
+        /// ```
         pub fn a6<'py>(
             py: ::pyo3::Python<'py>,
         ) -> ::pyo3::PyResult<(i64, ::pyo3::Py<::pyo3::types::PyString>)> {
-            let result = py.import("example")?.getattr("a6")?.call((), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "a6")?.call((), None)?;
+            result.extract()
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a7(x: int) -> tuple[int, str, float]
+        /// ```
         pub fn a7<'py>(
             py: ::pyo3::Python<'py>,
             x: i64,
         ) -> ::pyo3::PyResult<(i64, ::pyo3::Py<::pyo3::types::PyString>, f64)> {
-            let result = py.import("example")?.getattr("a7")?.call((x,), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "a7")?.call((x,), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def a8(x, y)
+        /// ```
+        pub fn a8<'py>(
+            py: ::pyo3::Python<'py>,
+            x: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+            y: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+        ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+            let result = MODULE.getattr(py, "a8")?.call((x, y), None)?;
+            result.extract()
         }
     }
     "###);
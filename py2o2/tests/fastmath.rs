@@ -0,0 +1,242 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TARGET: &str = "fastmath";
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json(TARGET)?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "copysign": {
+                "name": "copysign",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    },
+                    {
+                        "name": "y",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": "Return a float with the magnitude (absolute value) of x but the sign of y.\n\nOn platforms that support signed zeros, copysign(1.0, -0.0)\nreturns -1.0.",
+                "signature": "def copysign(x: float, y: float, /) -> float",
+                "overloads": [],
+                "source": "stub"
+            },
+            "factorial": {
+                "name": "factorial",
+                "parameters": [
+                    {
+                        "name": "n",
+                        "type": {
+                            "kind": "unknown"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "unknown"
+                },
+                "doc": "Find n!.\n\nRaise a ValueError if x is negative or non-integral.",
+                "signature": "def factorial(n, /)",
+                "overloads": [],
+                "source": "text_signature"
+            },
+            "sqrt": {
+                "name": "sqrt",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": "Return the square root of x.",
+                "signature": "def sqrt(x: float, /) -> float",
+                "overloads": [],
+                "source": "stub"
+            }
+        },
+        "type_definitions": {},
+        "without_signature": [
+            "hypot"
+        ]
+    }
+    "###);
+
+    let interface = Interface::from_json(&json)?;
+    insta::assert_debug_snapshot!(interface, @r###"
+    Interface {
+        functions: {
+            "copysign": Function {
+                name: "copysign",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Primitive(
+                            Float,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "y",
+                        type: Primitive(
+                            Float,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Float,
+                ),
+                doc: Some(
+                    "Return a float with the magnitude (absolute value) of x but the sign of y.\n\nOn platforms that support signed zeros, copysign(1.0, -0.0)\nreturns -1.0.",
+                ),
+                signature: Some(
+                    "def copysign(x: float, y: float, /) -> float",
+                ),
+                overloads: [],
+                source: Stub,
+            },
+            "factorial": Function {
+                name: "factorial",
+                parameters: [
+                    Parameter {
+                        name: "n",
+                        type: Unknown,
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Unknown,
+                doc: Some(
+                    "Find n!.\n\nRaise a ValueError if x is negative or non-integral.",
+                ),
+                signature: Some(
+                    "def factorial(n, /)",
+                ),
+                overloads: [],
+                source: TextSignature,
+            },
+            "sqrt": Function {
+                name: "sqrt",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Primitive(
+                            Float,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Float,
+                ),
+                doc: Some(
+                    "Return the square root of x.",
+                ),
+                signature: Some(
+                    "def sqrt(x: float, /) -> float",
+                ),
+                overloads: [],
+                source: Stub,
+            },
+        },
+        type_definitions: {},
+        without_signature: [
+            "hypot",
+        ],
+    }
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module(TARGET)?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "fastmath",
+    );
+    /// Return a float with the magnitude (absolute value) of x but the sign of y.
+    ///
+    /// ```text
+    /// On platforms that support signed zeros, copysign(1.0, -0.0)
+    /// returns -1.0.
+    /// ```
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def copysign(x: float, y: float, /) -> float
+    /// ```
+    pub fn copysign<'py>(py: ::pyo3::Python<'py>, x: f64, y: f64) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "copysign")?.call((x, y), None)?;
+        result.extract()
+    }
+    /// Find n!.
+    ///
+    /// ```text
+    /// Raise a ValueError if x is negative or non-integral.
+    /// ```
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def factorial(n, /)
+    /// ```
+    pub fn factorial<'py>(
+        py: ::pyo3::Python<'py>,
+        n: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "factorial")?.call((n,), None)?;
+        result.extract()
+    }
+    /// Return the square root of x.
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def sqrt(x: float, /) -> float
+    /// ```
+    pub fn sqrt<'py>(py: ::pyo3::Python<'py>, x: f64) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "sqrt")?.call((x,), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
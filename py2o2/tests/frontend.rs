@@ -0,0 +1,422 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+use std::path::{Path, PathBuf};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+
+/// Drop what differs between static and runtime inspection by design
+fn normalize(mut interface: Interface) -> Interface {
+    fn function(f: &mut Function) {
+        f.signature = None;
+        f.source = Source::Runtime;
+        f.overloads.iter_mut().for_each(function);
+    }
+    interface.functions.values_mut().for_each(function);
+    for typedef in interface.type_definitions.values_mut() {
+        typedef.source = Source::Runtime;
+    }
+    interface
+}
+
+fn check(target: &str, options: &InspectOptions) -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let path = Path::new(PYTHON_ROOT).join(format!("{}.py", target));
+    let parsed = Interface::from_source_path(&path, options)?;
+    let imported = Interface::from_py_module_with_options(target, options)?;
+    let no_import = Interface::from_py_ast(target, options)?;
+    assert_eq!(
+        normalize(no_import),
+        normalize(imported.clone()),
+        "{}",
+        target
+    );
+    assert_eq!(normalize(parsed), normalize(imported), "{}", target);
+    Ok(())
+}
+
+#[test]
+fn same_as_runtime() -> Result<()> {
+    for target in [
+        "example",
+        "type_aliases",
+        "union",
+        "callable",
+        "docstring",
+        "overload",
+        "annotated",
+        "special_forms",
+        "layout",
+        "greek",
+        "keywords",
+    ] {
+        check(target, &InspectOptions::default())?;
+    }
+    check(
+        "untyped",
+        &InspectOptions {
+            infer_from_docstring: true,
+            ..Default::default()
+        },
+    )?;
+    check(
+        "legacy",
+        &InspectOptions {
+            stub_paths: vec![PathBuf::from(PYTHON_ROOT).join("stubs")],
+            ..Default::default()
+        },
+    )?;
+    Ok(())
+}
+
+#[test]
+fn no_import() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let options = InspectOptions {
+        no_import: true,
+        ..Default::default()
+    };
+    assert!(Interface::from_py_module("side_effect").is_err());
+    let interface = Interface::from_py_module_with_options("side_effect", &options)?;
+    insta::assert_snapshot!(generate("side_effect", &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "side_effect",
+    );
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Count(pub i64);
+    impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for Count {
+        fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+            self.0.into_py(py)
+        }
+    }
+    /// Double the count
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def double(x: Count) -> int
+    /// ```
+    pub fn double<'py>(py: ::pyo3::Python<'py>, x: Count) -> ::pyo3::PyResult<i64> {
+        let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn no_import_submodule() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let options = InspectOptions {
+        no_import: true,
+        ..Default::default()
+    };
+    // The parent package is not executed either
+    let interface = Interface::from_py_module_with_options("side_effect_package.sub", &options)?;
+    assert_eq!(interface.functions.keys().collect::<Vec<_>>(), ["triple"]);
+    let err = Interface::from_py_module_with_options("side_effect_package.missing", &options)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Module `side_effect_package.missing` is not found"
+    );
+    Ok(())
+}
+
+#[test]
+fn source() -> Result<()> {
+    let interface = Interface::from_source(
+        "canvas",
+        r#"
+from typing import NewType
+import typing as t
+
+Pixel = NewType("Pixel", int)
+
+def area(width: Pixel, height: "Pixel", *, scale: int) -> t.List[int]:
+    """Area of the rectangle"""
+    return [width * height * scale]
+"#,
+        &InspectOptions::default(),
+    )?;
+    insta::assert_debug_snapshot!(interface, @r###"
+    Interface {
+        functions: {
+            "area": Function {
+                name: "area",
+                parameters: [
+                    Parameter {
+                        name: "width",
+                        type: UserDefined {
+                            module: "canvas",
+                            name: "Pixel",
+                            supertype: Primitive(
+                                Int,
+                            ),
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "height",
+                        type: UserDefined {
+                            module: "canvas",
+                            name: "Pixel",
+                            supertype: Primitive(
+                                Int,
+                            ),
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                    Parameter {
+                        name: "scale",
+                        type: Primitive(
+                            Int,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: List {
+                    inner: [
+                        Primitive(
+                            Int,
+                        ),
+                    ],
+                },
+                doc: Some(
+                    "Area of the rectangle",
+                ),
+                signature: Some(
+                    "def area(width: Pixel, height: 'Pixel', *, scale: int) -> t.List[int]",
+                ),
+                overloads: [],
+                source: Static,
+            },
+        },
+        type_definitions: {
+            "Pixel": TypeDefinition {
+                name: "Pixel",
+                module: "canvas",
+                supertype: Primitive(
+                    Int,
+                ),
+                doc: None,
+                source: Static,
+            },
+        },
+        without_signature: [],
+    }
+    "###);
+    insta::assert_snapshot!(generate("canvas", &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "canvas",
+    );
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Pixel(pub i64);
+    impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for Pixel {
+        fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+            self.0.into_py(py)
+        }
+    }
+    /// Area of the rectangle
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def area(width: Pixel, height: 'Pixel', *, scale: int) -> t.List[int]
+    /// ```
+    pub fn area<'py>(
+        py: ::pyo3::Python<'py>,
+        width: Pixel,
+        height: Pixel,
+        scale: i64,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyList>> {
+        let result = MODULE.getattr(py, "area")?.call((width, height, scale), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn type_comments() -> Result<()> {
+    let interface = Interface::from_source(
+        "legacy_comments",
+        r#"
+from typing import List
+
+def join(sep, items):
+    # type: (str, List[str]) -> str
+    return sep.join(items)
+
+def scale(values,  # type: List[float]
+          factor=1.0,  # type: float
+          ):
+    # type: (...) -> List[float]
+    return [v * factor for v in values]
+"#,
+        &InspectOptions::default(),
+    )?;
+    insta::assert_snapshot!(generate("legacy_comments", &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "legacy_comments",
+    );
+    /// # Python signature
+    ///
+    /// ```python
+    /// def join(sep, items)
+    /// ```
+    pub fn join<'py>(
+        py: ::pyo3::Python<'py>,
+        sep: &str,
+        items: &::pyo3::types::PyList,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "join")?.call((sep, items), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def scale(values, factor=1.0)
+    /// ```
+    pub fn scale<'py>(
+        py: ::pyo3::Python<'py>,
+        values: &::pyo3::types::PyList,
+        factor: f64,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyList>> {
+        let result = MODULE.getattr(py, "scale")?.call((values, factor), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn guards() -> Result<()> {
+    let source = r#"
+import sys
+
+if sys.version_info >= (3, 10):
+    def new() -> int: ...
+else:
+    def old() -> int: ...
+
+if sys.platform == "win32":
+    def windows() -> int: ...
+elif sys.platform.startswith("linux") and sys.version_info < (3, 12):
+    def linux() -> int: ...
+"#;
+    let functions = |python_version, platform: &str| -> Result<Vec<String>> {
+        let options = InspectOptions {
+            python_version: Some(python_version),
+            platform: Some(platform.to_string()),
+            ..Default::default()
+        };
+        let interface = Interface::from_source("guards", source, &options)?;
+        Ok(interface.functions.into_keys().collect())
+    };
+    assert_eq!(functions((3, 9), "linux")?, ["linux", "old"]);
+    assert_eq!(functions((3, 12), "linux")?, ["new"]);
+    assert_eq!(functions((3, 10), "win32")?, ["new", "windows"]);
+    Ok(())
+}
+
+#[test]
+fn nested_class_object() {
+    let source = r#"
+class Outer:
+    class Inner:
+        pass
+
+def f(cls: type[Outer.Inner]) -> None: ...
+"#;
+    let err = Interface::from_source("nested", source, &InspectOptions::default()).unwrap_err();
+    insta::assert_snapshot!(format!("{:#}", err), @"Nested class nested.Outer.Inner is not supported in type[T]");
+}
+
+#[test]
+fn empty_subscript() {
+    let error = |annotation: &str| {
+        let source = format!(
+            "from typing import Callable, Concatenate, Optional\ndef f(x: {}) -> None: ...\n",
+            annotation
+        );
+        let err = Interface::from_source("empty", &source, &InspectOptions::default()).unwrap_err();
+        format!("{:#}", err)
+    };
+    insta::assert_snapshot!(error("Optional[()]"), @"Optional takes a type: ()");
+    insta::assert_snapshot!(error("Callable[Concatenate[()], int]"), @"Concatenate takes types and a ParamSpec: ()");
+}
+
+#[test]
+fn recursive_alias() {
+    let error = |source: &str| {
+        let err =
+            Interface::from_source("recursive", source, &InspectOptions::default()).unwrap_err();
+        format!("{:#}", err)
+    };
+    insta::assert_snapshot!(error(r#"
+Json = dict[str, "Json"] | list["Json"] | str
+
+def dump(value: Json) -> str: ...
+"#), @"Recursive type alias `Json` in `recursive`");
+    insta::assert_snapshot!(error(r#"
+X = "X"
+
+def f(x: X) -> None: ...
+"#), @"Recursive type alias `X` in `recursive`");
+}
+
+#[test]
+fn recursive_alias_no_import() {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let options = InspectOptions {
+        no_import: true,
+        ..Default::default()
+    };
+    let err = Interface::from_py_module_with_options("recursive_alias", &options).unwrap_err();
+    insta::assert_snapshot!(format!("{:#}", err), @"Recursive type alias `Json` in `recursive_alias`");
+}
+
+#[test]
+fn diagnostic_path() -> Result<()> {
+    let dir = std::env::temp_dir().join("py2o2-diagnostic-path");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("bad.py");
+    std::fs::write(&path, "def f(x: int -> int: ...\n")?;
+    let err = Interface::from_source_path(&path, &InspectOptions::default()).unwrap_err();
+    // The file is shown instead of the module name `bad`
+    let location = format!("--> {}:1:14", path.display());
+    assert!(format!("{:#}", err).contains(&location), "{:#}", err);
+    Ok(())
+}
+
+#[test]
+fn reload_function() -> Result<()> {
+    // Not conflicting with reloading the module by `MODULE.reload(py)`
+    let interface = Interface::from_source(
+        "reloader",
+        "def reload() -> None: ...\n",
+        &InspectOptions::default(),
+    )?;
+    insta::assert_snapshot!(generate("reloader", &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "reloader",
+    );
+    /// # Python signature
+    ///
+    /// ```python
+    /// def reload() -> None
+    /// ```
+    pub fn reload<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<()> {
+        let _ = MODULE.getattr(py, "reload")?.call((), None)?;
+        Ok(())
+    }
+    "###);
+    Ok(())
+}
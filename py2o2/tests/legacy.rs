@@ -0,0 +1,182 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+use std::path::PathBuf;
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const STUB_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/stubs/");
+const TARGET: &str = "legacy";
+
+fn options() -> InspectOptions {
+    InspectOptions {
+        stub_paths: vec![PathBuf::from(STUB_ROOT)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json_with_options(TARGET, &options())?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "answer": {
+                "name": "answer",
+                "parameters": [],
+                "return": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": null,
+                "signature": "def answer() -> int",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "scale": {
+                "name": "scale",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    },
+                    {
+                        "name": "factor",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": "Multiply `x` by `factor`",
+                "signature": "def scale(x: float, factor: float) -> float",
+                "overloads": [],
+                "source": "stub"
+            },
+            "user_name": {
+                "name": "user_name",
+                "parameters": [
+                    {
+                        "name": "user_id",
+                        "type": {
+                            "kind": "user_defined",
+                            "module": "legacy",
+                            "name": "UserId",
+                            "supertype": {
+                                "kind": "primitive",
+                                "name": "int"
+                            }
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": null,
+                "signature": "def user_name(user_id: UserId) -> str",
+                "overloads": [],
+                "source": "stub"
+            }
+        },
+        "type_definitions": {
+            "UserId": {
+                "name": "UserId",
+                "module": "legacy",
+                "supertype": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": null,
+                "source": "stub"
+            }
+        }
+    }
+    "###);
+
+    // Without stub directory, only runtime annotations are available
+    let interface = Interface::from_py_module(TARGET)?;
+    assert!(interface.type_definitions.is_empty());
+    for f in interface.functions.values() {
+        assert_eq!(f.source, Source::Runtime);
+    }
+    assert_eq!(
+        interface.unannotated(),
+        [
+            "scale(x)",
+            "scale(factor)",
+            "scale -> return",
+            "user_name(user_id)",
+            "user_name -> return"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module_with_options(TARGET, &options())?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "legacy",
+    );
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct UserId(pub i64);
+    impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
+        fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+            self.0.into_py(py)
+        }
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def answer() -> int
+    /// ```
+    pub fn answer<'py>(py: ::pyo3::Python<'py>) -> ::pyo3::PyResult<i64> {
+        let result = MODULE.getattr(py, "answer")?.call((), None)?;
+        result.extract()
+    }
+    /// Multiply `x` by `factor`
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def scale(x: float, factor: float) -> float
+    /// ```
+    pub fn scale<'py>(
+        py: ::pyo3::Python<'py>,
+        x: f64,
+        factor: f64,
+    ) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "scale")?.call((x, factor), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def user_name(user_id: UserId) -> str
+    /// ```
+    pub fn user_name<'py>(
+        py: ::pyo3::Python<'py>,
+        user_id: UserId,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "user_name")?.call((user_id,), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TARGET: &str = "overload";
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json(TARGET)?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "double": {
+                "name": "double",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "union",
+                            "args": [
+                                {
+                                    "kind": "primitive",
+                                    "name": "int"
+                                },
+                                {
+                                    "kind": "primitive",
+                                    "name": "str"
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "union",
+                    "args": [
+                        {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        {
+                            "kind": "primitive",
+                            "name": "str"
+                        }
+                    ]
+                },
+                "doc": "Double the value",
+                "signature": "def double(x: int | str) -> int | str",
+                "overloads": [
+                    {
+                        "name": "double",
+                        "parameters": [
+                            {
+                                "name": "x",
+                                "type": {
+                                    "kind": "primitive",
+                                    "name": "int"
+                                },
+                                "doc": null,
+                                "inferred": false
+                            }
+                        ],
+                        "return": {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        "doc": null,
+                        "signature": "def double(x: int) -> int",
+                        "overloads": []
+                    },
+                    {
+                        "name": "double",
+                        "parameters": [
+                            {
+                                "name": "x",
+                                "type": {
+                                    "kind": "primitive",
+                                    "name": "str"
+                                },
+                                "doc": null,
+                                "inferred": false
+                            }
+                        ],
+                        "return": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": null,
+                        "signature": "def double(x: str) -> str",
+                        "overloads": []
+                    }
+                ],
+                "source": "runtime"
+            }
+        },
+        "type_definitions": {}
+    }
+    "###);
+
+    let interface = Interface::from_json(&json)?;
+    insta::assert_debug_snapshot!(interface, @r###"
+    Interface {
+        functions: {
+            "double": Function {
+                name: "double",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Union {
+                            args: [
+                                Primitive(
+                                    Int,
+                                ),
+                                Primitive(
+                                    Str,
+                                ),
+                            ],
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Union {
+                    args: [
+                        Primitive(
+                            Int,
+                        ),
+                        Primitive(
+                            Str,
+                        ),
+                    ],
+                },
+                doc: Some(
+                    "Double the value",
+                ),
+                signature: Some(
+                    "def double(x: int | str) -> int | str",
+                ),
+                overloads: [
+                    Function {
+                        name: "double",
+                        parameters: [
+                            Parameter {
+                                name: "x",
+                                type: Primitive(
+                                    Int,
+                                ),
+                                doc: None,
+                                inferred: false,
+                            },
+                        ],
+                        return: Primitive(
+                            Int,
+                        ),
+                        doc: None,
+                        signature: Some(
+                            "def double(x: int) -> int",
+                        ),
+                        overloads: [],
+                        source: Runtime,
+                    },
+                    Function {
+                        name: "double",
+                        parameters: [
+                            Parameter {
+                                name: "x",
+                                type: Primitive(
+                                    Str,
+                                ),
+                                doc: None,
+                                inferred: false,
+                            },
+                        ],
+                        return: Primitive(
+                            Str,
+                        ),
+                        doc: None,
+                        signature: Some(
+                            "def double(x: str) -> str",
+                        ),
+                        overloads: [],
+                        source: Runtime,
+                    },
+                ],
+                source: Runtime,
+            },
+        },
+        type_definitions: {},
+        without_signature: [],
+    }
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module(TARGET)?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "overload",
+    );
+    pub trait Union5d6b010906f780ce: ::pyo3::conversion::IntoPy<::pyo3::PyObject> {}
+    impl Union5d6b010906f780ce for i64 {}
+    impl Union5d6b010906f780ce for &str {}
+    /// Double the value
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def double(x: int | str) -> int | str
+    /// ```
+    pub fn double<'py>(
+        py: ::pyo3::Python<'py>,
+        x: impl Union5d6b010906f780ce,
+    ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
+        let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def double(x: int) -> int
+    /// ```
+    pub fn double_1<'py>(py: ::pyo3::Python<'py>, x: i64) -> ::pyo3::PyResult<i64> {
+        let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def double(x: str) -> str
+    /// ```
+    pub fn double_2<'py>(
+        py: ::pyo3::Python<'py>,
+        x: &str,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "double")?.call((x,), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn conflict() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let mut interface = Interface::from_py_module(TARGET)?;
+    let mut double_1 = interface.functions["double"].overloads[0].clone();
+    double_1.name = "double_1".to_string();
+    interface.functions.insert(double_1.name.clone(), double_1);
+    let err = generate(TARGET, &interface, true).unwrap_err();
+    insta::assert_snapshot!(err, @"`double_1` generated for an overload variant of `double` conflicts with the function of the same name");
+    Ok(())
+}
@@ -0,0 +1,343 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TARGET: &str = "special_forms";
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json(TARGET)?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "absurd": {
+                "name": "absurd",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "never"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": null,
+                "signature": "def absurd(x: NoReturn) -> int",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "describe": {
+                "name": "describe",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "any"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "str"
+                },
+                "doc": null,
+                "signature": "def describe(x: object) -> str",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "fail": {
+                "name": "fail",
+                "parameters": [
+                    {
+                        "name": "message",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "never"
+                },
+                "doc": null,
+                "signature": "def fail(message: str) -> NoReturn",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "identity": {
+                "name": "identity",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "any"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "any"
+                },
+                "doc": null,
+                "signature": "def identity(x: Any) -> Any",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "subclass": {
+                "name": "subclass",
+                "parameters": [
+                    {
+                        "name": "cls",
+                        "type": {
+                            "kind": "class_object",
+                            "module": "special_forms",
+                            "name": "Animal"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "class_object",
+                    "module": "special_forms",
+                    "name": "Animal"
+                },
+                "doc": null,
+                "signature": "def subclass(cls: type[special_forms.Animal]) -> type[special_forms.Animal]",
+                "overloads": [],
+                "source": "runtime"
+            }
+        },
+        "type_definitions": {}
+    }
+    "###);
+
+    let interface = Interface::from_json(&json)?;
+    insta::assert_debug_snapshot!(interface, @r###"
+    Interface {
+        functions: {
+            "absurd": Function {
+                name: "absurd",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Never,
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Int,
+                ),
+                doc: None,
+                signature: Some(
+                    "def absurd(x: NoReturn) -> int",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "describe": Function {
+                name: "describe",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Any,
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Str,
+                ),
+                doc: None,
+                signature: Some(
+                    "def describe(x: object) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "fail": Function {
+                name: "fail",
+                parameters: [
+                    Parameter {
+                        name: "message",
+                        type: Primitive(
+                            Str,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Never,
+                doc: None,
+                signature: Some(
+                    "def fail(message: str) -> NoReturn",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "identity": Function {
+                name: "identity",
+                parameters: [
+                    Parameter {
+                        name: "x",
+                        type: Any,
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Any,
+                doc: None,
+                signature: Some(
+                    "def identity(x: Any) -> Any",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "subclass": Function {
+                name: "subclass",
+                parameters: [
+                    Parameter {
+                        name: "cls",
+                        type: ClassObject {
+                            module: "special_forms",
+                            name: "Animal",
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: ClassObject {
+                    module: "special_forms",
+                    name: "Animal",
+                },
+                doc: None,
+                signature: Some(
+                    "def subclass(cls: type[special_forms.Animal]) -> type[special_forms.Animal]",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+        },
+        type_definitions: {},
+        without_signature: [],
+    }
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module(TARGET)?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "special_forms",
+    );
+    /// Marker of Python class `special_forms.Animal`
+    #[derive(Debug)]
+    pub struct Animal;
+    impl ::py2o2_runtime::PyTypeInfoUser for Animal {
+        const NAME: &'static str = "Animal";
+        const MODULE: &'static [&'static str] = &["special_forms"];
+        fn type_object(py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<&::pyo3::types::PyType> {
+            py.import("special_forms")?.getattr("Animal")?.extract()
+        }
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def absurd(x: NoReturn) -> int
+    /// ```
+    pub fn absurd<'py>(
+        py: ::pyo3::Python<'py>,
+        x: ::std::convert::Infallible,
+    ) -> ::pyo3::PyResult<i64> {
+        let _ = (py, x);
+        match x {}
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def describe(x: object) -> str
+    /// ```
+    pub fn describe<'py>(
+        py: ::pyo3::Python<'py>,
+        x: ::pyo3::PyObject,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "describe")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def fail(message: str) -> NoReturn
+    /// ```
+    pub fn fail<'py>(
+        py: ::pyo3::Python<'py>,
+        message: &str,
+    ) -> ::pyo3::PyResult<::std::convert::Infallible> {
+        let _ = MODULE.getattr(py, "fail")?.call((message,), None)?;
+        Err(
+            ::pyo3::exceptions::PyRuntimeError::new_err(
+                "fail returned though annotated as NoReturn",
+            ),
+        )
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def identity(x: Any) -> Any
+    /// ```
+    pub fn identity<'py>(
+        py: ::pyo3::Python<'py>,
+        x: ::pyo3::PyObject,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "identity")?.call((x,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def subclass(cls: type[special_forms.Animal]) -> type[special_forms.Animal]
+    /// ```
+    pub fn subclass<'py>(
+        py: ::pyo3::Python<'py>,
+        cls: ::py2o2_runtime::ClassObject<Animal>,
+    ) -> ::pyo3::PyResult<::py2o2_runtime::ClassObject<Animal>> {
+        let result = MODULE.getattr(py, "subclass")?.call((cls,), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn class_marker_conflict() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let mut interface = Interface::from_py_module(TARGET)?;
+    // `type[zoo.Animal]` besides `type[special_forms.Animal]`
+    let mut f = interface.functions["subclass"].clone();
+    f.name = "zoo_subclass".to_string();
+    f.parameters[0].r#type = Type::ClassObject {
+        module: "zoo".to_string(),
+        name: "Animal".to_string(),
+    };
+    interface.functions.insert(f.name.clone(), f);
+    let err = generate(TARGET, &interface, true).unwrap_err();
+    insta::assert_snapshot!(err, @"Classes `special_forms.Animal` and `zoo.Animal` are both used in `type[T]` but cannot share the marker `Animal`");
+    Ok(())
+}
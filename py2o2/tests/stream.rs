@@ -0,0 +1,90 @@
+use anyhow::Result;
+use py2o2::inspect::*;
+use std::path::PathBuf;
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TYPESHED_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/typeshed/");
+const TARGET: &str = "stream";
+
+fn options() -> InspectOptions {
+    InspectOptions {
+        stub_paths: vec![PathBuf::from(TYPESHED_ROOT)],
+        ..Default::default()
+    }
+}
+
+/// The stub in `stubs/<distribution>/` imports `_typeshed`, which only exists for type checkers,
+/// and `dump` using its protocol falls back to the runtime annotations
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json_with_options(TARGET, &options())?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "count": {
+                "name": "count",
+                "parameters": [
+                    {
+                        "name": "s",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": null,
+                "signature": "def count(s: str) -> int",
+                "overloads": [],
+                "source": "stub"
+            },
+            "dump": {
+                "name": "dump",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    },
+                    {
+                        "name": "fp",
+                        "type": {
+                            "kind": "unknown"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "none"
+                },
+                "doc": "Write `x` to the file-like object",
+                "signature": "def dump(x: int, fp) -> None",
+                "overloads": [],
+                "source": "runtime"
+            }
+        },
+        "type_definitions": {}
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn stub() -> Result<()> {
+    let path = PathBuf::from(TYPESHED_ROOT).join("stubs/stream/stream.pyi");
+    let interface = Interface::from_stub(TARGET, &path, &InspectOptions::default())?;
+    assert_eq!(interface.functions.keys().collect::<Vec<_>>(), ["count"]);
+    assert!(Interface::from_source_path(&path, &InspectOptions::default()).is_err());
+    Ok(())
+}
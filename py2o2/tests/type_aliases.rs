@@ -19,7 +19,9 @@ fn type_aliases() -> Result<()> {
                         "type": {
                             "kind": "primitive",
                             "name": "str"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     },
                     {
                         "name": "servers",
@@ -58,12 +60,58 @@ fn type_aliases() -> Result<()> {
                                     ]
                                 }
                             ]
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "none"
-                }
+                },
+                "doc": null,
+                "signature": "def broadcast_message(message: str, servers: collections.abc.Sequence[tuple[tuple[str, int], dict[str, str]]]) -> None",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "digits": {
+                "name": "digits",
+                "parameters": [
+                    {
+                        "name": "n",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "dict",
+                    "inner": [
+                        {
+                            "kind": "annotated",
+                            "inner": {
+                                "kind": "primitive",
+                                "name": "int"
+                            },
+                            "metadata": [
+                                {
+                                    "kind": "rust",
+                                    "type": "u8"
+                                }
+                            ]
+                        },
+                        {
+                            "kind": "primitive",
+                            "name": "str"
+                        }
+                    ]
+                },
+                "doc": null,
+                "signature": "def digits(n: int) -> dict[typing.Annotated[int, Rust(type='u8')], str]",
+                "overloads": [],
+                "source": "runtime"
             },
             "get_user_name": {
                 "name": "get_user_name",
@@ -78,13 +126,19 @@ fn type_aliases() -> Result<()> {
                                 "kind": "primitive",
                                 "name": "int"
                             }
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
                     "kind": "primitive",
                     "name": "str"
-                }
+                },
+                "doc": null,
+                "signature": "def get_user_name(user_id: type_aliases.UserId) -> str",
+                "overloads": [],
+                "source": "runtime"
             },
             "scale": {
                 "name": "scale",
@@ -94,7 +148,9 @@ fn type_aliases() -> Result<()> {
                         "type": {
                             "kind": "primitive",
                             "name": "float"
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     },
                     {
                         "name": "vector",
@@ -106,7 +162,9 @@ fn type_aliases() -> Result<()> {
                                     "name": "float"
                                 }
                             ]
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
@@ -117,7 +175,83 @@ fn type_aliases() -> Result<()> {
                             "name": "float"
                         }
                     ]
-                }
+                },
+                "doc": null,
+                "signature": "def scale(scalar: float, vector: list[float]) -> list[float]",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "total": {
+                "name": "total",
+                "parameters": [
+                    {
+                        "name": "histograms",
+                        "type": {
+                            "kind": "list",
+                            "inner": [
+                                {
+                                    "kind": "dict",
+                                    "inner": [
+                                        {
+                                            "kind": "primitive",
+                                            "name": "float"
+                                        },
+                                        {
+                                            "kind": "primitive",
+                                            "name": "int"
+                                        }
+                                    ]
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "int"
+                },
+                "doc": null,
+                "signature": "def total(histograms: list[dict[float, int]]) -> int",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "weighted_sum": {
+                "name": "weighted_sum",
+                "parameters": [
+                    {
+                        "name": "pair",
+                        "type": {
+                            "kind": "tuple",
+                            "tags": [
+                                {
+                                    "kind": "list",
+                                    "inner": [
+                                        {
+                                            "kind": "primitive",
+                                            "name": "float"
+                                        }
+                                    ]
+                                },
+                                {
+                                    "kind": "primitive",
+                                    "name": "float"
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "primitive",
+                    "name": "float"
+                },
+                "doc": null,
+                "signature": "def weighted_sum(pair: tuple[list[float], float]) -> float",
+                "overloads": [],
+                "source": "runtime"
             }
         },
         "type_definitions": {
@@ -127,7 +261,9 @@ fn type_aliases() -> Result<()> {
                 "supertype": {
                     "kind": "primitive",
                     "name": "int"
-                }
+                },
+                "doc": null,
+                "source": "runtime"
             }
         }
     }
@@ -145,6 +281,8 @@ fn type_aliases() -> Result<()> {
                         type: Primitive(
                             Str,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                     Parameter {
                         name: "servers",
@@ -176,9 +314,53 @@ fn type_aliases() -> Result<()> {
                                 },
                             ],
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: None,
+                doc: None,
+                signature: Some(
+                    "def broadcast_message(message: str, servers: collections.abc.Sequence[tuple[tuple[str, int], dict[str, str]]]) -> None",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "digits": Function {
+                name: "digits",
+                parameters: [
+                    Parameter {
+                        name: "n",
+                        type: Primitive(
+                            Int,
+                        ),
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Dict {
+                    inner: [
+                        Annotated {
+                            inner: Primitive(
+                                Int,
+                            ),
+                            metadata: [
+                                Rust {
+                                    type: "u8",
+                                },
+                            ],
+                        },
+                        Primitive(
+                            Str,
+                        ),
+                    ],
+                },
+                doc: None,
+                signature: Some(
+                    "def digits(n: int) -> dict[typing.Annotated[int, Rust(type='u8')], str]",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "get_user_name": Function {
                 name: "get_user_name",
@@ -192,11 +374,19 @@ fn type_aliases() -> Result<()> {
                                 Int,
                             ),
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: Primitive(
                     Str,
                 ),
+                doc: None,
+                signature: Some(
+                    "def get_user_name(user_id: type_aliases.UserId) -> str",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "scale": Function {
                 name: "scale",
@@ -206,6 +396,8 @@ fn type_aliases() -> Result<()> {
                         type: Primitive(
                             Float,
                         ),
+                        doc: None,
+                        inferred: false,
                     },
                     Parameter {
                         name: "vector",
@@ -216,6 +408,8 @@ fn type_aliases() -> Result<()> {
                                 ),
                             ],
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: List {
@@ -225,6 +419,78 @@ fn type_aliases() -> Result<()> {
                         ),
                     ],
                 },
+                doc: None,
+                signature: Some(
+                    "def scale(scalar: float, vector: list[float]) -> list[float]",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "total": Function {
+                name: "total",
+                parameters: [
+                    Parameter {
+                        name: "histograms",
+                        type: List {
+                            inner: [
+                                Dict {
+                                    inner: [
+                                        Primitive(
+                                            Float,
+                                        ),
+                                        Primitive(
+                                            Int,
+                                        ),
+                                    ],
+                                },
+                            ],
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Int,
+                ),
+                doc: None,
+                signature: Some(
+                    "def total(histograms: list[dict[float, int]]) -> int",
+                ),
+                overloads: [],
+                source: Runtime,
+            },
+            "weighted_sum": Function {
+                name: "weighted_sum",
+                parameters: [
+                    Parameter {
+                        name: "pair",
+                        type: Tuple {
+                            tags: [
+                                List {
+                                    inner: [
+                                        Primitive(
+                                            Float,
+                                        ),
+                                    ],
+                                },
+                                Primitive(
+                                    Float,
+                                ),
+                            ],
+                        },
+                        doc: None,
+                        inferred: false,
+                    },
+                ],
+                return: Primitive(
+                    Float,
+                ),
+                doc: None,
+                signature: Some(
+                    "def weighted_sum(pair: tuple[list[float], float]) -> float",
+                ),
+                overloads: [],
+                source: Runtime,
             },
         },
         type_definitions: {
@@ -234,8 +500,11 @@ fn type_aliases() -> Result<()> {
                 supertype: Primitive(
                     Int,
                 ),
+                doc: None,
+                source: Runtime,
             },
         },
+        without_signature: [],
     }
     "###);
 
@@ -247,6 +516,10 @@ fn codegen() -> Result<()> {
     std::env::set_var("PYTHONPATH", PYTHON_ROOT);
     let interface = Interface::from_py_module(TARGET)?;
     insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::with_markers(
+        "type_aliases",
+    );
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct UserId(pub i64);
     impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
@@ -254,42 +527,88 @@ fn codegen() -> Result<()> {
             self.0.into_py(py)
         }
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def broadcast_message(message: str, servers: collections.abc.Sequence[tuple[tuple[str, int], dict[str, str]]]) -> None
+    /// ```
     pub fn broadcast_message<'py>(
         py: ::pyo3::Python<'py>,
         message: &str,
         servers: &::pyo3::types::PyList,
     ) -> ::pyo3::PyResult<()> {
-        let _ = py
-            .import("type_aliases")?
-            .getattr("broadcast_message")?
-            .call((message, servers), None)?;
+        let _ = MODULE.getattr(py, "broadcast_message")?.call((message, servers), None)?;
         Ok(())
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def digits(n: int) -> dict[typing.Annotated[int, Rust(type='u8')], str]
+    /// ```
+    pub fn digits<'py>(
+        py: ::pyo3::Python<'py>,
+        n: i64,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyDict>> {
+        let result = MODULE.getattr(py, "digits")?.call((n,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def get_user_name(user_id: type_aliases.UserId) -> str
+    /// ```
     pub fn get_user_name<'py>(
         py: ::pyo3::Python<'py>,
         user_id: UserId,
     ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
-        let result = py
-            .import("type_aliases")?
-            .getattr("get_user_name")?
-            .call((user_id,), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "get_user_name")?.call((user_id,), None)?;
+        result.extract()
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def scale(scalar: float, vector: list[float]) -> list[float]
+    /// ```
     pub fn scale<'py>(
         py: ::pyo3::Python<'py>,
         scalar: f64,
         vector: &::pyo3::types::PyList,
     ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyList>> {
-        let result = py
-            .import("type_aliases")?
-            .getattr("scale")?
-            .call((scalar, vector), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "scale")?.call((scalar, vector), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def total(histograms: list[dict[float, int]]) -> int
+    /// ```
+    pub fn total<'py>(
+        py: ::pyo3::Python<'py>,
+        histograms: &::pyo3::types::PyList,
+    ) -> ::pyo3::PyResult<i64> {
+        let result = MODULE.getattr(py, "total")?.call((histograms,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def weighted_sum(pair: tuple[list[float], float]) -> float
+    /// ```
+    pub fn weighted_sum<'py>(
+        py: ::pyo3::Python<'py>,
+        pair: (&::pyo3::types::PyList, f64),
+    ) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "weighted_sum")?.call((pair,), None)?;
+        result.extract()
     }
     "###);
 
     insta::assert_snapshot!(generate(TARGET, &interface, false)?, @r###"
     pub mod type_aliases {
+        /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+        pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::with_markers(
+            "type_aliases",
+        );
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct UserId(pub i64);
         impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
@@ -297,37 +616,243 @@ fn codegen() -> Result<()> {
                 self.0.into_py(py)
             }
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def broadcast_message(message: str, servers: collections.abc.Sequence[tuple[tuple[str, int], dict[str, str]]]) -> None
+        /// ```
         pub fn broadcast_message<'py>(
             py: ::pyo3::Python<'py>,
             message: &str,
             servers: &::pyo3::types::PyList,
         ) -> ::pyo3::PyResult<()> {
-            let _ = py
-                .import("type_aliases")?
-                .getattr("broadcast_message")?
-                .call((message, servers), None)?;
+            let _ = MODULE.getattr(py, "broadcast_message")?.call((message, servers), None)?;
             Ok(())
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def digits(n: int) -> dict[typing.Annotated[int, Rust(type='u8')], str]
+        /// ```
+        pub fn digits<'py>(
+            py: ::pyo3::Python<'py>,
+            n: i64,
+        ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyDict>> {
+            let result = MODULE.getattr(py, "digits")?.call((n,), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def get_user_name(user_id: type_aliases.UserId) -> str
+        /// ```
         pub fn get_user_name<'py>(
             py: ::pyo3::Python<'py>,
             user_id: UserId,
         ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
-            let result = py
-                .import("type_aliases")?
-                .getattr("get_user_name")?
-                .call((user_id,), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "get_user_name")?.call((user_id,), None)?;
+            result.extract()
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def scale(scalar: float, vector: list[float]) -> list[float]
+        /// ```
         pub fn scale<'py>(
             py: ::pyo3::Python<'py>,
             scalar: f64,
             vector: &::pyo3::types::PyList,
         ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyList>> {
-            let result = py
-                .import("type_aliases")?
-                .getattr("scale")?
-                .call((scalar, vector), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "scale")?.call((scalar, vector), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def total(histograms: list[dict[float, int]]) -> int
+        /// ```
+        pub fn total<'py>(
+            py: ::pyo3::Python<'py>,
+            histograms: &::pyo3::types::PyList,
+        ) -> ::pyo3::PyResult<i64> {
+            let result = MODULE.getattr(py, "total")?.call((histograms,), None)?;
+            result.extract()
+        }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def weighted_sum(pair: tuple[list[float], float]) -> float
+        /// ```
+        pub fn weighted_sum<'py>(
+            py: ::pyo3::Python<'py>,
+            pair: (&::pyo3::types::PyList, f64),
+        ) -> ::pyo3::PyResult<f64> {
+            let result = MODULE.getattr(py, "weighted_sum")?.call((pair,), None)?;
+            result.extract()
+        }
+    }
+    "###);
+    Ok(())
+}
+
+#[test]
+fn codegen_gil() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module(TARGET)?;
+    let options = CodegenOptions {
+        bare: true,
+        gil: true,
+    };
+    insta::assert_snapshot!(generate_with_options(TARGET, &interface, &options)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::with_markers(
+        "type_aliases",
+    );
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct UserId(pub i64);
+    impl ::pyo3::conversion::IntoPy<::pyo3::PyObject> for UserId {
+        fn into_py(self, py: ::pyo3::Python<'_>) -> ::pyo3::PyObject {
+            self.0.into_py(py)
+        }
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def broadcast_message(message: str, servers: collections.abc.Sequence[tuple[tuple[str, int], dict[str, str]]]) -> None
+    /// ```
+    pub fn broadcast_message<'py>(
+        py: ::pyo3::Python<'py>,
+        message: &str,
+        servers: &::pyo3::types::PyList,
+    ) -> ::pyo3::PyResult<()> {
+        let _ = MODULE.getattr(py, "broadcast_message")?.call((message, servers), None)?;
+        Ok(())
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def digits(n: int) -> dict[typing.Annotated[int, Rust(type='u8')], str]
+    /// ```
+    pub fn digits<'py>(
+        py: ::pyo3::Python<'py>,
+        n: i64,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyDict>> {
+        let result = MODULE.getattr(py, "digits")?.call((n,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def get_user_name(user_id: type_aliases.UserId) -> str
+    /// ```
+    pub fn get_user_name<'py>(
+        py: ::pyo3::Python<'py>,
+        user_id: UserId,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyString>> {
+        let result = MODULE.getattr(py, "get_user_name")?.call((user_id,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def scale(scalar: float, vector: list[float]) -> list[float]
+    /// ```
+    pub fn scale<'py>(
+        py: ::pyo3::Python<'py>,
+        scalar: f64,
+        vector: &::pyo3::types::PyList,
+    ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyList>> {
+        let result = MODULE.getattr(py, "scale")?.call((scalar, vector), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def total(histograms: list[dict[float, int]]) -> int
+    /// ```
+    pub fn total<'py>(
+        py: ::pyo3::Python<'py>,
+        histograms: &::pyo3::types::PyList,
+    ) -> ::pyo3::PyResult<i64> {
+        let result = MODULE.getattr(py, "total")?.call((histograms,), None)?;
+        result.extract()
+    }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def weighted_sum(pair: tuple[list[float], float]) -> float
+    /// ```
+    pub fn weighted_sum<'py>(
+        py: ::pyo3::Python<'py>,
+        pair: (&::pyo3::types::PyList, f64),
+    ) -> ::pyo3::PyResult<f64> {
+        let result = MODULE.getattr(py, "weighted_sum")?.call((pair,), None)?;
+        result.extract()
+    }
+    /// Functions acquiring the GIL by themselves, which take and return owned Rust values
+    pub mod gil {
+        #[allow(unused_imports)]
+        use super::*;
+        /// Same as [`super::broadcast_message`], but acquires the GIL and returns an owned value
+        pub fn broadcast_message(
+            message: &str,
+            servers: Vec<((String, i64), ::std::collections::HashMap<String, String>)>,
+        ) -> ::pyo3::PyResult<()> {
+            ::pyo3::Python::with_gil(|py| {
+                let servers = ::pyo3::types::PyList::new(py, servers);
+                super::broadcast_message(py, message, servers)
+            })
+        }
+        /// Same as [`super::digits`], but acquires the GIL and returns an owned value
+        pub fn digits(n: i64) -> ::pyo3::PyResult<::std::collections::HashMap<u8, String>> {
+            ::pyo3::Python::with_gil(|py| {
+                let result = super::digits(py, n)?;
+                ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+            })
+        }
+        /// Same as [`super::get_user_name`], but acquires the GIL and returns an owned value
+        pub fn get_user_name(user_id: UserId) -> ::pyo3::PyResult<String> {
+            ::pyo3::Python::with_gil(|py| {
+                let result = super::get_user_name(py, user_id)?;
+                ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+            })
+        }
+        /// Same as [`super::scale`], but acquires the GIL and returns an owned value
+        pub fn scale(scalar: f64, vector: Vec<f64>) -> ::pyo3::PyResult<Vec<f64>> {
+            ::pyo3::Python::with_gil(|py| {
+                let vector = ::pyo3::types::PyList::new(py, vector);
+                let result = super::scale(py, scalar, vector)?;
+                ::pyo3::IntoPy::<::pyo3::PyObject>::into_py(result, py).extract(py)
+            })
+        }
+        /// Same as [`super::total`], but acquires the GIL and returns an owned value
+        pub fn total(histograms: Vec<Vec<(f64, i64)>>) -> ::pyo3::PyResult<i64> {
+            ::pyo3::Python::with_gil(|py| {
+                let histograms = ::pyo3::types::PyList::new(
+                    py,
+                    histograms
+                        .into_iter()
+                        .map(|item| Ok({
+                            let dict = ::pyo3::types::PyDict::new(py);
+                            for (key, value) in item {
+                                dict.set_item(key, value)?;
+                            }
+                            dict
+                        }))
+                        .collect::<::pyo3::PyResult<Vec<_>>>()?,
+                );
+                super::total(py, histograms)
+            })
+        }
+        /// Same as [`super::weighted_sum`], but acquires the GIL and returns an owned value
+        pub fn weighted_sum(pair: (Vec<f64>, f64)) -> ::pyo3::PyResult<f64> {
+            ::pyo3::Python::with_gil(|py| {
+                let pair = {
+                    let (tag0, tag1) = pair;
+                    (::pyo3::types::PyList::new(py, tag0), tag1)
+                };
+                super::weighted_sum(py, pair)
+            })
         }
     }
     "###);
@@ -28,7 +28,9 @@ fn inspect() -> Result<()> {
                                     "name": "str"
                                 }
                             ]
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
@@ -43,7 +45,11 @@ fn inspect() -> Result<()> {
                             "name": "str"
                         }
                     ]
-                }
+                },
+                "doc": null,
+                "signature": "def f_new(a: int | str) -> int | str",
+                "overloads": [],
+                "source": "runtime"
             },
             "f_old": {
                 "name": "f_old",
@@ -62,7 +68,9 @@ fn inspect() -> Result<()> {
                                     "name": "str"
                                 }
                             ]
-                        }
+                        },
+                        "doc": null,
+                        "inferred": false
                     }
                 ],
                 "return": {
@@ -77,7 +85,11 @@ fn inspect() -> Result<()> {
                             "name": "str"
                         }
                     ]
-                }
+                },
+                "doc": null,
+                "signature": "def f_old(a: Union[int, str]) -> Union[int, str]",
+                "overloads": [],
+                "source": "runtime"
             }
         },
         "type_definitions": {}
@@ -103,6 +115,8 @@ fn inspect() -> Result<()> {
                                 ),
                             ],
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: Union {
@@ -115,6 +129,12 @@ fn inspect() -> Result<()> {
                         ),
                     ],
                 },
+                doc: None,
+                signature: Some(
+                    "def f_new(a: int | str) -> int | str",
+                ),
+                overloads: [],
+                source: Runtime,
             },
             "f_old": Function {
                 name: "f_old",
@@ -131,6 +151,8 @@ fn inspect() -> Result<()> {
                                 ),
                             ],
                         },
+                        doc: None,
+                        inferred: false,
                     },
                 ],
                 return: Union {
@@ -143,9 +165,16 @@ fn inspect() -> Result<()> {
                         ),
                     ],
                 },
+                doc: None,
+                signature: Some(
+                    "def f_old(a: Union[int, str]) -> Union[int, str]",
+                ),
+                overloads: [],
+                source: Runtime,
             },
         },
         type_definitions: {},
+        without_signature: [],
     }
     "###);
 
@@ -157,47 +186,75 @@ fn codegen() -> Result<()> {
     std::env::set_var("PYTHONPATH", PYTHON_ROOT);
     let interface = Interface::from_py_module(TARGET)?;
     insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "union",
+    );
     pub trait Union5d6b010906f780ce: ::pyo3::conversion::IntoPy<::pyo3::PyObject> {}
     impl Union5d6b010906f780ce for i64 {}
     impl Union5d6b010906f780ce for &str {}
+    /// # Python signature
+    ///
+    /// ```python
+    /// def f_new(a: int | str) -> int | str
+    /// ```
     pub fn f_new<'py>(
         py: ::pyo3::Python<'py>,
         a: impl Union5d6b010906f780ce,
     ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
-        let result = py.import("union")?.getattr("f_new")?.call((a,), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "f_new")?.call((a,), None)?;
+        result.extract()
     }
+    /// # Python signature
+    ///
+    /// ```python
+    /// def f_old(a: Union[int, str]) -> Union[int, str]
+    /// ```
     pub fn f_old<'py>(
         py: ::pyo3::Python<'py>,
         a: impl Union5d6b010906f780ce,
     ) -> ::pyo3::PyResult<::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>> {
-        let result = py.import("union")?.getattr("f_old")?.call((a,), None)?;
-        Ok(result.extract()?)
+        let result = MODULE.getattr(py, "f_old")?.call((a,), None)?;
+        result.extract()
     }
     "###);
 
     insta::assert_snapshot!(generate(TARGET, &interface, false)?, @r###"
     pub mod union {
+        /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+        pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+            "union",
+        );
         pub trait Union5d6b010906f780ce: ::pyo3::conversion::IntoPy<::pyo3::PyObject> {}
         impl Union5d6b010906f780ce for i64 {}
         impl Union5d6b010906f780ce for &str {}
+        /// # Python signature
+        ///
+        /// ```python
+        /// def f_new(a: int | str) -> int | str
+        /// ```
         pub fn f_new<'py>(
             py: ::pyo3::Python<'py>,
             a: impl Union5d6b010906f780ce,
         ) -> ::pyo3::PyResult<
             ::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>,
         > {
-            let result = py.import("union")?.getattr("f_new")?.call((a,), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "f_new")?.call((a,), None)?;
+            result.extract()
         }
+        /// # Python signature
+        ///
+        /// ```python
+        /// def f_old(a: Union[int, str]) -> Union[int, str]
+        /// ```
         pub fn f_old<'py>(
             py: ::pyo3::Python<'py>,
             a: impl Union5d6b010906f780ce,
         ) -> ::pyo3::PyResult<
             ::py2o2_runtime::Enum2<i64, ::pyo3::Py<::pyo3::types::PyString>>,
         > {
-            let result = py.import("union")?.getattr("f_old")?.call((a,), None)?;
-            Ok(result.extract()?)
+            let result = MODULE.getattr(py, "f_old")?.call((a,), None)?;
+            result.extract()
         }
     }
     "###);
@@ -0,0 +1,267 @@
+use anyhow::Result;
+use py2o2::{codegen::*, inspect::*};
+
+const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
+const TARGET: &str = "untyped";
+
+const OPTIONS: InspectOptions = InspectOptions {
+    infer_from_docstring: true,
+    stub_paths: Vec::new(),
+    no_import: false,
+    python_version: None,
+    platform: None,
+};
+
+#[test]
+fn inspect() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let json = get_inspect_json_with_options(TARGET, &OPTIONS)?;
+    insta::assert_snapshot!(json, @r###"
+    {
+        "functions": {
+            "expression": {
+                "name": "expression",
+                "parameters": [
+                    {
+                        "name": "value",
+                        "type": {
+                            "kind": "unknown"
+                        },
+                        "doc": "Not a type.",
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "unknown"
+                },
+                "doc": "Docstring types are resolved without evaluating them.\n\nParameters\n----------\nvalue : __import__(\"os\").getcwd()\n    Not a type.",
+                "signature": "def expression(value)",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "google": {
+                "name": "google",
+                "parameters": [
+                    {
+                        "name": "name",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": "The name.",
+                        "inferred": true
+                    },
+                    {
+                        "name": "count",
+                        "type": {
+                            "kind": "unknown"
+                        },
+                        "doc": "Without type.",
+                        "inferred": false
+                    }
+                ],
+                "return": {
+                    "kind": "unknown"
+                },
+                "doc": "Repeat a name in Google style.\n\nArgs:\n    name (str): The name.\n    count: Without type.",
+                "signature": "def google(name, count)",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "numpy": {
+                "name": "numpy",
+                "parameters": [
+                    {
+                        "name": "x",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "int"
+                        },
+                        "doc": "The first value.",
+                        "inferred": true
+                    },
+                    {
+                        "name": "y",
+                        "type": {
+                            "kind": "list",
+                            "inner": [
+                                {
+                                    "kind": "primitive",
+                                    "name": "float"
+                                }
+                            ]
+                        },
+                        "doc": null,
+                        "inferred": true
+                    },
+                    {
+                        "name": "scale",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "float"
+                        },
+                        "doc": "Weight of the sum.",
+                        "inferred": true
+                    }
+                ],
+                "return": {
+                    "kind": "unknown"
+                },
+                "doc": "Weighted sum in NumPy style.\n\nParameters\n----------\nx : int\n    The first value.\ny : list of float\nscale : float, optional\n    Weight of the sum.",
+                "signature": "def numpy(x, y, scale=1.0)",
+                "overloads": [],
+                "source": "runtime"
+            },
+            "sphinx": {
+                "name": "sphinx",
+                "parameters": [
+                    {
+                        "name": "a",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": "The first string.",
+                        "inferred": true
+                    },
+                    {
+                        "name": "b",
+                        "type": {
+                            "kind": "primitive",
+                            "name": "str"
+                        },
+                        "doc": "The second string.",
+                        "inferred": true
+                    }
+                ],
+                "return": {
+                    "kind": "unknown"
+                },
+                "doc": "Concatenate in Sphinx style.\n\n:param str a: The first string.\n:param b: The second string.\n:type b: str",
+                "signature": "def sphinx(a, b)",
+                "overloads": [],
+                "source": "runtime"
+            }
+        },
+        "type_definitions": {}
+    }
+    "###);
+
+    // Types are not inferred by default
+    let interface = Interface::from_py_module(TARGET)?;
+    for f in interface.functions.values() {
+        for p in &f.parameters {
+            assert_eq!(p.r#type, Type::Unknown);
+            assert!(!p.inferred);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn codegen() -> Result<()> {
+    std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let interface = Interface::from_py_module_with_options(TARGET, &OPTIONS)?;
+    insta::assert_snapshot!(generate(TARGET, &interface, true)?, @r###"
+    /// The Python module, reloaded by `MODULE.reload(py)` after which the functions call the new definitions
+    pub static MODULE: ::py2o2_runtime::ModuleCache = ::py2o2_runtime::ModuleCache::new(
+        "untyped",
+    );
+    /// Docstring types are resolved without evaluating them.
+    ///
+    /// ```text
+    /// Parameters
+    /// ----------
+    /// value : __import__("os").getcwd()
+    ///     Not a type.
+    /// ```
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def expression(value)
+    /// ```
+    pub fn expression<'py>(
+        py: ::pyo3::Python<'py>,
+        value: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "expression")?.call((value,), None)?;
+        result.extract()
+    }
+    /// Repeat a name in Google style.
+    ///
+    /// ```text
+    /// Args:
+    ///     name (str): The name.
+    ///     count: Without type.
+    /// ```
+    ///
+    /// Type of `name` is inferred from the docstring.
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def google(name, count)
+    /// ```
+    pub fn google<'py>(
+        py: ::pyo3::Python<'py>,
+        name: &str,
+        count: impl ::pyo3::conversion::IntoPy<::pyo3::PyObject>,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "google")?.call((name, count), None)?;
+        result.extract()
+    }
+    /// Weighted sum in NumPy style.
+    ///
+    /// ```text
+    /// Parameters
+    /// ----------
+    /// x : int
+    ///     The first value.
+    /// y : list of float
+    /// scale : float, optional
+    ///     Weight of the sum.
+    /// ```
+    ///
+    /// Types of `x`, `y`, `scale` are inferred from the docstring.
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def numpy(x, y, scale=1.0)
+    /// ```
+    pub fn numpy<'py>(
+        py: ::pyo3::Python<'py>,
+        x: i64,
+        y: &::pyo3::types::PyList,
+        scale: f64,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "numpy")?.call((x, y, scale), None)?;
+        result.extract()
+    }
+    /// Concatenate in Sphinx style.
+    ///
+    /// ```text
+    /// :param str a: The first string.
+    /// :param b: The second string.
+    /// :type b: str
+    /// ```
+    ///
+    /// Types of `a`, `b` are inferred from the docstring.
+    ///
+    /// # Python signature
+    ///
+    /// ```python
+    /// def sphinx(a, b)
+    /// ```
+    pub fn sphinx<'py>(
+        py: ::pyo3::Python<'py>,
+        a: &str,
+        b: &str,
+    ) -> ::pyo3::PyResult<::pyo3::PyObject> {
+        let result = MODULE.getattr(py, "sphinx")?.call((a, b), None)?;
+        result.extract()
+    }
+    "###);
+    Ok(())
+}
@@ -0,0 +1,23 @@
+from typing import Annotated
+
+import py2o2
+
+
+def narrow(x: Annotated[int, py2o2.Rust("u8")]) -> Annotated[int, py2o2.Rust("u8")]:
+    return 2 * x
+
+
+def rename(type: Annotated[str, py2o2.Rename("kind")]) -> str:
+    return f"kind = {type}"
+
+
+def skipped() -> Annotated[None, py2o2.Skip()]:
+    pass
+
+
+def other_metadata(x: Annotated[float, "unit: meter"]) -> float:
+    return x
+
+
+def skipped_parameter(x: Annotated[int | str, py2o2.Skip()]) -> None:
+    pass
@@ -1,6 +1,7 @@
 # Example from https://docs.python.org/3/library/typing.html#callable
 
 from collections.abc import Callable
+from typing import Concatenate, ParamSpec, Protocol
 
 
 def feeder(get_next_item: Callable[[], str]) -> None:
@@ -22,3 +23,27 @@ def async_query(
 
 def ellipsis_callable(f: Callable[..., None]) -> None:
     pass
+
+
+P = ParamSpec("P")
+
+
+def concatenate(f: Callable[Concatenate[int, P], float]) -> float:
+    return f(2, 3.0, scale=4.0)
+
+
+class Formatter(Protocol):
+    def __call__(self, value: int, *, prefix: str) -> str:
+        ...
+
+
+def keyword_callback(f: Formatter) -> str:
+    return f(42, prefix="answer = ")
+
+
+def unexpected_keyword(f: Formatter) -> str:
+    return f(42, prefix="answer = ", suffix="!")
+
+
+def unexpected_positional(f: Formatter) -> str:
+    return f(42, 43, prefix="answer = ")
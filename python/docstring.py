@@ -0,0 +1,50 @@
+from typing import NewType
+
+Pixel = NewType("Pixel", int)
+Pixel.__doc__ = "Length in pixels"
+
+
+def google(x: int, name: str) -> str:
+    """Greet in Google style.
+
+    Args:
+        x (int): Number of greetings.
+        name: Whom to greet. Long descriptions
+            may continue on the next line.
+
+    Returns:
+        Greeting message.
+    """
+    return f"Hello, {name}! " * x
+
+
+def numpy(width: Pixel, height: Pixel) -> int:
+    """Area of a rectangle in NumPy style.
+
+    Parameters
+    ----------
+    width : Pixel
+        Width of the rectangle.
+    height : Pixel
+        Height of the rectangle.
+
+    Returns
+    -------
+    int
+        Area of the rectangle.
+    """
+    return width * height
+
+
+def sphinx(a: float, b: float) -> float:
+    """Sum of two numbers in Sphinx style.
+
+    :param a: The first number.
+    :param float b: The second number.
+    :return: The sum.
+    """
+    return a + b
+
+
+def undocumented(a: int) -> None:
+    pass
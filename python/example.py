@@ -25,3 +25,7 @@ def a6() -> tuple[int, str]:
 
 def a7(x: int) -> tuple[int, str, float]:
     return 2 * x, "x si " + str(x), float(x)
+
+
+def a8(x, y):
+    return x + y
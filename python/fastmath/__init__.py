@@ -0,0 +1,3 @@
+"""Functions implemented in C, re-exported from the standard library"""
+
+from math import copysign, factorial, hypot, sqrt
@@ -0,0 +1,2 @@
+def sqrt(x: float, /) -> float: ...
+def copysign(x: float, y: float, /) -> float: ...
@@ -0,0 +1,20 @@
+# Identifiers in Unicode are normalized into NFKC,
+# see https://docs.python.org/3/reference/lexical_analysis.html#identifiers
+
+import math
+
+c = 299792458.0
+
+
+def λ_to_ν(λ: float) -> float:
+    """Frequency of light from its wavelength"""
+    return c / λ
+
+
+def gaussian(x: float, μ: float, σ: float) -> float:
+    return math.exp(-(((x - μ) / σ) ** 2) / 2) / (σ * math.sqrt(2 * math.pi))
+
+
+# Defined as `find` since `ﬁ` is normalized into `fi`
+def ﬁnd(ℎ: list[str], x: str) -> int:
+    return ℎ.index(x)
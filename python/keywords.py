@@ -0,0 +1,9 @@
+# Names which are keywords in Rust but not in Python
+
+
+def f(type: int, match: str) -> int:
+    return type + len(match)
+
+
+def move(self: int, _: int) -> int:
+    return self - _
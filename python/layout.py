@@ -0,0 +1,40 @@
+"""Definitions spread over lines and nested blocks"""
+
+from typing import TYPE_CHECKING, \
+    NewType
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence  # only for annotations
+else:
+    from collections.abc import Sequence
+
+Meters = NewType(
+    "Meters",  # unit of length
+    float,
+)
+
+
+def total(
+    xs: Sequence[float],  # values to be added
+    start: float = 0.0,
+) -> float:
+    """Sum of values"""
+    s = start
+    for x in xs:
+        if x > 0:
+            s += x
+        elif x < 0:
+            s -= x
+        else:
+            pass
+
+    return s
+
+
+def scale(xs: list[Meters], \
+          factor: float) -> list[Meters]:
+    return [Meters(x * factor) for x in xs]
+
+
+async def fetch(url: str) -> str:
+    ...
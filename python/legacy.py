@@ -0,0 +1,11 @@
+def scale(x, factor):
+    """Multiply `x` by `factor`"""
+    return x * factor
+
+
+def user_name(user_id):
+    return f"user{user_id}"
+
+
+def answer() -> int:
+    return 42
@@ -0,0 +1,16 @@
+import typing
+
+
+@typing.overload
+def double(x: int) -> int:
+    ...
+
+
+@typing.overload
+def double(x: str) -> str:
+    ...
+
+
+def double(x: int | str) -> int | str:
+    """Double the value"""
+    return x * 2
@@ -0,0 +1,5 @@
+Json = dict[str, "Json"] | list["Json"] | str
+
+
+def dump(value: Json) -> str:
+    return str(value)
@@ -0,0 +1,12 @@
+"""Module failing at import time, which can only be inspected without executing it"""
+
+from typing import NewType
+
+Count = NewType("Count", int)
+
+raise RuntimeError("side_effect must not be imported")
+
+
+def double(x: Count) -> int:
+    """Double the count"""
+    return 2 * x
@@ -0,0 +1,3 @@
+"""Package failing at import time, whose submodules can only be inspected without executing it"""
+
+raise RuntimeError("side_effect_package must not be imported")
@@ -0,0 +1,29 @@
+from typing import Any, NoReturn
+
+
+class Animal:
+    pass
+
+
+class Dog(Animal):
+    pass
+
+
+def identity(x: Any) -> Any:
+    return x
+
+
+def describe(x: object) -> str:
+    return f"{x}"
+
+
+def subclass(cls: type[Animal]) -> type[Animal]:
+    return type("Sub" + cls.__name__, (cls,), {})
+
+
+def fail(message: str) -> NoReturn:
+    raise RuntimeError(message)
+
+
+def absurd(x: NoReturn) -> int:
+    return x
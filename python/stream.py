@@ -0,0 +1,7 @@
+def dump(x: int, fp) -> None:
+    """Write `x` to the file-like object"""
+    fp.write(str(x))
+
+
+def count(s):
+    return len(s)
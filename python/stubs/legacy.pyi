@@ -0,0 +1,6 @@
+from typing import NewType
+
+UserId = NewType("UserId", int)
+
+def scale(x: float, factor: float) -> float: ...
+def user_name(user_id: UserId) -> str: ...
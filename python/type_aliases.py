@@ -1,7 +1,9 @@
 # Examples at https://docs.python.org/3/library/typing.html#type-aliases
 
 from collections.abc import Sequence
-from typing import NewType
+from typing import Annotated, NewType
+
+import py2o2
 
 Vector = list[float]
 
@@ -24,3 +26,23 @@ UserId = NewType("UserId", int)
 
 def get_user_name(user_id: UserId) -> str:
     return f"ID = {user_id}"
+
+
+Histogram = dict[float, int]
+
+
+def total(histograms: list[Histogram]) -> int:
+    return sum(sum(h.values()) for h in histograms)
+
+
+def weighted_sum(pair: tuple[Vector, float]) -> float:
+    vector, weight = pair
+    return weight * sum(vector)
+
+
+# Keys are still hashable in Rust when annotated
+Digits = dict[Annotated[int, py2o2.Rust("u8")], str]
+
+
+def digits(n: int) -> Digits:
+    return {i: str(i) for i in range(n)}
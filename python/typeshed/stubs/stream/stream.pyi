@@ -0,0 +1,4 @@
+from _typeshed import SupportsWrite
+
+def dump(x: int, fp: SupportsWrite[str]) -> None: ...
+def count(s: str) -> int: ...
@@ -0,0 +1,43 @@
+def numpy(x, y, scale=1.0):
+    """Weighted sum in NumPy style.
+
+    Parameters
+    ----------
+    x : int
+        The first value.
+    y : list of float
+    scale : float, optional
+        Weight of the sum.
+    """
+    return scale * (x + sum(y))
+
+
+def google(name, count):
+    """Repeat a name in Google style.
+
+    Args:
+        name (str): The name.
+        count: Without type.
+    """
+    return name * count
+
+
+def sphinx(a, b):
+    """Concatenate in Sphinx style.
+
+    :param str a: The first string.
+    :param b: The second string.
+    :type b: str
+    """
+    return a + b
+
+
+def expression(value):
+    """Docstring types are resolved without evaluating them.
+
+    Parameters
+    ----------
+    value : __import__("os").getcwd()
+        Not a type.
+    """
+    return value
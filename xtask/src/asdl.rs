@@ -0,0 +1,443 @@
+//! Generate `py2o2/src/ast/nodes.rs` from the ASDL grammar of Python's `ast` module
+//!
+//! The grammar is read from the docstrings of the node classes,
+//! e.g. `FunctionDef(identifier name, arguments args, ...)`,
+//! which CPython generates from `Parser/Python.asdl`.
+
+use anyhow::{bail, Result};
+use proc_macro2::{Ident, Span, TokenStream};
+use pyo3::{prelude::*, types::PyType};
+use quote::{format_ident, quote};
+
+/// Rust names of ASDL types other than the camel case of them,
+/// e.g. to avoid clashes with the names of constructors
+const RENAMES: &[(&str, &str)] = &[
+    // historical name
+    ("stmt", "Statements"),
+    // `Expr` is the expression statement
+    ("expr", "Expressions"),
+    ("boolop", "BoolOperator"),
+    ("unaryop", "UnaryOperator"),
+    ("cmpop", "CmpOp"),
+    ("withitem", "WithItem"),
+];
+
+/// Fields declared as `expr*` but containing `None`,
+/// i.e. `**d` in a dict display and keyword-only arguments without default
+const NULLABLE_ELEMENTS: &[(&str, &str)] = &[("Dict", "keys"), ("arguments", "kw_defaults")];
+
+/// Location attributes declared by `attributes (...)` in ASDL
+const ATTRIBUTES: &[(&str, &str)] = &[
+    ("lineno", "int"),
+    ("col_offset", "int"),
+    ("end_lineno", "int?"),
+    ("end_col_offset", "int?"),
+];
+
+#[derive(Debug)]
+enum Definition {
+    /// `name = A(...) | B(...)`
+    Sum {
+        name: String,
+        constructors: Vec<Constructor>,
+        attributes: bool,
+    },
+    /// `name(...)`
+    Product {
+        constructor: Constructor,
+        attributes: bool,
+    },
+}
+
+#[derive(Debug)]
+struct Constructor {
+    name: String,
+    fields: Vec<Field>,
+    /// Definition in ASDL, e.g. `Name(identifier id, expr_context ctx)`
+    asdl: String,
+}
+
+#[derive(Debug)]
+struct Field {
+    ty: String,
+    /// `?` or `*`
+    quantifier: Option<char>,
+    name: String,
+}
+
+fn parse_constructor(asdl: &str) -> Result<Constructor> {
+    let asdl = asdl.trim();
+    let Some((name, rest)) = asdl.split_once('(') else {
+        return Ok(Constructor {
+            name: asdl.to_string(),
+            fields: Vec::new(),
+            asdl: asdl.to_string(),
+        });
+    };
+    let Some(fields) = rest.strip_suffix(')') else {
+        bail!("Unexpected ASDL constructor: {}", asdl);
+    };
+    let fields = fields
+        .split(',')
+        .map(|field| {
+            let Some((ty, name)) = field.trim().split_once(' ') else {
+                bail!("Unexpected ASDL field: {}", field);
+            };
+            let (ty, quantifier) = match ty.strip_suffix(['?', '*']) {
+                Some(stripped) => (stripped, ty.chars().last()),
+                None => (ty, None),
+            };
+            Ok(Field {
+                ty: ty.to_string(),
+                quantifier,
+                name: name.to_string(),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Constructor {
+        name: name.to_string(),
+        fields,
+        asdl: asdl.to_string(),
+    })
+}
+
+/// Read the ASDL definitions from the `ast` module of the running Python
+fn definitions(py: Python<'_>) -> Result<Vec<Definition>> {
+    fn doc(ty: &PyType) -> PyResult<String> {
+        Ok(ty
+            .getattr("__doc__")?
+            .extract::<Option<String>>()?
+            .unwrap_or_default())
+    }
+    fn subclasses(ty: &PyType) -> PyResult<Vec<&PyType>> {
+        let mut subclasses = Vec::new();
+        for sub in ty
+            .call_method0("__subclasses__")?
+            .extract::<Vec<&PyType>>()?
+        {
+            // e.g. `ast.Num` or `ast.Suite`
+            if !doc(sub)?.starts_with("Deprecated") {
+                subclasses.push(sub);
+            }
+        }
+        Ok(subclasses)
+    }
+
+    let ast = py.import("ast")?;
+    let mut definitions = Vec::new();
+    for ty in subclasses(ast.getattr("AST")?.extract()?)? {
+        let attributes = !ty
+            .getattr("_attributes")?
+            .extract::<Vec<&str>>()?
+            .is_empty();
+        let constructors = subclasses(ty)?;
+        if constructors.is_empty() {
+            definitions.push(Definition::Product {
+                constructor: parse_constructor(&doc(ty)?)?,
+                attributes,
+            });
+        } else {
+            definitions.push(Definition::Sum {
+                name: ty.name()?.to_string(),
+                constructors: constructors
+                    .into_iter()
+                    .map(|c| parse_constructor(&doc(c)?))
+                    .collect::<Result<_>>()?,
+                attributes,
+            });
+        }
+    }
+    Ok(definitions)
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn method_ident(name: &str) -> Ident {
+    if syn::parse_str::<Ident>(name).is_ok() {
+        Ident::new(name, Span::call_site())
+    } else {
+        Ident::new_raw(name, Span::call_site())
+    }
+}
+
+struct Generator<'a> {
+    definitions: &'a [Definition],
+}
+
+impl Generator<'_> {
+    /// Rust type of an ASDL type, e.g. `Statements<'py>` for `stmt`
+    fn ty(&self, ty: &str) -> Result<TokenStream> {
+        Ok(match ty {
+            "identifier" | "string" => quote! { &'py str },
+            "int" => quote! { i64 },
+            "constant" => quote! { &'py ::pyo3::PyAny },
+            _ => {
+                let definition = self
+                    .definitions
+                    .iter()
+                    .find(|d| match d {
+                        Definition::Sum { name, .. } => name == ty,
+                        Definition::Product { constructor, .. } => constructor.name == ty,
+                    })
+                    .ok_or_else(|| anyhow::anyhow!("Unknown ASDL type: {}", ty))?;
+                match definition {
+                    Definition::Sum { constructors, .. } if is_simple(constructors) => {
+                        let ident = format_ident!("{}", rust_name(ty));
+                        quote! { #ident }
+                    }
+                    // Use the constructor directly, e.g. `ExceptHandler` for `excepthandler`
+                    Definition::Sum { constructors, .. } if constructors.len() == 1 => {
+                        let ident = format_ident!("{}", constructors[0].name);
+                        quote! { #ident<'py> }
+                    }
+                    _ => {
+                        let ident = format_ident!("{}", rust_name(ty));
+                        quote! { #ident<'py> }
+                    }
+                }
+            }
+        })
+    }
+
+    fn accessors(&self, owner: &str, fields: &[Field]) -> Result<TokenStream> {
+        let mut methods = Vec::new();
+        for field in fields {
+            let ty = self.ty(&field.ty)?;
+            let ty = match field.quantifier {
+                Some('?') => quote! { Option<#ty> },
+                Some(_) if NULLABLE_ELEMENTS.contains(&(owner, field.name.as_str())) => {
+                    quote! { Vec<Option<#ty>> }
+                }
+                Some(_) => quote! { Vec<#ty> },
+                None => ty,
+            };
+            let doc = format!(
+                " `{}{} {}`",
+                field.ty,
+                field.quantifier.map(String::from).unwrap_or_default(),
+                field.name
+            );
+            let method = method_ident(&field.name);
+            let name = &field.name;
+            methods.push(quote! {
+                #[doc = #doc]
+                pub fn #method(&self) -> ::pyo3::PyResult<#ty> {
+                    self.0.getattr(#name)?.extract()
+                }
+            });
+        }
+        Ok(quote! { #(#methods)* })
+    }
+
+    fn attributes() -> Result<Vec<Field>> {
+        Ok(ATTRIBUTES
+            .iter()
+            .map(|(name, ty)| Field {
+                ty: ty.trim_end_matches('?').to_string(),
+                quantifier: ty.strip_prefix("int").and_then(|q| q.chars().next()),
+                name: name.to_string(),
+            })
+            .collect())
+    }
+
+    fn node(
+        &self,
+        python_name: &str,
+        constructor: &Constructor,
+        attributes: bool,
+    ) -> Result<TokenStream> {
+        let python = format_ident!("{}", python_name);
+        let ident = format_ident!("{}", rust_name(python_name));
+        let import = if ident == python_name {
+            quote! { import_pytype!(ast.#python); }
+        } else {
+            quote! { import_pytype!(ast.#python as #ident); }
+        };
+        let mut fields = self.accessors(python_name, &constructor.fields)?;
+        if attributes {
+            let attributes = self.accessors(python_name, &Self::attributes()?)?;
+            fields = quote! { #fields #attributes };
+        }
+        let doc = format!(" `{}`", constructor.asdl);
+        Ok(quote! {
+            #import
+
+            #[doc = #doc]
+            impl<'py> #ident<'py> {
+                #fields
+            }
+        })
+    }
+
+    fn simple_sum(&self, name: &str, constructors: &[Constructor]) -> TokenStream {
+        let ident = format_ident!("{}", rust_name(name));
+        let variants: Vec<_> = constructors
+            .iter()
+            .map(|c| format_ident!("{}", c.name))
+            .collect();
+        let names = constructors.iter().map(|c| &c.name);
+        let doc = format!(
+            " `{} = {}`",
+            name,
+            constructors
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+                .join(" | ")
+        );
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum #ident {
+                #(#variants,)*
+            }
+
+            impl<'py> ::pyo3::FromPyObject<'py> for #ident {
+                fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                    check_instance(ob, #name)?;
+                    match ob.get_type().name()? {
+                        #(#names => Ok(#ident::#variants),)*
+                        other => Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                            "Unknown ast.{}: {}", #name, other
+                        ))),
+                    }
+                }
+            }
+        }
+    }
+
+    fn sum(&self, name: &str, constructors: &[Constructor]) -> TokenStream {
+        let ident = format_ident!("{}", rust_name(name));
+        let variants: Vec<_> = constructors
+            .iter()
+            .map(|c| format_ident!("{}", c.name))
+            .collect();
+        let names = constructors.iter().map(|c| &c.name);
+        let doc = format!(" `{}`", name);
+        let other = format!(
+            " `ast.{}` of a newer Python than the one generating this module",
+            name
+        );
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug)]
+            pub enum #ident<'py> {
+                #(#variants(#variants<'py>),)*
+                #[doc = #other]
+                Other(&'py ::pyo3::PyAny),
+            }
+
+            impl<'py> ::pyo3::FromPyObject<'py> for #ident<'py> {
+                fn extract(ob: &'py ::pyo3::PyAny) -> ::pyo3::PyResult<Self> {
+                    check_instance(ob, #name)?;
+                    Ok(match ob.get_type().name()? {
+                        #(#names => #ident::#variants(ob.extract()?),)*
+                        _ => #ident::Other(ob),
+                    })
+                }
+            }
+
+            impl<'py> #ident<'py> {
+                /// The underlying Python object
+                pub fn as_any(&self) -> &'py ::pyo3::PyAny {
+                    match self {
+                        #(#ident::#variants(node) => node.0,)*
+                        #ident::Other(node) => node,
+                    }
+                }
+            }
+        }
+    }
+
+    fn generate(&self) -> Result<TokenStream> {
+        let mut items = Vec::new();
+        for definition in self.definitions {
+            match definition {
+                Definition::Product {
+                    constructor,
+                    attributes,
+                } => items.push(self.node(&constructor.name, constructor, *attributes)?),
+                Definition::Sum {
+                    name, constructors, ..
+                } if is_simple(constructors) => items.push(self.simple_sum(name, constructors)),
+                Definition::Sum {
+                    name,
+                    constructors,
+                    attributes,
+                } => {
+                    if constructors.len() > 1 {
+                        items.push(self.sum(name, constructors));
+                    }
+                    for c in constructors {
+                        items.push(self.node(&c.name, c, *attributes)?);
+                    }
+                }
+            }
+        }
+        Ok(quote! { #(#items)* })
+    }
+}
+
+/// Sum type of constructors without fields, e.g. `operator`
+fn is_simple(constructors: &[Constructor]) -> bool {
+    constructors.iter().all(|c| c.fields.is_empty())
+}
+
+fn rust_name(name: &str) -> String {
+    RENAMES
+        .iter()
+        .find(|(asdl, _)| *asdl == name)
+        .map(|(_, rust)| rust.to_string())
+        .unwrap_or_else(|| camel_case(name))
+}
+
+/// Source of `py2o2/src/ast/nodes.rs`
+pub fn generate() -> Result<String> {
+    Python::with_gil(|py| {
+        let version = py.import("sys")?.getattr("version_info")?;
+        let version: (u8, u8) = (
+            version.getattr("major")?.extract()?,
+            version.getattr("minor")?.extract()?,
+        );
+        let definitions = definitions(py)?;
+        let items = Generator {
+            definitions: &definitions,
+        }
+        .generate()?;
+        let header = format!(
+            " Wrappers of the nodes in the ASDL grammar of Python {}.{}, generated by `cargo xtask`",
+            version.0, version.1
+        );
+        let file: syn::File = syn::parse2(quote! {
+            #![doc = #header]
+
+            use py2o2_runtime::{import_pytype, PyTypeInfoUser};
+
+            fn check_instance(ob: &::pyo3::PyAny, name: &str) -> ::pyo3::PyResult<()> {
+                let ty = ob.py().import("ast")?.getattr(name)?;
+                if ob.is_instance(ty)? {
+                    Ok(())
+                } else {
+                    Err(::pyo3::exceptions::PyTypeError::new_err(format!(
+                        "Not an ast.{}: {}",
+                        name,
+                        ob.get_type()
+                    )))
+                }
+            }
+
+            #items
+        })?;
+        Ok(prettyplease::unparse(&file))
+    })
+}
@@ -0,0 +1,86 @@
+//! `cargo xtask differential [PATH...]`: compare the parser with `ast.parse` over a corpus
+//!
+//! Without paths, the `python/` fixtures and the standard library of the Python linked by pyo3 are used.
+
+use anyhow::Result;
+use py2o2::ast::differential::{compare, Outcome};
+use pyo3::prelude::*;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn stdlib() -> Result<PathBuf> {
+    Python::with_gil(|py| {
+        let paths = py.import("sysconfig")?.call_method0("get_paths")?;
+        let stdlib: PathBuf = paths.get_item("stdlib")?.extract()?;
+        Ok(stdlib)
+    })
+}
+
+/// `.py` and `.pyi` files under `path`, where `site-packages` is skipped
+fn sources(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if path.is_file() {
+        files.push(path.to_owned());
+        return Ok(());
+    }
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() && !entry.ends_with("site-packages") {
+            sources(&entry, files)?;
+        } else if entry
+            .extension()
+            .is_some_and(|ext| ext == "py" || ext == "pyi")
+        {
+            files.push(entry);
+        }
+    }
+    Ok(())
+}
+
+pub fn run(paths: Vec<PathBuf>, python_root: &Path) -> Result<()> {
+    let roots = if paths.is_empty() {
+        vec![python_root.to_owned(), stdlib()?]
+    } else {
+        paths
+    };
+    let mut files = Vec::new();
+    for root in &roots {
+        sources(root, &mut files)?;
+    }
+
+    let mut counts = BTreeMap::<&str, usize>::new();
+    for path in &files {
+        // A few files in the test suite are not in UTF-8 on purpose
+        let Ok(source) = fs::read_to_string(path) else {
+            *counts.entry("not UTF-8").or_default() += 1;
+            continue;
+        };
+        let name = path.display().to_string();
+        let outcome = compare(&name, &source);
+        let kind = match &outcome {
+            Outcome::Same => "same",
+            Outcome::Invalid(_) => "invalid",
+            Outcome::Unsupported(_) => "unsupported",
+            Outcome::Rejected(_) => "rejected",
+            Outcome::Mismatch { .. } => "mismatch",
+        };
+        *counts.entry(kind).or_default() += 1;
+        match outcome {
+            Outcome::Rejected(diagnostic) => println!("{}\n", diagnostic),
+            Outcome::Mismatch { python, parser } => {
+                let show = |stmt: Option<String>| stmt.unwrap_or_else(|| "<end>\n".to_string());
+                println!("mismatch: {}", name);
+                println!("--- ast.parse\n{}", show(python.map(|s| s.to_string())));
+                println!("+++ parser\n{}", show(parser.map(|s| s.to_string())));
+            }
+            _ => {}
+        }
+    }
+    println!("{} files: {:?}", files.len(), counts);
+    Ok(())
+}
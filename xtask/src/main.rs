@@ -1,16 +1,49 @@
 use anyhow::Result;
+use py2o2::inspect::InspectOptions;
 use std::{fs, path::Path, process::Command};
 
+mod asdl;
+mod differential;
+
 const PYTHON_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../python/");
 
 fn main() -> Result<()> {
     std::env::set_var("PYTHONPATH", PYTHON_ROOT);
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("differential") {
+        return differential::run(args.map(Into::into).collect(), Path::new(PYTHON_ROOT));
+    }
+
     let testing_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../py2o2-testing/src");
-    for module in ["example", "type_aliases", "union", "callable"] {
-        let code = py2o2::generate(module, true)?;
-        fs::write(&testing_root.join(format!("{}.rs", module)), code)?;
+    let options = InspectOptions {
+        stub_paths: vec![Path::new(PYTHON_ROOT).join("stubs")],
+        ..Default::default()
+    };
+    let codegen_options = py2o2::codegen::CodegenOptions {
+        bare: true,
+        gil: true,
+    };
+    for module in [
+        "example",
+        "type_aliases",
+        "union",
+        "callable",
+        "docstring",
+        "overload",
+        "annotated",
+        "special_forms",
+        "fastmath",
+        "legacy",
+        "greek",
+        "keywords",
+    ] {
+        let code = py2o2::generate_with_codegen_options(module, &options, &codegen_options)?;
+        fs::write(testing_root.join(format!("{}.rs", module)), code)?;
     }
 
+    let ast_nodes = Path::new(env!("CARGO_MANIFEST_DIR")).join("../py2o2/src/ast/nodes.rs");
+    fs::write(ast_nodes, asdl::generate()?)?;
+
     let st = Command::new("cargo").arg("fmt").arg("--all").status()?;
     assert!(st.success());
     Ok(())